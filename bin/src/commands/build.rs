@@ -4,7 +4,7 @@ use crate::{
     context::{self, Context},
     error::Error,
     executor::Executor,
-    modules::{pbo::Collapse, Binarize, Files, Rapifier},
+    modules::{pbo::Collapse, Binarize, Files, Models, Rapifier, StringtableReferences, Textures},
     report::Report,
 };
 
@@ -90,10 +90,13 @@ pub fn executor(ctx: Context, matches: &ArgMatches) -> Executor {
 
     if matches.get_one::<bool>("no-rap") != Some(&true) {
         executor.add_module(Box::<Rapifier>::default());
+        executor.add_module(Box::<StringtableReferences>::default());
     }
     if matches.get_one::<bool>("no-bin") != Some(&true) {
         executor.add_module(Box::<Binarize>::default());
     }
+    executor.add_module(Box::<Textures>::default());
+    executor.add_module(Box::<Models>::default());
     executor.add_module(Box::<Files>::default());
 
     executor.init();
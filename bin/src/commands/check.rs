@@ -5,7 +5,7 @@ use crate::{
     context::Context,
     error::Error,
     executor::Executor,
-    modules::{pbo::Collapse, Binarize, Rapifier},
+    modules::{pbo::Collapse, Binarize, Models, Rapifier, StringtableReferences, Textures},
     report::Report,
 };
 
@@ -31,7 +31,10 @@ pub fn execute() -> Result<Report, Error> {
     executor.collapse(Collapse::Yes);
 
     executor.add_module(Box::<Rapifier>::default());
+    executor.add_module(Box::<StringtableReferences>::default());
     executor.add_module(Box::<Binarize>::new(Binarize::new(true)));
+    executor.add_module(Box::<Textures>::new(Textures::new(true)));
+    executor.add_module(Box::<Models>::default());
 
     info!("Running checks");
 
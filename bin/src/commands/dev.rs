@@ -6,7 +6,10 @@ use crate::{
     context::Context,
     error::Error,
     executor::Executor,
-    modules::{pbo::Collapse, Binarize, FilePatching, Files, Rapifier},
+    modules::{
+        pbo::Collapse, Binarize, FilePatching, Files, Models, Rapifier, StringtableReferences,
+        Textures,
+    },
     report::Report,
 };
 
@@ -129,7 +132,10 @@ pub fn context(
 
     if rapify && matches.get_one::<bool>("no-rap") != Some(&true) {
         executor.add_module(Box::<Rapifier>::default());
+        executor.add_module(Box::<StringtableReferences>::default());
     }
+    executor.add_module(Box::<Textures>::default());
+    executor.add_module(Box::<Models>::default());
     executor.add_module(Box::<Files>::default());
     executor.add_module(Box::<FilePatching>::default());
     if force_binarize || matches.get_one::<bool>("binarize") == Some(&true) {
@@ -0,0 +1,151 @@
+use std::{path::PathBuf, sync::Arc};
+
+use clap::{ArgAction, ArgMatches, Command};
+use hemtt_workspace::reporting::{Code, Severity};
+
+use crate::{context::Context, error::Error, report::Report};
+
+#[must_use]
+pub fn cli() -> Command {
+    Command::new("fmt")
+        .about("Format the config files of the project")
+        .long_about("Format the .cpp and .hpp files in addons and optionals, configured by the [format] section of .hemtt/project.toml")
+        .arg(
+            clap::Arg::new("check")
+                .long("check")
+                .help("Report files that are not formatted, without changing them")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("paths")
+                .help("Files or folders to format, defaults to addons and optionals")
+                .num_args(0..),
+        )
+}
+
+/// Execute the fmt command
+///
+/// # Errors
+/// [`Error`] if a file can not be read or written, or an exclude pattern is invalid
+pub fn execute(matches: &ArgMatches) -> Result<Report, Error> {
+    let ctx = Context::new(None, crate::context::PreservePrevious::Remove, true)?;
+    let config = ctx.config().format();
+    let check = matches.get_flag("check");
+
+    let glob_options = glob::MatchOptions {
+        require_literal_separator: true,
+        ..Default::default()
+    };
+    let excludes = config
+        .exclude()
+        .iter()
+        .map(|exclude| glob::Pattern::new(exclude.trim_start_matches('/')))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let roots = matches.get_many::<String>("paths").map_or_else(
+        || {
+            ["addons", "optionals"]
+                .iter()
+                .map(|root| ctx.project_folder().join(root))
+                .collect::<Vec<_>>()
+        },
+        |paths| paths.map(PathBuf::from).collect(),
+    );
+
+    let mut report = Report::new();
+    let mut changed = 0;
+    for root in roots {
+        if !root.exists() {
+            continue;
+        }
+        for entry in walkdir::WalkDir::new(root)
+            .into_iter()
+            .filter_map(std::result::Result::ok)
+            .filter(|e| e.file_type().is_file())
+            .filter(|e| {
+                e.path()
+                    .extension()
+                    .is_some_and(|ext| ext == "cpp" || ext == "hpp")
+            })
+        {
+            let path = entry.path();
+            let file = path
+                .strip_prefix(ctx.project_folder())
+                .unwrap_or(path)
+                .display()
+                .to_string()
+                .replace('\\', "/");
+            if file.contains(".hemttout")
+                || excludes
+                    .iter()
+                    .any(|pattern| pattern.matches_with(&file, glob_options))
+            {
+                debug!("skipping {}", file);
+                continue;
+            }
+            let source = std::fs::read_to_string(path)?;
+            let formatted = match hemtt_config::format::format(&source, config) {
+                Ok(formatted) => formatted,
+                Err(error) => {
+                    report.push(Arc::new(FormatFailed { file, error }));
+                    continue;
+                }
+            };
+            if formatted == source {
+                continue;
+            }
+            changed += 1;
+            if check {
+                report.push(Arc::new(NotFormatted { file }));
+            } else {
+                std::fs::write(path, formatted)?;
+                debug!("formatted {}", file);
+            }
+        }
+    }
+    if !check {
+        info!("Formatted {} files", changed);
+    }
+    Ok(report)
+}
+
+struct NotFormatted {
+    file: String,
+}
+
+impl Code for NotFormatted {
+    fn ident(&self) -> &'static str {
+        "FMT1"
+    }
+
+    fn message(&self) -> String {
+        format!("File `{}` is not formatted", self.file)
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("run `hemtt fmt` to format it".to_string())
+    }
+}
+
+struct FormatFailed {
+    file: String,
+    error: hemtt_config::format::Error,
+}
+
+impl Code for FormatFailed {
+    fn ident(&self) -> &'static str {
+        "FMT2"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        format!("File `{}` could not be formatted", self.file)
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(self.error.to_string())
+    }
+}
@@ -0,0 +1,163 @@
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
+
+use clap::{ArgMatches, Command};
+use hemtt_signing::BIPrivateKey;
+
+use crate::{
+    context::{Context, PreservePrevious},
+    error::Error,
+    modules::sign::{load_keys, read_key},
+    report::Report,
+};
+
+#[must_use]
+pub fn cli() -> Command {
+    Command::new("keys")
+        .about("Manage signing keys")
+        .long_about("Generate, export and rotate the private keys used to sign releases")
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("generate")
+                .about("Generate a new private key")
+                .arg(
+                    clap::Arg::new("authority")
+                        .help("Authority of the new key")
+                        .required(true),
+                )
+                .arg(output_arg().required(true)),
+        )
+        .subcommand(
+            Command::new("export")
+                .about("Export the public key of a private key")
+                .arg(
+                    clap::Arg::new("key")
+                        .help("Private key (.biprivatekey) to export")
+                        .required(true),
+                )
+                .arg(output_arg()),
+        )
+        .subcommand(
+            Command::new("rotate")
+                .about("Generate a new key for the project, keeping the current key for signing")
+                .arg(
+                    clap::Arg::new("authority")
+                        .help("Authority of the new key")
+                        .required(true),
+                )
+                .arg(output_arg()),
+        )
+}
+
+fn output_arg() -> clap::Arg {
+    clap::Arg::new("output")
+        .long("output")
+        .short('o')
+        .help("Folder to write the keys to")
+}
+
+/// Execute the keys command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+///
+/// # Panics
+/// If the args are not present from clap
+pub fn execute(matches: &ArgMatches) -> Result<Report, Error> {
+    match matches.subcommand() {
+        Some(("generate", matches)) => {
+            let key = BIPrivateKey::generate(
+                1024,
+                matches.get_one::<String>("authority").expect("required"),
+            )?;
+            write_key_pair(
+                &key,
+                Path::new(matches.get_one::<String>("output").expect("required")),
+            )?;
+        }
+        Some(("export", matches)) => {
+            let path = PathBuf::from(matches.get_one::<String>("key").expect("required"));
+            let key = read_key(&path)?;
+            let folder = output(matches, path.parent().unwrap_or_else(|| Path::new(".")));
+            let public = folder.join(format!("{}.bikey", key.authority()));
+            key.to_public_key().write(&mut File::create(&public)?)?;
+            info!("Exported {}", public.display());
+        }
+        Some(("rotate", matches)) => rotate(matches)?,
+        _ => unreachable!(),
+    }
+    Ok(Report::new())
+}
+
+fn rotate(matches: &ArgMatches) -> Result<(), Error> {
+    let ctx = Context::new(None, PreservePrevious::Remove, false)?;
+    let current = load_keys(&ctx)?;
+    let Some(previous) = current.first() else {
+        return Err(Error::SigningKey(
+            "no key is set in `[signing]`, use `hemtt keys generate` to create one".to_string(),
+        ));
+    };
+    let signing = ctx.config().signing();
+    let previous_path = signing
+        .private_key_env()
+        .and_then(|var| std::env::var(var).ok())
+        .filter(|path| !path.is_empty())
+        .or_else(|| signing.private_key().cloned())
+        .unwrap_or_default()
+        .replace('\\', "/");
+    let default_folder = Path::new(&previous_path)
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    let key = BIPrivateKey::generate(
+        1024,
+        matches.get_one::<String>("authority").expect("required"),
+    )?;
+    if key.authority() == previous.authority() {
+        return Err(Error::SigningKey(format!(
+            "the new key must have a different authority than `{}`",
+            previous.authority()
+        )));
+    }
+    let (private, _) = write_key_pair(&key, &output(matches, &default_folder))?;
+    info!("Update `.hemtt/project.toml` to sign with both keys until the new key is in use:");
+    println!();
+    println!("[signing]");
+    println!(
+        "private_key = \"{}\"",
+        private.display().to_string().replace('\\', "/")
+    );
+    println!("additional_keys = [\"{previous_path}\"]");
+    println!();
+    info!(
+        "Once the new key is whitelisted, remove `{}` from `additional_keys`",
+        previous.authority()
+    );
+    Ok(())
+}
+
+fn output(matches: &ArgMatches, default: &Path) -> PathBuf {
+    matches
+        .get_one::<String>("output")
+        .map_or_else(|| default.to_path_buf(), PathBuf::from)
+}
+
+/// Write a private key and its public key, refusing to overwrite an existing private key
+fn write_key_pair(key: &BIPrivateKey, folder: &Path) -> Result<(PathBuf, PathBuf), Error> {
+    std::fs::create_dir_all(folder)?;
+    let private = folder.join(format!("{}.biprivatekey", key.authority()));
+    let public = folder.join(format!("{}.bikey", key.authority()));
+    if private.exists() {
+        return Err(Error::SigningKey(format!(
+            "`{}` already exists",
+            private.display()
+        )));
+    }
+    key.write_danger(&mut File::create(&private)?)?;
+    key.to_public_key().write(&mut File::create(&public)?)?;
+    info!("Created {} and {}", private.display(), public.display());
+    warn!("Keep the private key secret, it should not be committed to the repository");
+    Ok((private, public))
+}
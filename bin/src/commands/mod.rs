@@ -2,6 +2,8 @@ pub mod book;
 pub mod build;
 pub mod check;
 pub mod dev;
+pub mod fmt;
+pub mod keys;
 pub mod launch;
 pub mod localization;
 pub mod new;
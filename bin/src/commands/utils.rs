@@ -10,6 +10,7 @@ pub fn cli() -> Command {
         .arg_required_else_help(true)
         .subcommand(utils::config::cli())
         .subcommand(utils::inspect::cli())
+        .subcommand(utils::p3d::cli())
         .subcommand(utils::paa::cli())
         .subcommand(utils::pbo::cli())
         .subcommand(utils::sqf::cli())
@@ -24,6 +25,7 @@ pub fn execute(matches: &ArgMatches) -> Result<Report, Error> {
     match matches.subcommand() {
         Some(("config", matches)) => utils::config::execute(matches),
         Some(("inspect", matches)) => utils::inspect::execute(matches),
+        Some(("p3d", matches)) => utils::p3d::execute(matches),
         Some(("paa", matches)) => utils::paa::execute(matches),
         Some(("pbo", matches)) => utils::pbo::execute(matches),
         Some(("sqf", matches)) => utils::sqf::execute(matches),
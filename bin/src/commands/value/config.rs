@@ -0,0 +1,289 @@
+use std::sync::Arc;
+
+use clap::{ArgAction, ArgMatches, Command};
+use hemtt_config::{
+    database::{Database, Origin},
+    Item, Number, Value,
+};
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::reporting::Code;
+use serde::Serialize;
+
+use crate::{context::Context, error::Error, report::Report};
+
+#[must_use]
+pub fn cli() -> Command {
+    Command::new("config")
+        .about("Print a value from the configs of the project")
+        .long_about("Print a property or class from the configs of the project, after preprocessing and following inheritance. The path is separated by `/` or `>>`, such as `CfgWeapons/abe_rifle/magazines`")
+        .arg(
+            clap::Arg::new("path")
+                .help("Path to the property or class")
+                .required(true),
+        )
+        .arg(
+            clap::Arg::new("addon")
+                .long("addon")
+                .short('a')
+                .help("Only read the config of this addon, instead of the whole project"),
+        )
+        .arg(
+            clap::Arg::new("json")
+                .long("json")
+                .help("Print as JSON")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+/// Execute the value config command
+///
+/// # Errors
+/// [`Error`] if a config can not be read
+///
+/// # Panics
+/// If a path is not provided, but this is usually handled by clap
+pub fn execute(matches: &ArgMatches) -> Result<Report, Error> {
+    // The workspace is built the same as `hemtt check`, to discover addon prefixes and the `include` folder
+    let ctx = Context::new(Some("check"), crate::context::PreservePrevious::Keep, false)?;
+    let mut report = Report::new();
+
+    let Some(database) = database(&ctx, matches.get_one::<String>("addon"), &mut report)? else {
+        return Ok(report);
+    };
+    let path = matches
+        .get_one::<String>("path")
+        .expect("path is required")
+        .replace('/', ">>")
+        .split(">>")
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<_>>()
+        .join(" >> ");
+    let json = matches.get_flag("json");
+
+    if let Some((owner, property)) = database.property_owner(&path) {
+        let value = database
+            .value(&path)
+            .unwrap_or_else(|| property.value().clone());
+        if json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&Query::Property {
+                    path: &path,
+                    value: json_value(&value),
+                    source: value.to_string(),
+                    inherited_from: (!owner.eq_ignore_ascii_case(&path_class(&path)))
+                        .then_some(owner.clone()),
+                    definitions: definitions(property.definitions()),
+                })?
+            );
+        } else {
+            println!("{}", assignment(&path, &value));
+            if !owner.eq_ignore_ascii_case(&path_class(&path)) {
+                println!("  inherited from {owner}");
+            }
+            print_definitions(property.definitions());
+        }
+    } else if let Some(class) = database.class(&path) {
+        let parents = database.parents(&path);
+        let properties = class
+            .properties()
+            .map(|property| {
+                let value = database
+                    .value(&format!("{path} >> {}", property.name()))
+                    .unwrap_or_else(|| property.value().clone());
+                (property.name().to_string(), value)
+            })
+            .collect::<Vec<_>>();
+        if json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&Query::Class {
+                    path: &path,
+                    parent: class.parent(),
+                    parents: &parents,
+                    definitions: definitions(class.definitions()),
+                    properties: properties
+                        .iter()
+                        .map(|(name, value)| (name.clone(), json_value(value)))
+                        .collect(),
+                })?
+            );
+        } else {
+            match class.parent() {
+                Some(parent) => println!("class {}: {parent}", class.name()),
+                None => println!("class {}", class.name()),
+            }
+            for parent in &parents {
+                println!("  inherits from {parent}");
+            }
+            print_definitions(class.definitions());
+            for (name, value) in &properties {
+                println!("  {}", assignment(name, value));
+            }
+        }
+    } else {
+        report.push(Arc::new(NotFound {
+            message: format!("`{path}` was not found in the config"),
+        }));
+    }
+    Ok(report)
+}
+
+/// Merge the configs of the project, or of a single addon
+fn database(
+    ctx: &Context,
+    only: Option<&String>,
+    report: &mut Report,
+) -> Result<Option<Database>, Error> {
+    let mut sources = Vec::new();
+    let mut found_addon = false;
+    for addon in ctx.addons() {
+        if only.is_some_and(|only| !addon.name().eq_ignore_ascii_case(only)) {
+            continue;
+        }
+        found_addon = true;
+        let path = ctx
+            .workspace_path()
+            .join(addon.folder())?
+            .join("config.cpp")?;
+        if !path.exists()? {
+            continue;
+        }
+        let processed = match Processor::run(&path) {
+            Ok(processed) => processed,
+            Err((_, hemtt_preprocessor::Error::Code(e))) => {
+                report.push(e);
+                continue;
+            }
+            Err((_, e)) => return Err(e.into()),
+        };
+        match hemtt_config::parse(Some(ctx.config()), &processed) {
+            Ok(configreport) => {
+                sources.push((
+                    addon.name().to_string(),
+                    configreport.into_config(),
+                    processed,
+                ));
+            }
+            Err(codes) => report.extend(codes),
+        }
+    }
+    if let Some(only) = only {
+        if !found_addon {
+            report.push(Arc::new(NotFound {
+                message: format!("Addon `{only}` was not found"),
+            }));
+            return Ok(None);
+        }
+    }
+    Ok(Some(Database::new(sources)))
+}
+
+/// A property as it would be written in a config
+fn assignment(name: &str, value: &Value) -> String {
+    if matches!(value, Value::Array(_)) {
+        format!("{name}[] = {value}")
+    } else {
+        format!("{name} = {value}")
+    }
+}
+
+/// The path of the class a property path points into
+fn path_class(path: &str) -> String {
+    path.rsplit_once(" >> ")
+        .map(|(class, _)| class.to_string())
+        .unwrap_or_default()
+}
+
+fn print_definitions(origins: &[Origin]) {
+    for origin in origins {
+        println!(
+            "  defined in {}:{} ({})",
+            origin.path.as_str().trim_start_matches('/'),
+            origin.line,
+            origin.addon
+        );
+    }
+}
+
+fn definitions(origins: &[Origin]) -> Vec<Definition> {
+    origins
+        .iter()
+        .map(|origin| Definition {
+            addon: origin.addon.clone(),
+            file: origin.path.as_str().trim_start_matches('/').to_string(),
+            line: origin.line,
+        })
+        .collect()
+}
+
+fn json_value(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Str(string) => string.value().into(),
+        Value::Number(number) => json_number(number),
+        Value::Expression(expression) => expression.to_string().into(),
+        Value::Array(array) | Value::UnexpectedArray(array) => {
+            array.items().iter().map(json_item).collect()
+        }
+        Value::Invalid(_) => serde_json::Value::Null,
+    }
+}
+
+fn json_item(item: &Item) -> serde_json::Value {
+    match item {
+        Item::Str(string) => string.value().into(),
+        Item::Number(number) => json_number(number),
+        Item::Array(items) => items.iter().map(json_item).collect(),
+        Item::Invalid(_) => serde_json::Value::Null,
+    }
+}
+
+fn json_number(number: &Number) -> serde_json::Value {
+    match number {
+        Number::Int32 { value, .. } => (*value).into(),
+        Number::Int64 { value, .. } => (*value).into(),
+        Number::Float32 { value, .. } => (*value).into(),
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum Query<'a> {
+    Property {
+        path: &'a str,
+        value: serde_json::Value,
+        source: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        inherited_from: Option<String>,
+        definitions: Vec<Definition>,
+    },
+    Class {
+        path: &'a str,
+        parent: Option<&'a str>,
+        parents: &'a [String],
+        definitions: Vec<Definition>,
+        properties: serde_json::Map<String, serde_json::Value>,
+    },
+}
+
+#[derive(Serialize)]
+struct Definition {
+    addon: String,
+    file: String,
+    line: usize,
+}
+
+struct NotFound {
+    message: String,
+}
+
+impl Code for NotFound {
+    fn ident(&self) -> &'static str {
+        "VC1"
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+}
@@ -3,6 +3,8 @@ use hemtt_common::version::Version;
 
 use crate::{context::Context, error::Error, report::Report};
 
+mod config;
+
 #[must_use]
 pub fn cli() -> Command {
     Command::new("value")
@@ -13,6 +15,9 @@ pub fn cli() -> Command {
                 .help("Name of the new value")
                 .required(true),
         )
+        .subcommand(config::cli())
+        .args_conflicts_with_subcommands(true)
+        .subcommand_negates_reqs(true)
 }
 
 #[allow(clippy::too_many_lines)]
@@ -24,6 +29,9 @@ pub fn cli() -> Command {
 /// # Panics
 /// If a name is not provided, but this is usually handled by clap
 pub fn execute(matches: &ArgMatches) -> Result<Report, Error> {
+    if let Some(("config", matches)) = matches.subcommand() {
+        return config::execute(matches);
+    }
     let default = String::new();
     let ctx = Context::new(None, crate::context::PreservePrevious::Remove, false)?;
     match matches
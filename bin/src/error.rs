@@ -15,10 +15,14 @@ pub enum Error {
     Preprocessor(#[from] hemtt_preprocessor::Error),
     #[error("PBO error: {0}")]
     Pbo(#[from] hemtt_pbo::Error),
+    #[error("P3D error: {0}")]
+    P3d(#[from] hemtt_p3d::Error),
     #[error("Prefix error: {0}")]
     Prefix(#[from] hemtt_common::prefix::Error),
     #[error("Signing error: {0}")]
     Signing(#[from] hemtt_signing::Error),
+    #[error("Signing key error: {0}")]
+    SigningKey(String),
     #[error("Version Error: {0}")]
     Version(#[from] hemtt_common::version::Error),
     #[error("Workspace Error: {0}")]
@@ -28,6 +32,9 @@ pub enum Error {
     #[error("Addon Error: {0}")]
     Addon(#[from] hemtt_workspace::addons::Error),
 
+    #[error("Verification failed: {0}")]
+    VerifyFailed(String),
+
     #[error("Update error: {0}")]
     Update(String),
 
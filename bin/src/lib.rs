@@ -27,11 +27,13 @@ pub fn cli() -> Command {
         .subcommand(commands::book::cli())
         .subcommand(commands::new::cli())
         .subcommand(commands::check::cli())
+        .subcommand(commands::fmt::cli())
         .subcommand(commands::localization::cli())
         .subcommand(commands::dev::cli())
         .subcommand(commands::launch::cli())
         .subcommand(commands::build::cli())
         .subcommand(commands::release::cli())
+        .subcommand(commands::keys::cli())
         .subcommand(commands::script::cli())
         .subcommand(commands::utils::cli())
         .subcommand(commands::value::cli())
@@ -140,6 +142,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
         Some(("new", matches)) => commands::new::execute(matches).map(Some),
         Some(("dev", matches)) => commands::dev::execute(matches, &[]).map(Some),
         Some(("check", _matches)) => commands::check::execute().map(Some),
+        Some(("fmt", matches)) => commands::fmt::execute(matches).map(Some),
         Some(("localization", matches)) => commands::localization::execute(matches)
             .map_err(std::convert::Into::into)
             .map(Some),
@@ -152,6 +155,9 @@ pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
         Some(("launch", matches)) => commands::launch::execute(matches)
             .map_err(std::convert::Into::into)
             .map(Some),
+        Some(("keys", matches)) => commands::keys::execute(matches)
+            .map_err(std::convert::Into::into)
+            .map(Some),
         Some(("script", matches)) => commands::script::execute(matches)
             .map_err(std::convert::Into::into)
             .map(Some),
@@ -171,9 +177,10 @@ pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
     };
     if let Some(report) = report? {
         report.write_to_stdout();
-        if !matches.subcommand_name().is_some_and(|s| {
-            s == "new" || s == "utils" || s == "wiki" || s == "book" || s == "localization"
-        }) {
+        if !matches
+            .subcommand_name()
+            .is_some_and(|s| ["new", "utils", "wiki", "book", "localization", "keys"].contains(&s))
+        {
             report.write_ci_annotations()?;
         }
         if report.failed() {
@@ -1,12 +1,18 @@
+use hemtt_workspace::WorkspacePath;
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use vfs::VfsFileType;
+
 use crate::{context::Context, error::Error, report::Report};
 
 mod binarize;
 mod file_patching;
 mod files;
+mod models;
 mod new;
 mod rapifier;
 mod sqf;
 mod stringtables;
+mod textures;
 
 pub mod archive;
 pub mod bom;
@@ -18,11 +24,13 @@ pub use binarize::Binarize;
 pub use file_patching::FilePatching;
 pub use files::Files;
 pub use hook::Hooks;
+pub use models::Models;
 pub use new::Licenses;
 pub use rapifier::Rapifier;
 pub use sign::Sign;
 pub use sqf::SQFCompiler;
-pub use stringtables::Stringtables;
+pub use stringtables::{StringtableReferences, Stringtables};
+pub use textures::Textures;
 
 pub trait Module {
     fn name(&self) -> &'static str;
@@ -69,3 +77,35 @@ pub trait Module {
         Ok(Report::new())
     }
 }
+
+/// Run `lint` on every file in the addons with the given extension, in parallel
+///
+/// # Errors
+/// [`Error`] if the addons can not be walked, or `lint` fails
+pub fn lint_files(
+    ctx: &Context,
+    extension: &str,
+    lint: impl Fn(&WorkspacePath) -> Result<Report, Error> + Sync,
+) -> Result<Report, Error> {
+    let mut entries = Vec::new();
+    for addon in ctx.addons() {
+        for entry in ctx.workspace_path().join(addon.folder())?.walk_dir()? {
+            if entry.metadata()?.file_type == VfsFileType::File
+                && entry
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+            {
+                entries.push(entry);
+            }
+        }
+    }
+    let reports = entries
+        .par_iter()
+        .map(&lint)
+        .collect::<Result<Vec<Report>, Error>>()?;
+    let mut report = Report::new();
+    for new_report in reports {
+        report.merge(new_report);
+    }
+    Ok(report)
+}
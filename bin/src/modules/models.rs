@@ -0,0 +1,38 @@
+use crate::{context::Context, error::Error, report::Report};
+use hemtt_p3d::{
+    analyze::{lint_check, lint_one},
+    P3D,
+};
+
+use super::{lint_files, Module};
+
+#[derive(Default)]
+pub struct Models;
+
+impl Module for Models {
+    fn name(&self) -> &'static str {
+        "Models"
+    }
+
+    fn check(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+        report.extend(lint_check(ctx.config().lints().models().clone()));
+        Ok(report)
+    }
+
+    fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        lint_files(ctx, "p3d", |entry| {
+            let mut report = Report::new();
+            // binarized models can not be linted, and are skipped
+            match P3D::read(&mut entry.open_file()?) {
+                Ok(p3d) => {
+                    report.extend(lint_one(&(p3d, entry.clone()), Some(ctx.config())));
+                }
+                Err(e) => {
+                    debug!("failed to read {} for linting: {}", entry.as_str(), e);
+                }
+            }
+            Ok(report)
+        })
+    }
+}
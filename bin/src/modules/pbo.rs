@@ -8,8 +8,11 @@ use hemtt_common::{
     prefix::{Prefix, FILES},
     version::Version,
 };
-use hemtt_pbo::WritablePbo;
-use hemtt_workspace::addons::{Addon, Location};
+use hemtt_pbo::StreamingPbo;
+use hemtt_workspace::{
+    addons::{Addon, Location},
+    WorkspacePath,
+};
 use vfs::VfsFileType;
 
 use crate::{context::Context, error::Error, progress::progress_bar, report::Report};
@@ -58,6 +61,7 @@ pub fn build(ctx: &Context, collapse: Collapse) -> Result<Report, Error> {
     Ok(Report::new())
 }
 
+#[allow(clippy::too_many_lines)]
 fn internal_build(
     ctx: &Context,
     addon: &Addon,
@@ -65,7 +69,7 @@ fn internal_build(
     version: &Version,
     git_hash: Option<&String>,
 ) -> Result<(), Error> {
-    let mut pbo = WritablePbo::new();
+    let mut pbo = StreamingPbo::new();
     let target = ctx.build_folder().expect("build folder exists");
 
     let pbo_name = addon.pbo_name(ctx.config().prefix());
@@ -156,9 +160,14 @@ fn internal_build(
                 .as_str()
                 .trim_start_matches(&format!("/{}/", addon.folder()))
                 .replace('/', "\\");
-            trace!("adding file {:?}", file);
-
-            pbo.add_file(file, entry.open_file()?)?;
+            let size = entry.metadata()?.len;
+            if hemtt_pbo::is_compressible(&file) && should_compress(ctx, addon, &entry)? {
+                trace!("adding compressed file {:?}", file);
+                pbo.add_file_compressed(file, entry, size)?;
+            } else {
+                trace!("adding file {:?}", file);
+                pbo.add_file(file, entry, size)?;
+            }
         }
     }
     for header in ctx.config().properties() {
@@ -169,6 +178,29 @@ fn internal_build(
             pbo.add_property(header.0, header.1.clone());
         }
     }
-    pbo.write(&mut File::create(target_pbo)?, true)?;
+    pbo.write(&mut File::create(target_pbo)?, true, |entry| {
+        entry.open_file().map_err(std::io::Error::other)
+    })?;
     Ok(())
 }
+
+/// Should the file be compressed, based on the project and addon configs
+fn should_compress(ctx: &Context, addon: &Addon, entry: &WorkspacePath) -> Result<bool, Error> {
+    for compress in ctx.config().files().compress() {
+        if glob::Pattern::new(compress)?.matches(entry.as_str()) {
+            return Ok(true);
+        }
+    }
+    if let Some(config) = addon.config() {
+        for compress in config.files().compress() {
+            if glob::Pattern::new(compress)?.matches(
+                entry
+                    .as_str()
+                    .trim_start_matches(&format!("/{}/", addon.folder())),
+            ) {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
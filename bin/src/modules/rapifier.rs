@@ -3,9 +3,15 @@ use std::{
     sync::atomic::{AtomicU16, Ordering},
 };
 
-use hemtt_config::{analyze::lint_check, parse, rapify::Rapify};
+use hemtt_config::{
+    analyze::{lint_check, lint_database},
+    database::Database,
+    parse,
+    rapify::Rapify,
+    Config, ConfigReport,
+};
 use hemtt_preprocessor::Processor;
-use hemtt_workspace::{addons::Addon, WorkspacePath};
+use hemtt_workspace::{addons::Addon, reporting::Processed, WorkspacePath};
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 use vfs::VfsFileType;
 
@@ -67,34 +73,53 @@ impl Module for Rapifier {
             .collect::<Result<Vec<_>, Error>>()?;
 
         let progress = progress_bar(entries.len() as u64).with_message("Rapifying Configs");
-        let reports = entries
+        let results = entries
             .par_iter()
             .map(|(addon, entry)| {
-                let report = rapify(addon, entry, ctx)?;
+                let (report, parsed) = rapify(addon, entry, ctx)?;
                 counter.fetch_add(1, Ordering::Relaxed);
                 progress.inc(1);
-                Ok(report)
+                Ok((
+                    report,
+                    parsed.map(|(config, processed)| (addon.name().to_string(), config, processed)),
+                ))
             })
-            .collect::<Result<Vec<Report>, Error>>()?;
+            .collect::<Result<Vec<_>, Error>>()?;
 
-        for new_report in reports {
+        let mut sources = Vec::new();
+        for (new_report, parsed) in results {
             report.merge(new_report);
+            sources.extend(parsed);
         }
 
         progress.finish_and_clear();
         info!("Rapified {} addon configs", counter.load(Ordering::Relaxed));
+
+        let database = Database::new(sources);
+        debug!(
+            "merged configs in load order: {}",
+            database.load_order().join(", ")
+        );
+        for code in lint_database(&database, Some(ctx.config())) {
+            report.push(code);
+        }
         Ok(report)
     }
 }
 
 #[allow(clippy::too_many_lines)]
-pub fn rapify(addon: &Addon, path: &WorkspacePath, ctx: &Context) -> Result<Report, Error> {
+/// Rapify a file, returning the parsed config and its preprocessed source if it is an addon's `config.cpp`
+pub fn rapify(
+    addon: &Addon,
+    path: &WorkspacePath,
+    ctx: &Context,
+) -> Result<(Report, Option<(Config, Processed)>), Error> {
     let mut report = Report::new();
     let processed = match Processor::run(path) {
         Ok(processed) => processed,
         Err((_, hemtt_preprocessor::Error::Code(e))) => {
             report.push(e);
-            return Ok(report);
+            return Ok((report, None));
         }
         Err((_, e)) => {
             return Err(e.into());
@@ -109,7 +134,7 @@ pub fn rapify(addon: &Addon, path: &WorkspacePath, ctx: &Context) -> Result<Repo
             for e in &errors {
                 report.push(e.clone());
             }
-            return Ok(report);
+            return Ok((report, None));
         }
     };
     configreport.warnings().into_iter().for_each(|e| {
@@ -119,8 +144,17 @@ pub fn rapify(addon: &Addon, path: &WorkspacePath, ctx: &Context) -> Result<Repo
         report.push(e.clone());
     });
     if !configreport.errors().is_empty() {
-        return Ok(report);
+        return Ok((report, None));
     }
+    super::stringtables::add_localizations(
+        addon,
+        &processed,
+        configreport
+            .config()
+            .localizations()
+            .into_iter()
+            .map(|string| (string.value().to_string(), string.span())),
+    );
     let out = if std::path::Path::new(&path.filename())
         .extension()
         .map_or(false, |ext| ext.eq_ignore_ascii_case("cpp"))
@@ -149,7 +183,7 @@ pub fn rapify(addon: &Addon, path: &WorkspacePath, ctx: &Context) -> Result<Repo
             "skipping rapify for {}, as instructed by preprocessor",
             out.as_str()
         );
-        return Ok(report);
+        return Ok((report, database_source(path, configreport, processed)));
     }
     let mut output = match out.create_file() {
         Ok(output) => output,
@@ -160,7 +194,16 @@ pub fn rapify(addon: &Addon, path: &WorkspacePath, ctx: &Context) -> Result<Repo
     if let Err(e) = configreport.config().rapify(&mut output, 0) {
         return Err(e.into());
     }
-    Ok(report)
+    Ok((report, database_source(path, configreport, processed)))
+}
+
+/// Only an addon's `config.cpp` is loaded by the game, and merged into the database
+fn database_source(
+    path: &WorkspacePath,
+    configreport: ConfigReport,
+    processed: Processed,
+) -> Option<(Config, Processed)> {
+    (path.filename() == "config.cpp").then(|| (configreport.into_config(), processed))
 }
 
 pub fn can_rapify(path: &str) -> bool {
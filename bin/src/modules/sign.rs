@@ -1,5 +1,6 @@
 use std::{
     fs::{create_dir_all, File},
+    path::{Path, PathBuf},
     sync::Arc,
 };
 
@@ -36,6 +37,8 @@ impl Module for Sign {
             Repository::discover(".")?;
         }
 
+        load_keys(ctx)?;
+
         let mut report = Report::new();
 
         ctx.addons().to_vec().iter().for_each(|addon| {
@@ -64,83 +67,144 @@ impl Module for Sign {
     }
 
     fn pre_release(&self, ctx: &Context) -> Result<Report, Error> {
-        let authority = get_authority(ctx, None)?;
-        let addons_key = BIPrivateKey::generate(1024, &authority)?;
-        create_dir_all(
-            ctx.build_folder()
+        let persistent = load_keys(ctx)?;
+        let addons_keys = if persistent.is_empty() {
+            vec![BIPrivateKey::generate(1024, &get_authority(ctx, None)?)?]
+        } else {
+            persistent.clone()
+        };
+        write_public_keys(
+            &addons_keys,
+            &ctx.build_folder()
                 .expect("build folder exists")
                 .join("keys"),
         )?;
-        addons_key.to_public_key().write(&mut File::create(
-            ctx.build_folder()
-                .expect("build folder exists")
-                .join("keys")
-                .join(format!("{authority}.bikey")),
-        )?)?;
         ctx.addons().to_vec().par_iter().try_for_each(|addon| {
             let pbo_name = addon.pbo_name(ctx.config().prefix());
-            let (mut pbo, sig_location, key) = match addon.location() {
-                Location::Addons => {
-                    let target_pbo = {
-                        let mut path = ctx
-                            .build_folder()
-                            .expect("build folder exists")
-                            .join("addons")
-                            .join(pbo_name);
-                        path.set_extension("pbo");
-                        path
-                    };
-                    (
-                        ReadablePbo::from(File::open(&target_pbo)?)?,
-                        target_pbo.with_extension(format!("pbo.{authority}.bisign")),
-                        addons_key.clone(),
-                    )
-                }
+            let (target_pbo, keys) = match addon.location() {
+                Location::Addons => (
+                    ctx.build_folder()
+                        .expect("build folder exists")
+                        .join("addons")
+                        .join(pbo_name),
+                    addons_keys.clone(),
+                ),
                 Location::Optionals => {
-                    let (mut target_pbo, key, authority) =
-                        if ctx.config().hemtt().build().optional_mod_folders() {
-                            let authority = get_authority(ctx, Some(&pbo_name))?;
-                            let key = BIPrivateKey::generate(1024, &authority)?;
-                            let mod_root = ctx
-                                .build_folder()
-                                .expect("build folder exists")
-                                .join("optionals")
-                                .join(format!(
-                                    "@{}",
-                                    addon.pbo_name(ctx.config().hemtt().release().folder())
-                                ));
-                            create_dir_all(mod_root.join("keys"))?;
-                            key.to_public_key().write(&mut File::create(
-                                mod_root.join("keys").join(format!("{authority}.bikey")),
-                            )?)?;
-                            (mod_root.join("addons").join(pbo_name), key, authority)
+                    if ctx.config().hemtt().build().optional_mod_folders() {
+                        let keys = if persistent.is_empty() {
+                            vec![BIPrivateKey::generate(
+                                1024,
+                                &get_authority(ctx, Some(&pbo_name))?,
+                            )?]
                         } else {
-                            (
-                                ctx.build_folder()
-                                    .expect("build folder exists")
-                                    .join(addon.location().to_string())
-                                    .join(pbo_name),
-                                addons_key.clone(),
-                                authority.clone(),
-                            )
+                            persistent.clone()
                         };
-                    target_pbo.set_extension("pbo");
-                    (
-                        ReadablePbo::from(File::open(&target_pbo)?)?,
-                        target_pbo.with_extension(format!("pbo.{authority}.bisign")),
-                        key,
-                    )
+                        let mod_root = ctx
+                            .build_folder()
+                            .expect("build folder exists")
+                            .join("optionals")
+                            .join(format!(
+                                "@{}",
+                                addon.pbo_name(ctx.config().hemtt().release().folder())
+                            ));
+                        write_public_keys(&keys, &mod_root.join("keys"))?;
+                        (mod_root.join("addons").join(pbo_name), keys)
+                    } else {
+                        (
+                            ctx.build_folder()
+                                .expect("build folder exists")
+                                .join(addon.location().to_string())
+                                .join(pbo_name),
+                            addons_keys.clone(),
+                        )
+                    }
                 }
             };
-            debug!("signing {:?}", sig_location.display());
-            let sig = key.sign(&mut pbo, ctx.config().signing().version())?;
-            sig.write(&mut File::create(sig_location)?)?;
+            let target_pbo = target_pbo.with_extension("pbo");
+            let mut pbo = ReadablePbo::from(File::open(&target_pbo)?)?;
+            for key in keys {
+                let sig_location =
+                    target_pbo.with_extension(format!("pbo.{}.bisign", key.authority()));
+                debug!("signing {:?}", sig_location.display());
+                let sig = key.sign(&mut pbo, ctx.config().signing().version())?;
+                sig.write(&mut File::create(sig_location)?)?;
+            }
             Result::<(), Error>::Ok(())
         })?;
         Ok(Report::new())
     }
 }
 
+/// Load the persistent keys set in the `[signing]` config
+///
+/// An empty list is returned if no keys are set, and a key should be generated for the release
+///
+/// # Errors
+/// [`Error::SigningKey`] if a key cannot be found, read, or is not valid
+pub fn load_keys(ctx: &Context) -> Result<Vec<BIPrivateKey>, Error> {
+    let signing = ctx.config().signing();
+    let primary = match signing.private_key_env() {
+        Some(var) => match std::env::var(var) {
+            Ok(path) if !path.is_empty() => Some(PathBuf::from(path)),
+            _ => {
+                let Some(path) = signing.private_key() else {
+                    return Err(Error::SigningKey(format!(
+                        "environment variable `{var}` is not set"
+                    )));
+                };
+                Some(PathBuf::from(path))
+            }
+        },
+        None => signing.private_key().map(PathBuf::from),
+    };
+    let Some(primary) = primary else {
+        if !signing.additional_keys().is_empty() {
+            return Err(Error::SigningKey(
+                "`additional_keys` requires `private_key` or `private_key_env` to be set"
+                    .to_string(),
+            ));
+        }
+        return Ok(Vec::new());
+    };
+    let mut keys: Vec<BIPrivateKey> = Vec::new();
+    for path in std::iter::once(primary).chain(signing.additional_keys().iter().map(PathBuf::from))
+    {
+        let key = read_key(&ctx.project_folder().join(path))?;
+        if keys.iter().any(|k| k.authority() == key.authority()) {
+            return Err(Error::SigningKey(format!(
+                "multiple keys have the authority `{}`",
+                key.authority()
+            )));
+        }
+        keys.push(key);
+    }
+    Ok(keys)
+}
+
+/// Read and validate a private key
+///
+/// # Errors
+/// [`Error::SigningKey`] if the key cannot be read, or is not valid
+pub fn read_key(path: &Path) -> Result<BIPrivateKey, Error> {
+    let key = File::open(path)
+        .map_err(hemtt_signing::Error::from)
+        .and_then(|mut file| BIPrivateKey::read(&mut file))
+        .and_then(|key| key.validate().map(|()| key))
+        .map_err(|e| Error::SigningKey(format!("`{}`: {e}", path.display())))?;
+    debug!("loaded key {} from {}", key.authority(), path.display());
+    Ok(key)
+}
+
+fn write_public_keys(keys: &[BIPrivateKey], folder: &Path) -> Result<(), Error> {
+    create_dir_all(folder)?;
+    for key in keys {
+        key.to_public_key().write(&mut File::create(
+            folder.join(format!("{}.bikey", key.authority())),
+        )?)?;
+    }
+    Ok(())
+}
+
 pub fn get_authority(ctx: &Context, suffix: Option<&str>) -> Result<String, Error> {
     let mut authority = format!(
         "{}_{}",
@@ -81,6 +81,13 @@ impl Module for SQFCompiler {
                 }
                 match hemtt_sqf::parser::run(&database, &processed) {
                     Ok(sqf) => {
+                        super::stringtables::add_localizations(
+                            addon,
+                            &processed,
+                            sqf.localizations()
+                                .into_iter()
+                                .map(|(key, span)| (key.to_string(), span)),
+                        );
                         let codes = analyze(
                             &sqf,
                             Some(ctx.config()),
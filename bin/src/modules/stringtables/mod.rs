@@ -1,11 +1,15 @@
-use std::{io::BufReader, sync::Arc};
+use std::{io::BufReader, ops::Range, sync::Arc};
 
 use hemtt_stringtable::{
-    analyze::{lint_all, lint_check, lint_one},
+    analyze::{
+        lint_all, lint_check, lint_localizations, lint_one, lints::_01_sorted::StringtableData,
+        Localizations,
+    },
     Project,
 };
 use hemtt_workspace::{
-    reporting::{Code, Diagnostic},
+    addons::Addon,
+    reporting::{Code, Diagnostic, Processed},
     WorkspacePath,
 };
 
@@ -29,40 +33,14 @@ impl Module for Stringtables {
 
     fn check(&self, ctx: &crate::context::Context) -> Result<crate::report::Report, crate::Error> {
         let mut report = Report::new();
-        report.extend(lint_check(ctx.config().lints().sqf().clone()));
+        report.extend(lint_check(ctx.config().lints().stringtables().clone()));
         Ok(report)
     }
 
     fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
         let mut report = Report::new();
 
-        let mut stringtables = Vec::new();
-        for root in ["addons", "optionals"] {
-            if !ctx.workspace_path().join(root)?.exists()? {
-                continue;
-            }
-            let paths = ctx
-                .workspace_path()
-                .join(root)
-                .expect("vfs issue")
-                .walk_dir()
-                .expect("vfs issue")
-                .into_iter()
-                .filter(|p| p.filename() == "stringtable.xml")
-                .collect::<Vec<_>>();
-            for path in paths {
-                if path.exists().expect("vfs issue") {
-                    let existing = path.read_to_string().expect("vfs issue");
-                    match Project::from_reader(BufReader::new(existing.as_bytes())) {
-                        Ok(project) => stringtables.push((project, path, existing)),
-                        Err(e) => {
-                            debug!("Failed to parse stringtable for {}: {}", path, e);
-                            report.push(Arc::new(CodeStringtableInvalid::new(path, e.to_string())));
-                        }
-                    }
-                }
-            }
-        }
+        let stringtables = stringtables(ctx, Some(&mut report))?;
 
         report.extend(lint_all(&stringtables, Some(ctx.config())));
 
@@ -74,6 +52,102 @@ impl Module for Stringtables {
     }
 }
 
+/// Checks the stringtable keys referenced by configs and scripts
+///
+/// Must be added after the modules that record the references, [`super::Rapifier`] and [`super::SQFCompiler`]
+#[derive(Debug, Default)]
+pub struct StringtableReferences;
+
+impl Module for StringtableReferences {
+    fn name(&self) -> &'static str {
+        "Stringtable References"
+    }
+
+    fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+        let references = ctx
+            .addons()
+            .iter()
+            .flat_map(|addon| addon.build_data().localizations())
+            .collect();
+        // Only the stringtables of addons being built, their keys can only be referenced by those addons
+        let stringtables = stringtables(ctx, None)?
+            .into_iter()
+            .filter(|(_, path, _)| {
+                ctx.addons().iter().any(|addon| {
+                    path.as_str()
+                        .trim_start_matches('/')
+                        .starts_with(&format!("{}/", addon.folder()))
+                })
+            })
+            .collect();
+        report.extend(lint_localizations(
+            &Localizations::new(stringtables, references),
+            Some(ctx.config()),
+        ));
+        Ok(report)
+    }
+}
+
+/// Read the stringtables of all addons, invalid stringtables are reported if a report is provided
+fn stringtables(
+    ctx: &Context,
+    mut report: Option<&mut Report>,
+) -> Result<Vec<StringtableData>, Error> {
+    let mut stringtables = Vec::new();
+    for root in ["addons", "optionals"] {
+        if !ctx.workspace_path().join(root)?.exists()? {
+            continue;
+        }
+        let paths = ctx
+            .workspace_path()
+            .join(root)
+            .expect("vfs issue")
+            .walk_dir()
+            .expect("vfs issue")
+            .into_iter()
+            .filter(|p| p.filename() == "stringtable.xml")
+            .collect::<Vec<_>>();
+        for path in paths {
+            if path.exists().expect("vfs issue") {
+                let existing = path.read_to_string().expect("vfs issue");
+                match Project::from_reader(BufReader::new(existing.as_bytes())) {
+                    Ok(project) => stringtables.push((project, path, existing)),
+                    Err(e) => {
+                        debug!("Failed to parse stringtable for {}: {}", path, e);
+                        if let Some(report) = report.as_mut() {
+                            report.push(Arc::new(CodeStringtableInvalid::new(path, e.to_string())));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(stringtables)
+}
+
+/// Record the stringtable keys referenced by a processed file, at their location in the original source
+pub fn add_localizations(
+    addon: &Addon,
+    processed: &Processed,
+    localizations: impl IntoIterator<Item = (String, Range<usize>)>,
+) {
+    addon
+        .build_data()
+        .add_localizations(localizations.into_iter().filter_map(|(key, span)| {
+            let start = processed.mapping(span.start)?;
+            let end = processed.mapping(span.end.saturating_sub(1).max(span.start))?;
+            let file = start.original().path().clone();
+            let span =
+                if end.original().path() == &file && end.original_end() > start.original_start() {
+                    start.original_start()..end.original_end()
+                } else {
+                    start.original_start()..start.original_end()
+                };
+            Some((key.trim_start_matches('$').to_string(), file, span))
+        }));
+}
+
 #[allow(clippy::module_name_repetitions)]
 pub struct CodeStringtableInvalid {
     path: WorkspacePath,
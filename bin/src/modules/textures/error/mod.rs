@@ -0,0 +1 @@
+pub mod bte1_conversion_failed;
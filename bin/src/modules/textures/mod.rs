@@ -0,0 +1,180 @@
+use std::{
+    io::{Read, Write},
+    sync::atomic::{AtomicU16, Ordering},
+};
+
+use hemtt_paa::{
+    analyze::{lint_check, lint_one},
+    PaXType, Paa,
+};
+use hemtt_workspace::{addons::Addon, WorkspacePath};
+use image::ImageFormat;
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use vfs::VfsFileType;
+
+use crate::{context::Context, error::Error, progress::progress_bar, report::Report};
+
+use self::error::bte1_conversion_failed::ConversionFailed;
+
+use super::{lint_files, Module};
+
+mod error;
+
+/// Extensions of the images that are converted to PAA
+const SOURCES: [&str; 2] = ["png", "tga"];
+
+#[derive(Default)]
+pub struct Textures {
+    check_only: bool,
+}
+
+impl Textures {
+    #[must_use]
+    pub const fn new(check_only: bool) -> Self {
+        Self { check_only }
+    }
+}
+
+impl Module for Textures {
+    fn name(&self) -> &'static str {
+        "Textures"
+    }
+
+    fn check(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+        report.extend(lint_check(ctx.config().lints().textures().clone()));
+        Ok(report)
+    }
+
+    fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+        if !self.check_only {
+            report.merge(convert_all(ctx)?);
+        }
+        report.merge(lint_all(ctx)?);
+        Ok(report)
+    }
+}
+
+/// Convert the images in every addon that has conversion enabled
+fn convert_all(ctx: &Context) -> Result<Report, Error> {
+    let mut report = Report::new();
+    let counter = AtomicU16::new(0);
+    let mut entries = Vec::new();
+    for addon in ctx.addons() {
+        let Some(config) = addon.config().filter(|config| config.textures().enabled()) else {
+            debug!("texture conversion not enabled for {}", addon.name());
+            continue;
+        };
+        let globs = config
+            .textures()
+            .exclude()
+            .iter()
+            .map(|file| glob::Pattern::new(file))
+            .collect::<Result<Vec<_>, _>>()?;
+        for entry in ctx.workspace_path().join(addon.folder())?.walk_dir()? {
+            if entry.metadata()?.file_type != VfsFileType::File || !is_source(&entry) {
+                continue;
+            }
+            let relative = entry
+                .as_str()
+                .trim_start_matches(&format!("/{}/", addon.folder()));
+            if globs.iter().any(|pat| pat.matches(relative)) {
+                debug!("skipping texture conversion of {}", entry.as_str());
+                continue;
+            }
+            entries.push((addon, entry));
+        }
+    }
+
+    let progress = progress_bar(entries.len() as u64).with_message("Converting Textures");
+    let reports = entries
+        .par_iter()
+        .map(|(addon, entry)| {
+            let report = convert(addon, entry)?;
+            if report.errors().is_empty() {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+            progress.inc(1);
+            Ok(report)
+        })
+        .collect::<Result<Vec<Report>, Error>>()?;
+
+    for new_report in reports {
+        report.merge(new_report);
+    }
+
+    progress.finish_and_clear();
+    info!("Converted {} textures", counter.load(Ordering::Relaxed));
+    Ok(report)
+}
+
+/// Run the texture lints on every PAA in the addons
+fn lint_all(ctx: &Context) -> Result<Report, Error> {
+    lint_files(ctx, "paa", |entry| {
+        let mut report = Report::new();
+        match Paa::read(entry.open_file()?) {
+            Ok(paa) => {
+                report.extend(lint_one(&(paa, entry.clone()), Some(ctx.config())));
+            }
+            Err(e) => {
+                debug!("failed to read {} for linting: {}", entry.as_str(), e);
+            }
+        }
+        Ok(report)
+    })
+}
+
+/// Convert an image to a PAA next to it, and remove the image from the build
+///
+/// The format is picked from the suffix of the file name.
+/// If a PAA already exists next to the image, it is kept and the image is only removed
+pub fn convert(addon: &Addon, entry: &WorkspacePath) -> Result<Report, Error> {
+    let mut report = Report::new();
+    let out = entry.with_extension("paa")?;
+    if out.exists()? {
+        debug!(
+            "{} already exists in {}, not converting {}",
+            out.filename(),
+            addon.name(),
+            entry.as_str()
+        );
+        entry.vfs().remove_file()?;
+        return Ok(report);
+    }
+    let mut data = Vec::new();
+    entry.open_file()?.read_to_end(&mut data)?;
+    let format = entry
+        .extension()
+        .and_then(ImageFormat::from_extension)
+        .expect("only known image extensions are converted");
+    let image = match image::load_from_memory_with_format(&data, format) {
+        Ok(image) => image.to_rgba8(),
+        Err(e) => {
+            report.push(ConversionFailed::code(
+                entry.as_str().trim_start_matches('/').to_string(),
+                e.to_string(),
+            ));
+            return Ok(report);
+        }
+    };
+    let pax = PaXType::suggested(&entry.filename(), image.pixels().any(|p| p.0[3] < 255));
+    let mut buffer = Vec::new();
+    if let Err(e) = Paa::write_as(&image, &pax, &mut buffer) {
+        report.push(ConversionFailed::code(
+            entry.as_str().trim_start_matches('/').to_string(),
+            e.to_string(),
+        ));
+        return Ok(report);
+    }
+    trace!("converted {} to {}", entry.as_str(), pax);
+    out.create_file()?.write_all(&buffer)?;
+    entry.vfs().remove_file()?;
+    Ok(report)
+}
+
+fn is_source(entry: &WorkspacePath) -> bool {
+    entry
+        .extension()
+        .is_some_and(|ext| SOURCES.contains(&ext.to_lowercase().as_str()))
+}
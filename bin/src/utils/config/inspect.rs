@@ -1,7 +1,11 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{io::Cursor, path::PathBuf, sync::Arc};
 
 use hemtt_common::config::ProjectConfig;
-use hemtt_config::ConfigReport;
+use hemtt_config::{
+    analyze::CfgPatch,
+    derapify::{Derapify, Rapified},
+    ConfigReport,
+};
 use hemtt_preprocessor::Processor;
 use hemtt_workspace::{
     reporting::{Code, WorkspaceFiles},
@@ -12,26 +16,28 @@ use crate::Error;
 
 /// Prints information about a config to stdout
 ///
+/// Rapified configs are printed back as source, with their enum table listed first
+///
 /// # Errors
 /// [`Error::Preprocessor`] if the file can not be preprocessed
+/// [`Error::Io`] if a rapified config can not be read
 pub fn inspect(file: &PathBuf) -> Result<(), Error> {
+    let data = std::fs::read(file)?;
+    if hemtt_config::derapify::is_rapified(&data) {
+        let rapified = Rapified::derapify(&mut Cursor::new(data))?;
+        println!("Config is rapified!");
+        print_patches(&rapified.config().get_patches());
+        print_enums(rapified.enums());
+        println!();
+        print!("{}", rapified.config());
+        return Ok(());
+    }
     let report = get_report(file)?;
     let workspacefiles = WorkspaceFiles::new();
     match report {
         Ok(report) => {
             println!("Config is valid!");
-            if report.patches().is_empty() {
-                println!(" - Contains no CfgPatches");
-            } else {
-                println!(" - Contains the following CfgPatches:");
-                for patch in report.patches() {
-                    println!(
-                        "    - {}, requires {}",
-                        patch.name().as_str(),
-                        patch.required_version()
-                    );
-                }
-            }
+            print_patches(report.patches());
             for code in report.codes() {
                 if let Some(diag) = code.diagnostic() {
                     eprintln!("{}", diag.to_string(&workspacefiles));
@@ -50,6 +56,41 @@ pub fn inspect(file: &PathBuf) -> Result<(), Error> {
     }
 }
 
+fn print_patches(patches: &[CfgPatch]) {
+    if patches.is_empty() {
+        println!(" - Contains no CfgPatches");
+    } else {
+        println!(" - Contains the following CfgPatches:");
+        for patch in patches {
+            println!(
+                "    - {}, requires {}",
+                patch.name().as_str(),
+                patch.required_version()
+            );
+            if !patch.required_addons().is_empty() {
+                println!(
+                    "      requires addons: {}",
+                    patch
+                        .required_addons()
+                        .iter()
+                        .map(hemtt_config::Str::value)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+        }
+    }
+}
+
+fn print_enums(enums: &[(String, i32)]) {
+    if !enums.is_empty() {
+        println!(" - Contains the following enums:");
+        for (name, value) in enums {
+            println!("    - {name} = {value}");
+        }
+    }
+}
+
 pub fn get_report(file: &PathBuf) -> Result<Result<ConfigReport, Vec<Arc<dyn Code>>>, Error> {
     assert!(file.is_file());
     let folder = PathBuf::from(&file)
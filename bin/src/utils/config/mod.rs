@@ -10,8 +10,8 @@ pub use inspect::inspect;
 
 #[must_use]
 pub fn cli() -> Command {
-    Command::new("paa")
-        .about("Commands for PAA files")
+    Command::new("config")
+        .about("Commands for Config files")
         .arg_required_else_help(true)
         .subcommand(
             Command::new("inspect").about("Inspect a config file").arg(
@@ -13,7 +13,9 @@ use crate::Error;
 pub fn cli() -> Command {
     Command::new("inspect")
         .about("Inspect an Arma file")
-        .long_about("Provides information about supported files. Supported: pbo, bikey, bisign")
+        .long_about(
+            "Provides information about supported files. Supported: pbo, paa, p3d, bikey, bisign, config",
+        )
         .arg(
             clap::Arg::new("file")
                 .help("File to inspect")
@@ -39,6 +41,9 @@ pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
         "paa" => {
             super::paa::inspect(File::open(&path)?)?;
         }
+        "p3d" => {
+            super::p3d::inspect(File::open(&path)?)?;
+        }
         "pbo" => {
             super::pbo::inspect(File::open(&path)?)?;
         }
@@ -48,7 +53,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
         "bisign" => {
             bisign(File::open(&path)?, &path)?;
         }
-        "cpp" | "hpp" => {
+        "cpp" | "hpp" | "bin" => {
             super::config::inspect(&path)?;
         }
         _ => {
@@ -69,6 +74,19 @@ pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
                 super::paa::inspect(file)?;
                 return Ok(());
             }
+            // Rapified config
+            if buf.starts_with(b"\0raP") {
+                warn!("The file appears to be a rapified config but does not have the .bin extension.");
+                super::config::inspect(&path)?;
+                return Ok(());
+            }
+            // P3D
+            if &buf[..4] == b"MLOD" || &buf[..4] == b"ODOL" {
+                warn!("The file appears to be a P3D but does not have the .p3d extension.");
+                file.seek(std::io::SeekFrom::Start(0))?;
+                super::p3d::inspect(file)?;
+                return Ok(());
+            }
             // BiSign
             if BISign::read(&mut file).is_ok() {
                 warn!("The file appears to be a BiSign but does not have the .bisign extension.");
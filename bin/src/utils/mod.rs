@@ -1,5 +1,6 @@
 pub mod config;
 pub mod inspect;
+pub mod p3d;
 pub mod paa;
 pub mod pbo;
 pub mod sqf;
@@ -0,0 +1,96 @@
+use std::{
+    collections::BTreeSet,
+    fs::File,
+    io::{Read, Seek},
+};
+
+use hemtt_p3d::{resolution, P3D};
+use term_table::{
+    row::Row,
+    table_cell::{Alignment, TableCell},
+    Table, TableStyle,
+};
+
+use crate::Error;
+
+/// Prints information about an MLOD P3D to stdout
+///
+/// # Errors
+/// [`Error::P3d`] if the file is not a valid [`P3D`]
+pub fn inspect(mut file: File) -> Result<(), Error> {
+    let mut signature = [0; 4];
+    file.read_exact(&mut signature)?;
+    file.seek(std::io::SeekFrom::Start(0))?;
+    if &signature == b"ODOL" {
+        println!("Binarized ODOL models are not supported");
+    } else {
+        mlod(&P3D::read(&mut file)?);
+    }
+    Ok(())
+}
+
+fn mlod(p3d: &P3D) {
+    println!("MLOD");
+    println!("  - Version: {}", p3d.version);
+    println!("LODs: {}", p3d.lods.len());
+    let mut table = header(&["Resolution", "Points", "Faces", "Selections"]);
+    for lod in &p3d.lods {
+        add_row(
+            &mut table,
+            &[
+                resolution::name(lod.resolution),
+                lod.points.len().to_string(),
+                lod.faces.len().to_string(),
+                lod.selections().count().to_string(),
+            ],
+        );
+    }
+    println!("{}", table.render());
+    list(
+        "Textures",
+        p3d.lods
+            .iter()
+            .flat_map(|lod| lod.faces.iter().map(|face| face.texture.as_str())),
+    );
+    list(
+        "Materials",
+        p3d.lods
+            .iter()
+            .flat_map(|lod| lod.faces.iter().map(|face| face.material.as_str())),
+    );
+}
+
+fn header(columns: &[&str]) -> Table {
+    let mut table = Table::new();
+    table.style = TableStyle::thin();
+    table.add_row(Row::new(columns.iter().map(|column| {
+        TableCell::builder(*column)
+            .alignment(Alignment::Center)
+            .build()
+    })));
+    table
+}
+
+fn add_row(table: &mut Table, cells: &[String]) {
+    let mut row = Row::new(
+        cells
+            .iter()
+            .map(|cell| TableCell::builder(cell).alignment(Alignment::Right).build()),
+    );
+    row.has_separator = table.rows.len() == 1;
+    table.add_row(row);
+}
+
+/// Prints the unique, non empty values
+fn list<'a>(title: &str, values: impl Iterator<Item = &'a str>) {
+    let values = values
+        .filter(|value| !value.is_empty())
+        .collect::<BTreeSet<_>>();
+    if values.is_empty() {
+        return;
+    }
+    println!("{title}: {}", values.len());
+    for value in values {
+        println!("  - {value}");
+    }
+}
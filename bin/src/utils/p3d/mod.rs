@@ -0,0 +1,41 @@
+use std::{fs::File, path::PathBuf};
+
+use clap::{ArgMatches, Command};
+
+use crate::Error;
+
+mod inspect;
+mod retexture;
+
+pub use inspect::inspect;
+
+#[must_use]
+pub fn cli() -> Command {
+    Command::new("p3d")
+        .about("Commands for P3D files")
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("inspect")
+                .about("Inspect a P3D")
+                .arg(clap::Arg::new("p3d").help("P3D to inspect").required(true)),
+        )
+        .subcommand(retexture::cli())
+}
+
+/// Execute the p3d command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+///
+/// # Panics
+/// If the args are not present from clap
+pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
+    match matches.subcommand() {
+        Some(("inspect", matches)) => inspect::inspect(File::open(PathBuf::from(
+            matches.get_one::<String>("p3d").expect("required"),
+        ))?),
+        Some(("retexture", matches)) => retexture::execute(matches),
+
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,101 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read, Seek},
+    path::{Path, PathBuf},
+};
+
+use clap::{ArgMatches, Command};
+use hemtt_p3d::P3D;
+
+use crate::Error;
+
+#[must_use]
+pub fn cli() -> Command {
+    Command::new("retexture")
+        .about("Replace the prefix of texture and material paths in MLOD models")
+        .long_about("Replace the prefix of texture and material paths in MLOD models\n\nPrefixes are matched ignoring case, and only on whole folders. Binarized models are skipped.")
+        .arg(
+            clap::Arg::new("path")
+                .help("Path to the P3D file or a folder to recursively retexture")
+                .required(true),
+        )
+        .arg(
+            clap::Arg::new("from")
+                .long("from")
+                .help("Prefix to replace")
+                .required(true),
+        )
+        .arg(
+            clap::Arg::new("to")
+                .long("to")
+                .help("Prefix to replace it with")
+                .required(true),
+        )
+}
+
+/// Execute the retexture command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+///
+/// # Panics
+/// If the args are not present from clap
+pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
+    let path = PathBuf::from(matches.get_one::<String>("path").expect("required"));
+    let from = matches
+        .get_one::<String>("from")
+        .expect("required")
+        .replace('/', "\\");
+    let to = matches
+        .get_one::<String>("to")
+        .expect("required")
+        .replace('/', "\\");
+    if path.is_dir() {
+        let mut files = 0;
+        for entry in walkdir::WalkDir::new(&path) {
+            let entry = entry?;
+            if entry.file_type().is_file()
+                && entry
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("p3d"))
+                && file(entry.path(), &from, &to)?
+            {
+                files += 1;
+            }
+        }
+        info!("Retextured {} models", files);
+    } else if !file(&path, &from, &to)? {
+        info!("No changes in `{}`", path.display());
+    }
+    Ok(())
+}
+
+/// Retexture a single model, returning if it was changed
+fn file(path: &Path, from: &str, to: &str) -> Result<bool, Error> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut signature = [0; 4];
+    reader.read_exact(&mut signature)?;
+    if &signature != b"MLOD" {
+        warn!("Skipping `{}`, it is not an MLOD", path.display());
+        return Ok(false);
+    }
+    reader.rewind()?;
+    let mut p3d = P3D::read(&mut reader)?;
+    drop(reader);
+    let changed = p3d.retexture(from, to);
+    if changed == 0 {
+        return Ok(false);
+    }
+    // write next to the model and replace it, so a failed write does not lose the original
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+    if let Err(e) = p3d.write(&mut File::create(&temp)?) {
+        let _ = std::fs::remove_file(&temp);
+        return Err(e.into());
+    }
+    std::fs::rename(&temp, path)?;
+    info!("Replaced {} paths in `{}`", changed, path.display());
+    Ok(true)
+}
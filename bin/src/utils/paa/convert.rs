@@ -1,19 +1,34 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use clap::{ArgMatches, Command};
+use hemtt_paa::{PaXType, Paa};
 
 use crate::Error;
 
 #[must_use]
 pub fn cli() -> Command {
     Command::new("convert")
-        .about("Convert a PAA to another format")
-        .arg(clap::Arg::new("paa").help("PAA to convert").required(true))
+        .about("Convert a PAA to another format, or an image to a PAA")
+        .long_about("Convert a PAA to another format, or an image to a PAA\n\nWhen the output is a PAA, the full mipmap chain is generated. The format is picked from the file name suffix unless --format is given.")
+        .arg(
+            clap::Arg::new("input")
+                .help("PAA or image to convert")
+                .required(true),
+        )
         .arg(
             clap::Arg::new("output")
                 .help("Where to save the file")
                 .required(true),
         )
+        .arg(
+            clap::Arg::new("format")
+                .long("format")
+                .short('f')
+                .help("PAA format to write, DXT1, DXT5, RGBA4, RGBA5, RGBA8 or GRAYA")
+                .value_parser(clap::builder::ValueParser::new(|s: &str| {
+                    s.parse::<PaXType>()
+                })),
+        )
 }
 
 /// Execute the convert command
@@ -21,17 +36,45 @@ pub fn cli() -> Command {
 /// # Errors
 /// [`Error`] depending on the modules
 pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
-    let paa = PathBuf::from(matches.get_one::<String>("paa").expect("required"));
+    let input = PathBuf::from(matches.get_one::<String>("input").expect("required"));
     let output = PathBuf::from(matches.get_one::<String>("output").expect("required"));
     if output.exists() {
         error!("Output file already exists");
         return Ok(());
     }
-    let paa = hemtt_paa::Paa::read(std::fs::File::open(paa)?)?;
-    if let Err(e) = paa.maps()[0].get_image().save(output) {
+    if is_paa(&output) {
+        let image = match image::open(&input) {
+            Ok(image) => image.to_rgba8(),
+            Err(e) => {
+                error!("Failed to read image: {}", e);
+                return Ok(());
+            }
+        };
+        let format = matches
+            .get_one::<PaXType>("format")
+            .cloned()
+            .unwrap_or_else(|| {
+                PaXType::suggested(
+                    &output.to_string_lossy(),
+                    image.pixels().any(|p| p.0[3] < 255),
+                )
+            });
+        let mut buffer = Vec::new();
+        Paa::write_as(&image, &format, &mut buffer)?;
+        std::fs::write(&output, buffer)?;
+        info!("Image converted to {format} PAA");
+        return Ok(());
+    }
+    let paa = Paa::read(std::fs::File::open(input)?)?;
+    if let Err(e) = paa.maps()[0].get_image()?.save(output) {
         error!("Failed to save PNG: {}", e);
     } else {
         info!("PAA converted");
     }
     Ok(())
 }
+
+fn is_paa(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("paa"))
+}
@@ -1,5 +1,7 @@
 use std::fs::File;
 
+use hemtt_paa::{Paa, Tagg};
+use serde::Serialize;
 use term_table::{
     row::Row,
     table_cell::{Alignment, TableCell},
@@ -13,9 +15,14 @@ use crate::Error;
 /// # Errors
 /// [`Error::Io`] if the file is not a valid [`hemtt_paa::Paa`]
 pub fn inspect(mut file: File) -> Result<(), Error> {
-    let paa = hemtt_paa::Paa::read(&mut file)?;
+    let paa = Paa::read(&mut file)?;
     println!("PAA");
     println!("  - Format: {}", paa.format());
+    for tagg in paa.taggs() {
+        if let Some((name, value)) = describe(tagg) {
+            println!("  - {name}: {value}");
+        }
+    }
     let maps = paa.maps();
     println!("Maps: {}", maps.len());
     let mut table = Table::new();
@@ -59,5 +66,78 @@ pub fn inspect(mut file: File) -> Result<(), Error> {
         table.add_row(row);
     }
     println!("{}", table.render());
+    let problems = paa.validate();
+    if !problems.is_empty() {
+        println!("Problems: {}", problems.len());
+        for problem in problems {
+            println!("  - {problem}");
+        }
+    }
+    Ok(())
+}
+
+/// Prints information about a PAA to stdout as JSON
+///
+/// # Errors
+/// [`Error::Io`] if the file is not a valid [`hemtt_paa::Paa`]
+pub fn inspect_json(mut file: File) -> Result<(), Error> {
+    let paa = Paa::read(&mut file)?;
+    let json = PaaJson {
+        format: paa.format().to_string(),
+        average_color: paa.average_color().map(|c| c.to_string()),
+        max_color: paa.max_color().map(|c| c.to_string()),
+        alpha: paa.flag().map(|f| f.to_string()),
+        swizzle: paa.swizzle().map(|s| s.to_string()),
+        procedural: paa.procedural().map(ToString::to_string),
+        offsets: paa.offsets().map(<[u32]>::to_vec).unwrap_or_default(),
+        taggs: paa.taggs().iter().map(|t| t.name().to_string()).collect(),
+        maps: paa
+            .maps()
+            .iter()
+            .map(|m| MapJson {
+                width: m.width(),
+                height: m.height(),
+                size: m.data().len(),
+                compressed: m.is_compressed(),
+            })
+            .collect(),
+        problems: paa.validate().iter().map(ToString::to_string).collect(),
+    };
+    println!("{}", serde_json::to_string_pretty(&json)?);
     Ok(())
 }
+
+/// A readable name and value for the taggs that are not shown elsewhere
+fn describe(tagg: &Tagg) -> Option<(&str, String)> {
+    match tagg {
+        Tagg::AverageColor(color) => Some(("Average Color", color.to_string())),
+        Tagg::MaxColor(color) => Some(("Max Color", color.to_string())),
+        Tagg::Flag(flag) => Some(("Alpha", flag.to_string())),
+        Tagg::Swizzle(swizzle) => Some(("Swizzle", swizzle.to_string())),
+        Tagg::Procedural(expression) => Some(("Procedural", expression.clone())),
+        Tagg::Offsets(_) => None,
+        Tagg::Unknown { name, data } => Some((name, format!("{} bytes", data.len()))),
+    }
+}
+
+#[derive(Serialize)]
+struct PaaJson {
+    format: String,
+    average_color: Option<String>,
+    max_color: Option<String>,
+    alpha: Option<String>,
+    swizzle: Option<String>,
+    procedural: Option<String>,
+    offsets: Vec<u32>,
+    taggs: Vec<String>,
+    maps: Vec<MapJson>,
+    problems: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct MapJson {
+    width: u16,
+    height: u16,
+    size: usize,
+    compressed: bool,
+}
@@ -18,7 +18,13 @@ pub fn cli() -> Command {
         .subcommand(
             Command::new("inspect")
                 .about("Inspect a PAA")
-                .arg(clap::Arg::new("paa").help("PAA to inspect").required(true)),
+                .arg(clap::Arg::new("paa").help("PAA to inspect").required(true))
+                .arg(
+                    clap::Arg::new("json")
+                        .long("json")
+                        .help("Output as JSON")
+                        .action(clap::ArgAction::SetTrue),
+                ),
         )
 }
 
@@ -33,9 +39,16 @@ pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
     match matches.subcommand() {
         Some(("convert", matches)) => convert::execute(matches),
 
-        Some(("inspect", matches)) => inspect::inspect(File::open(PathBuf::from(
-            matches.get_one::<String>("paa").expect("required"),
-        ))?),
+        Some(("inspect", matches)) => {
+            let file = File::open(PathBuf::from(
+                matches.get_one::<String>("paa").expect("required"),
+            ))?;
+            if matches.get_flag("json") {
+                inspect::inspect_json(file)
+            } else {
+                inspect::inspect(file)
+            }
+        }
 
         _ => unreachable!(),
     }
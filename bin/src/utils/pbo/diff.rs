@@ -0,0 +1,492 @@
+use std::{collections::BTreeMap, fs::File, io::Cursor, path::PathBuf};
+
+use clap::{ArgAction, ArgMatches, Command};
+use hemtt_config::{derapify::Derapify, Class, Config, Property, Value};
+use hemtt_pbo::{Checksum, Header, ReadablePbo};
+use serde::Serialize;
+use sha1::{Digest, Sha1};
+
+use crate::Error;
+
+#[must_use]
+pub fn cli() -> Command {
+    Command::new("diff")
+        .about("Compare two PBOs")
+        .long_about("Compare the properties, headers and contents of two PBOs")
+        .arg(clap::Arg::new("a").help("Original PBO").required(true))
+        .arg(clap::Arg::new("b").help("Changed PBO").required(true))
+        .arg(
+            clap::Arg::new("json")
+                .long("json")
+                .help("Output the differences as JSON")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+#[derive(Debug, Default, Serialize)]
+struct PboDiff {
+    properties: Vec<PropertyDiff>,
+    files: Vec<FileDiff>,
+}
+
+#[derive(Debug, Serialize)]
+struct PropertyDiff {
+    key: String,
+    a: Option<String>,
+    b: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct FileDiff {
+    name: String,
+    status: Status,
+    headers: Vec<HeaderDiff>,
+    sha1: (Option<String>, Option<String>),
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diff: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Added,
+    Removed,
+    Modified,
+}
+
+#[derive(Debug, Serialize)]
+struct HeaderDiff {
+    field: &'static str,
+    a: String,
+    b: String,
+}
+
+/// Execute the diff command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+///
+/// # Panics
+/// If the args are not present from clap
+pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
+    let a_path = PathBuf::from(matches.get_one::<String>("a").expect("required"));
+    let b_path = PathBuf::from(matches.get_one::<String>("b").expect("required"));
+    // SAFETY: the PBOs are only read while diffing, and are not expected to be modified meanwhile
+    let a = unsafe { ReadablePbo::mmap(&File::open(&a_path)?)? };
+    // SAFETY: as above
+    let b = unsafe { ReadablePbo::mmap(&File::open(&b_path)?)? };
+    let diff = diff(&a, &b)?;
+    if matches.get_flag("json") {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+    } else {
+        print(&diff, &a_path, &b_path);
+    }
+    Ok(())
+}
+
+fn diff<T: AsRef<[u8]>>(
+    a: &ReadablePbo<Cursor<T>>,
+    b: &ReadablePbo<Cursor<T>>,
+) -> Result<PboDiff, Error> {
+    let mut diff = PboDiff::default();
+
+    for (key, value) in a.properties() {
+        match b.properties().get(key) {
+            Some(other) if other == value => {}
+            other => diff.properties.push(PropertyDiff {
+                key: key.clone(),
+                a: Some(value.clone()),
+                b: other.cloned(),
+            }),
+        }
+    }
+    for (key, value) in b.properties() {
+        if !a.properties().contains_key(key) {
+            diff.properties.push(PropertyDiff {
+                key: key.clone(),
+                a: None,
+                b: Some(value.clone()),
+            });
+        }
+    }
+
+    let headers = |pbo: &ReadablePbo<Cursor<T>>| {
+        pbo.files()
+            .into_iter()
+            .map(|h| (h.filename().to_lowercase(), h))
+            .collect::<BTreeMap<_, _>>()
+    };
+    let a_headers = headers(a);
+    let b_headers = headers(b);
+    let mut names = a_headers.keys().chain(b_headers.keys()).collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+
+    for name in names {
+        let a_header = a_headers.get(name);
+        let b_header = b_headers.get(name);
+        let a_data = a_header
+            .map(|h| a.file_slice(h.filename()))
+            .transpose()?
+            .flatten();
+        let b_data = b_header
+            .map(|h| b.file_slice(h.filename()))
+            .transpose()?
+            .flatten();
+        let sha1 = (
+            a_data.as_deref().map(sha1_hex),
+            b_data.as_deref().map(sha1_hex),
+        );
+        let (status, headers) = match (a_header, b_header) {
+            (Some(a_header), Some(b_header)) => {
+                let headers = header_diff(a_header, b_header);
+                if headers.is_empty() && sha1.0 == sha1.1 {
+                    continue;
+                }
+                (Status::Modified, headers)
+            }
+            (Some(_), None) => (Status::Removed, Vec::new()),
+            (None, Some(_)) => (Status::Added, Vec::new()),
+            (None, None) => unreachable!("name comes from one of the pbos"),
+        };
+        let mut errors = Vec::new();
+        let diff_text = if sha1.0 == sha1.1 {
+            None
+        } else {
+            let mut text = |data: Option<&[u8]>, side: &str| {
+                data.map(|data| {
+                    readable(data).unwrap_or_else(|e| {
+                        errors.push(format!("{side}: {e}, comparing bytes"));
+                        None
+                    })
+                })
+            };
+            let a_text = text(a_data.as_deref(), "a");
+            let b_text = text(b_data.as_deref(), "b");
+            match (a_text, b_text) {
+                (Some(None), _) | (_, Some(None)) => None,
+                (a_text, b_text) => {
+                    let a_text = a_text.flatten().unwrap_or_default();
+                    let b_text = b_text.flatten().unwrap_or_default();
+                    Some(
+                        similar::TextDiff::from_lines(&a_text, &b_text)
+                            .unified_diff()
+                            .context_radius(3)
+                            .to_string(),
+                    )
+                }
+            }
+        };
+        diff.files.push(FileDiff {
+            name: a_header
+                .or(b_header)
+                .expect("name comes from one of the pbos")
+                .filename()
+                .to_string(),
+            status,
+            headers,
+            sha1,
+            diff: diff_text,
+            errors,
+        });
+    }
+    Ok(diff)
+}
+
+fn header_diff(a: &Header, b: &Header) -> Vec<HeaderDiff> {
+    let mut diffs = Vec::new();
+    let mut compare = |field: &'static str, a: String, b: String| {
+        if a != b {
+            diffs.push(HeaderDiff { field, a, b });
+        }
+    };
+    compare("mime", a.mime().to_string(), b.mime().to_string());
+    compare(
+        "original",
+        a.original().to_string(),
+        b.original().to_string(),
+    );
+    compare("size", a.size().to_string(), b.size().to_string());
+    compare(
+        "timestamp",
+        a.timestamp().to_string(),
+        b.timestamp().to_string(),
+    );
+    diffs
+}
+
+fn sha1_hex(data: &[u8]) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(data);
+    Checksum::from(hasher.finalize().to_vec()).hex()
+}
+
+/// Get a readable form of a file, if it has one
+///
+/// Rapified configs are derapified into one line per property
+///
+/// # Errors
+/// If the file is a rapified config that can not be derapified
+fn readable(data: &[u8]) -> Result<Option<String>, String> {
+    if hemtt_config::derapify::is_rapified(data) {
+        let config = Config::derapify(&mut Cursor::new(data))
+            .map_err(|e| format!("failed to derapify config: {e}"))?;
+        let mut lines = Vec::new();
+        flatten(&config.0, "", &mut lines);
+        return Ok(Some(lines.join("\n") + "\n"));
+    }
+    Ok(std::str::from_utf8(data)
+        .ok()
+        .filter(|text| !text.contains('\0'))
+        .map(ToString::to_string))
+}
+
+fn flatten(properties: &[Property], path: &str, lines: &mut Vec<String>) {
+    for property in properties {
+        match property {
+            Property::Entry { name, value, .. } => {
+                let suffix = match value {
+                    Value::Array(a) if a.expand() => "[] +=",
+                    Value::Array(_) => "[] =",
+                    _ => " =",
+                };
+                lines.push(format!("{path}{}{suffix} {value};", name.as_str()));
+            }
+            Property::Class(Class::Local {
+                name,
+                parent,
+                properties,
+                ..
+            }) => {
+                lines.push(parent.as_ref().map_or_else(
+                    || format!("{path}class {}", name.as_str()),
+                    |parent| format!("{path}class {}: {}", name.as_str(), parent.as_str()),
+                ));
+                flatten(properties, &format!("{path}{} >> ", name.as_str()), lines);
+            }
+            Property::Class(Class::External { name }) => {
+                lines.push(format!("{path}class {};", name.as_str()));
+            }
+            Property::Delete(name) => {
+                lines.push(format!("{path}delete {};", name.as_str()));
+            }
+            Property::Class(Class::Root { .. })
+            | Property::MissingSemicolon(..)
+            | Property::Exec(_) => {}
+        }
+    }
+}
+
+fn print(diff: &PboDiff, a: &std::path::Path, b: &std::path::Path) {
+    println!("--- {}", a.display());
+    println!("+++ {}", b.display());
+    if !diff.properties.is_empty() {
+        println!("Properties");
+        for property in &diff.properties {
+            match (&property.a, &property.b) {
+                (Some(a), Some(b)) => println!("  ~ {}: {a} -> {b}", property.key),
+                (Some(a), None) => println!("  - {}: {a}", property.key),
+                (None, Some(b)) => println!("  + {}: {b}", property.key),
+                (None, None) => {}
+            }
+        }
+    }
+    if !diff.files.is_empty() {
+        println!("Files");
+        for file in &diff.files {
+            let marker = match file.status {
+                Status::Added => '+',
+                Status::Removed => '-',
+                Status::Modified => '~',
+            };
+            println!("  {marker} {}", file.name);
+            for header in &file.headers {
+                println!("      {}: {} -> {}", header.field, header.a, header.b);
+            }
+            if file.status == Status::Modified && file.sha1.0 != file.sha1.1 {
+                println!(
+                    "      sha1: {} -> {}",
+                    file.sha1.0.as_deref().unwrap_or_default(),
+                    file.sha1.1.as_deref().unwrap_or_default()
+                );
+            }
+            for error in &file.errors {
+                println!("      {error}");
+            }
+            if let Some(diff) = &file.diff {
+                for line in diff.lines() {
+                    println!("      {line}");
+                }
+            }
+        }
+    }
+    let count = |status| diff.files.iter().filter(|f| f.status == status).count();
+    println!(
+        "{} properties changed, {} files added, {} removed, {} modified",
+        diff.properties.len(),
+        count(Status::Added),
+        count(Status::Removed),
+        count(Status::Modified)
+    );
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use hemtt_pbo::WritablePbo;
+
+    use super::*;
+
+    fn pbo(properties: &[(&str, &str)], files: &[(&str, &[u8])]) -> ReadablePbo<Cursor<Vec<u8>>> {
+        let mut pbo = WritablePbo::new();
+        for (key, value) in properties {
+            pbo.add_property(*key, *value);
+        }
+        for (name, data) in files {
+            pbo.add_file(*name, Cursor::new(data.to_vec())).unwrap();
+        }
+        let mut output = Vec::new();
+        pbo.write(&mut output, true).unwrap();
+        ReadablePbo::from(Cursor::new(output)).unwrap()
+    }
+
+    /// A rapified config with a single `version` property
+    fn rapified(version: i32) -> Vec<u8> {
+        let mut body = vec![0, 1, 1, 2];
+        body.extend_from_slice(b"version\0");
+        body.extend_from_slice(&version.to_le_bytes());
+        let mut data = b"\0raP\0\0\0\0\x08\0\0\0".to_vec();
+        let enum_offset = u32::try_from(16 + body.len()).unwrap();
+        data.extend_from_slice(&enum_offset.to_le_bytes());
+        data.extend_from_slice(&body);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn properties() {
+        let a = pbo(&[("prefix", "z\\test"), ("version", "1.0.0")], &[]);
+        let b = pbo(&[("prefix", "z\\test"), ("author", "Someone")], &[]);
+        let diff = diff(&a, &b).unwrap();
+        assert!(diff.files.is_empty());
+        assert_eq!(diff.properties.len(), 2);
+        assert_eq!(diff.properties[0].key, "version");
+        assert_eq!(diff.properties[0].a.as_deref(), Some("1.0.0"));
+        assert_eq!(diff.properties[0].b, None);
+        assert_eq!(diff.properties[1].key, "author");
+        assert_eq!(diff.properties[1].a, None);
+        assert_eq!(diff.properties[1].b.as_deref(), Some("Someone"));
+    }
+
+    #[test]
+    fn files() {
+        let a = pbo(
+            &[],
+            &[
+                ("same.txt", b"same"),
+                ("changed.txt", b"one\ntwo\n"),
+                ("removed.txt", b"removed"),
+            ],
+        );
+        let b = pbo(
+            &[],
+            &[
+                ("same.txt", b"same"),
+                ("changed.txt", b"one\nthree\n"),
+                ("added.bin", b"\0\x01"),
+            ],
+        );
+        let diff = diff(&a, &b).unwrap();
+        let names = diff
+            .files
+            .iter()
+            .map(|f| (f.name.as_str(), f.status))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                ("added.bin", Status::Added),
+                ("changed.txt", Status::Modified),
+                ("removed.txt", Status::Removed),
+            ]
+        );
+
+        let added = &diff.files[0];
+        assert_eq!(added.sha1.0, None);
+        assert_eq!(added.sha1.1.as_deref(), Some(sha1_hex(b"\0\x01").as_str()));
+        assert_eq!(added.diff, None);
+
+        let changed = &diff.files[1];
+        assert_eq!(changed.headers.len(), 2);
+        assert_eq!(changed.headers[0].field, "original");
+        assert_eq!(changed.headers[1].field, "size");
+        assert_eq!(changed.headers[1].a, "8");
+        assert_eq!(changed.headers[1].b, "10");
+        assert_eq!(
+            changed.sha1.0.as_deref(),
+            Some(sha1_hex(b"one\ntwo\n").as_str())
+        );
+        assert_eq!(
+            changed.sha1.1.as_deref(),
+            Some(sha1_hex(b"one\nthree\n").as_str())
+        );
+        let text = changed.diff.as_deref().unwrap();
+        assert!(text.contains("-two\n"));
+        assert!(text.contains("+three\n"));
+    }
+
+    #[test]
+    fn rapified_config() {
+        let a = pbo(&[], &[("config.bin", &rapified(1))]);
+        let b = pbo(&[], &[("config.bin", &rapified(2))]);
+        let diff = diff(&a, &b).unwrap();
+        assert_eq!(diff.files.len(), 1);
+        assert!(diff.files[0].errors.is_empty());
+        let text = diff.files[0].diff.as_deref().unwrap();
+        assert!(text.contains("-version = 1;\n"));
+        assert!(text.contains("+version = 2;\n"));
+    }
+
+    #[test]
+    fn rapified_config_invalid() {
+        let mut broken = rapified(2);
+        broken.truncate(20);
+        let a = pbo(&[], &[("config.bin", &rapified(1))]);
+        let b = pbo(&[], &[("config.bin", &broken)]);
+        let diff = diff(&a, &b).unwrap();
+        assert_eq!(diff.files[0].diff, None);
+        assert_eq!(diff.files[0].errors.len(), 1);
+        assert!(diff.files[0].errors[0].starts_with("b: failed to derapify config"));
+    }
+
+    #[test]
+    fn json() {
+        let a = pbo(&[("version", "1")], &[("a.txt", b"a\n")]);
+        let b = pbo(&[("version", "2")], &[("b.txt", b"b\n")]);
+        let json = serde_json::to_value(diff(&a, &b).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "properties": [{ "key": "version", "a": "1", "b": "2" }],
+                "files": [
+                    {
+                        "name": "a.txt",
+                        "status": "removed",
+                        "headers": [],
+                        "sha1": [sha1_hex(b"a\n"), null],
+                        "diff": "@@ -1 +0,0 @@\n-a\n",
+                    },
+                    {
+                        "name": "b.txt",
+                        "status": "added",
+                        "headers": [],
+                        "sha1": [null, sha1_hex(b"b\n")],
+                        "diff": "@@ -0,0 +1 @@\n+b\n",
+                    },
+                ],
+            })
+        );
+    }
+}
@@ -4,8 +4,10 @@ use clap::{ArgMatches, Command};
 
 use crate::Error;
 
+mod diff;
 mod extract;
 mod inspect;
+mod pack;
 mod unpack;
 
 pub use inspect::inspect;
@@ -17,6 +19,8 @@ pub fn cli() -> Command {
         .arg_required_else_help(true)
         .subcommand(extract::cli())
         .subcommand(unpack::cli())
+        .subcommand(pack::cli())
+        .subcommand(diff::cli())
         .subcommand(
             Command::new("inspect")
                 .about("Inspect a PBO")
@@ -35,6 +39,8 @@ pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
     match matches.subcommand() {
         Some(("extract", matches)) => extract::execute(matches),
         Some(("unpack", matches)) => unpack::execute(matches),
+        Some(("pack", matches)) => pack::execute(matches),
+        Some(("diff", matches)) => diff::execute(matches),
 
         Some(("inspect", matches)) => inspect::inspect(File::open(PathBuf::from(
             matches.get_one::<String>("pbo").expect("required"),
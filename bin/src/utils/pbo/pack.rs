@@ -0,0 +1,147 @@
+use std::{fs::File, path::PathBuf};
+
+use clap::{ArgAction, ArgMatches, Command};
+use hemtt_common::prefix::{Prefix, FILES};
+use hemtt_pbo::{BISignVersion, ReadablePbo, WritablePbo};
+use hemtt_signing::BIPrivateKey;
+
+use crate::Error;
+
+#[must_use]
+pub fn cli() -> Command {
+    Command::new("pack")
+        .about("Pack a folder into a PBO")
+        .long_about("Pack a folder into a PBO, the counterpart of `hemtt utils pbo unpack`")
+        .arg(
+            clap::Arg::new("folder")
+                .help("Folder to pack")
+                .required(true),
+        )
+        .arg(
+            clap::Arg::new("output")
+                .help("PBO file to create")
+                .required(true),
+        )
+        .arg(
+            clap::Arg::new("property")
+                .long("property")
+                .short('p')
+                .help("Property to add to the PBO, as `key=value`")
+                .action(ArgAction::Append),
+        )
+        .arg(
+            clap::Arg::new("sign")
+                .long("sign")
+                .help("Private key (.biprivatekey) to sign the PBO with"),
+        )
+        .arg(
+            clap::Arg::new("sign-version")
+                .long("sign-version")
+                .help("Version of the signature")
+                .value_parser(["2", "3"])
+                .default_value("3"),
+        )
+}
+
+/// Execute the pack command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+///
+/// # Panics
+/// If the args are not present from clap
+pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
+    let folder = PathBuf::from(matches.get_one::<String>("folder").expect("required"));
+    let output = PathBuf::from(matches.get_one::<String>("output").expect("required"));
+    if !folder.is_dir() {
+        error!("Folder `{}` does not exist", folder.display());
+        return Ok(());
+    }
+    if output.exists() {
+        error!("Output file already exists");
+        return Ok(());
+    }
+
+    let mut pbo = WritablePbo::new();
+    for entry in walkdir::WalkDir::new(&folder) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let name = entry
+            .path()
+            .strip_prefix(&folder)
+            .expect("walkdir entries are in the folder")
+            .to_string_lossy()
+            .replace('/', "\\");
+        if !name.contains('\\') {
+            let lower = name.to_lowercase();
+            if FILES.contains(&lower.as_str()) {
+                let content = std::fs::read_to_string(entry.path())?;
+                pbo.add_property("prefix", Prefix::new(&content)?.to_string());
+                if content.contains('=') {
+                    add_properties(&mut pbo, &content, &name);
+                }
+                continue;
+            }
+            if lower == "properties.txt" {
+                add_properties(&mut pbo, &std::fs::read_to_string(entry.path())?, &name);
+                continue;
+            }
+        }
+        trace!("adding file {:?}", name);
+        pbo.add_file(name, File::open(entry.path())?)?;
+    }
+
+    for property in matches.get_many::<String>("property").unwrap_or_default() {
+        let Some((key, value)) = property.split_once('=') else {
+            error!("Invalid property `{property}`, expected `key=value`");
+            return Ok(());
+        };
+        pbo.add_property(key.trim(), value.trim());
+    }
+
+    if !pbo.properties().contains_key("prefix") {
+        warn!("No prefix file found, the PBO will not have a prefix");
+    }
+
+    pbo.write(&mut File::create(&output)?, true)?;
+    info!(
+        "Packed {} files into {}",
+        pbo.files().len(),
+        output.display()
+    );
+
+    if let Some(key) = matches.get_one::<String>("sign") {
+        let key = BIPrivateKey::read(&mut File::open(key)?)?;
+        let version = match matches
+            .get_one::<String>("sign-version")
+            .expect("default value")
+            .as_str()
+        {
+            "2" => BISignVersion::V2,
+            _ => BISignVersion::V3,
+        };
+        let authority = key.to_public_key().authority().to_string();
+        let signature = key.sign(&mut ReadablePbo::from(File::open(&output)?)?, version)?;
+        let signature_path = output.with_extension(format!("pbo.{authority}.bisign"));
+        signature.write(&mut File::create(&signature_path)?)?;
+        info!("Signed with {}", signature_path.display());
+    }
+    Ok(())
+}
+
+/// Add the `key=value` lines of a file as properties, ignoring the prefix
+fn add_properties(pbo: &mut WritablePbo<File>, content: &str, source: &str) {
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        let Some((key, value)) = line.split_once('=') else {
+            warn!("Ignoring invalid line in `{source}`: {line}");
+            continue;
+        };
+        let key = key.trim();
+        if key.eq_ignore_ascii_case("prefix") {
+            continue;
+        }
+        pbo.add_property(key, value.trim());
+    }
+}
@@ -6,6 +6,7 @@ use std::{
 
 use clap::{ArgMatches, Command};
 use hemtt_pbo::ReadablePbo;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use crate::Error;
 
@@ -31,7 +32,8 @@ pub fn cli() -> Command {
 /// [`Error`] depending on the modules
 pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
     let path = PathBuf::from(matches.get_one::<String>("pbo").expect("required"));
-    let mut pbo = ReadablePbo::from(File::open(path)?)?;
+    // SAFETY: the PBO is only read while unpacking, and is not expected to be modified meanwhile
+    let pbo = unsafe { ReadablePbo::mmap(&File::open(path)?)? };
     let output = PathBuf::from(matches.get_one::<String>("output").expect("required"));
     if output.exists() {
         error!("Output directory already exists");
@@ -51,14 +53,13 @@ pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
             file.write_all(format!("{key}={value}\n").as_bytes())?;
         }
     }
-    for header in pbo.files() {
+    pbo.files().par_iter().try_for_each(|header| {
         let path = output.join(header.filename().replace('\\', "/"));
         std::fs::create_dir_all(path.parent().expect("must have parent, just joined"))?;
-        let mut out = File::create(path)?;
-        let mut file = pbo
-            .file(header.filename())?
+        let data = pbo
+            .file_slice(header.filename())?
             .expect("file must exist if header exists");
-        std::io::copy(&mut file, &mut out)?;
-    }
-    Ok(())
+        File::create(path)?.write_all(&data)?;
+        Result::<(), Error>::Ok(())
+    })
 }
@@ -1,24 +1,37 @@
 use std::path::PathBuf;
 
 use clap::{ArgMatches, Command};
-use hemtt_pbo::ReadablePbo;
+use hemtt_pbo::{BISignVersion, ReadablePbo};
 
 use crate::{
     utils::inspect::{bikey, bisign},
     Error,
 };
 
+mod release;
+
 #[must_use]
 pub fn cli() -> Command {
     Command::new("verify")
         .about("Verify a signed PBO")
-        .long_about("Check a .bisign file against a public key and PBO")
-        .arg(clap::Arg::new("pbo").help("PBO to verify").required(true))
+        .long_about(
+            "Check a .bisign file against a public key and PBO, or every signature in a release",
+        )
         .arg(
-            clap::Arg::new("bikey")
-                .help("BIKey to verify against")
+            clap::Arg::new("pbo")
+                .help("PBO, mod folder or release zip to verify")
                 .required(true),
         )
+        .arg(
+            clap::Arg::new("bikey")
+                .help("BIKey to verify against, required when verifying a single PBO"),
+        )
+        .arg(
+            clap::Arg::new("sign-version")
+                .long("sign-version")
+                .help("Expected version of the signatures in a release")
+                .value_parser(["2", "3"]),
+        )
 }
 
 /// Execute the verify command
@@ -29,13 +42,32 @@ pub fn cli() -> Command {
 /// # Panics
 /// If the args are not present from clap
 pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
-    let pbo_path = PathBuf::from(matches.get_one::<String>("pbo").expect("required"));
-    let bikey_path = PathBuf::from(matches.get_one::<String>("bikey").expect("required"));
+    let path = PathBuf::from(matches.get_one::<String>("pbo").expect("required"));
+    if path.is_dir()
+        || path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+    {
+        let version = matches
+            .get_one::<String>("sign-version")
+            .map(|v| match v.as_str() {
+                "2" => BISignVersion::V2,
+                _ => BISignVersion::V3,
+            });
+        return release::verify(&path, version);
+    }
+    let Some(bikey_path) = matches.get_one::<String>("bikey") else {
+        error!("A BIKey is required to verify a single PBO");
+        return Ok(());
+    };
+    verify_pbo(&path, &PathBuf::from(bikey_path))
+}
 
+fn verify_pbo(pbo_path: &PathBuf, bikey_path: &PathBuf) -> Result<(), Error> {
     debug!("Reading PBO: {:?}", &pbo_path);
-    let mut pbo = ReadablePbo::from(std::fs::File::open(&pbo_path)?)?;
+    let mut pbo = ReadablePbo::from(std::fs::File::open(pbo_path)?)?;
     debug!("Reading BIKey: {:?}", &bikey_path);
-    let publickey = bikey(std::fs::File::open(&bikey_path)?, &bikey_path)?;
+    let publickey = bikey(std::fs::File::open(bikey_path)?, bikey_path)?;
 
     let signature_path = {
         let mut pbo_path = pbo_path.clone();
@@ -91,6 +123,9 @@ pub fn execute(matches: &ArgMatches) -> Result<(), Error> {
         Err(hemtt_signing::Error::InvalidLength) => {
             error!("Verification Failed: Invalid length");
         }
+        Err(hemtt_signing::Error::InvalidKey(e)) => {
+            error!("Verification Failed: Invalid key: {e}");
+        }
         Err(hemtt_signing::Error::AuthorityMissing) => {
             error!("Verification Failed: Missing authority");
         }
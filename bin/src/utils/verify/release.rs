@@ -0,0 +1,466 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{Cursor, Read},
+    path::{Path, PathBuf},
+};
+
+use hemtt_pbo::{BISignVersion, ReadablePbo};
+use hemtt_signing::{BIPublicKey, BISign};
+use term_table::{
+    row::Row,
+    table_cell::{Alignment, TableCell},
+    Table, TableStyle,
+};
+use vfs::SeekAndRead;
+use zip::ZipArchive;
+
+use crate::Error;
+
+/// A built release, either a mod folder or a release zip
+enum Release {
+    Folder(PathBuf),
+    Zip(ZipArchive<File>),
+}
+
+impl Release {
+    fn open(path: &Path) -> Result<Self, Error> {
+        if path.is_dir() {
+            Ok(Self::Folder(path.to_path_buf()))
+        } else {
+            Ok(Self::Zip(ZipArchive::new(File::open(path)?)?))
+        }
+    }
+
+    /// All files in the release, relative and separated by `/`
+    fn files(&self) -> Result<Vec<String>, Error> {
+        match self {
+            Self::Folder(root) => {
+                let mut files = Vec::new();
+                for entry in walkdir::WalkDir::new(root) {
+                    let entry = entry?;
+                    if entry.file_type().is_file() {
+                        files.push(
+                            entry
+                                .path()
+                                .strip_prefix(root)
+                                .expect("walkdir entries are in the folder")
+                                .to_string_lossy()
+                                .replace('\\', "/"),
+                        );
+                    }
+                }
+                Ok(files)
+            }
+            Self::Zip(zip) => Ok(zip
+                .file_names()
+                .filter(|name| !name.ends_with('/'))
+                .map(ToString::to_string)
+                .collect()),
+        }
+    }
+
+    fn open_file(&mut self, name: &str) -> Result<Box<dyn SeekAndRead>, Error> {
+        match self {
+            Self::Folder(root) => Ok(Box::new(File::open(root.join(name))?)),
+            Self::Zip(zip) => {
+                let mut data = Vec::new();
+                zip.by_name(name)?.read_to_end(&mut data)?;
+                Ok(Box::new(Cursor::new(data)))
+            }
+        }
+    }
+}
+
+struct Signature {
+    path: String,
+    /// The PBO the signature is for, as named by the signature
+    pbo: String,
+    authority: String,
+}
+
+impl Signature {
+    /// Parse a signature path, `<name>.pbo.<authority>.bisign`, ignoring case
+    fn parse(path: &str) -> Option<Self> {
+        if !has_extension(path, "bisign") {
+            return None;
+        }
+        let stem = &path[..path.len() - ".bisign".len()];
+        let split = stem.to_lowercase().rfind(".pbo.")?;
+        Some(Self {
+            path: path.to_string(),
+            pbo: stem[..split + ".pbo".len()].to_string(),
+            authority: stem[split + ".pbo.".len()..].to_string(),
+        })
+    }
+}
+
+/// Verify every PBO in the `addons` folders of a release against every key in its `keys` folder
+///
+/// # Errors
+/// [`Error`] if the release cannot be read, or any signature is not valid
+pub fn verify(path: &Path, expected: Option<BISignVersion>) -> Result<(), Error> {
+    let mut release = Release::open(path)?;
+    let (rows, keys) = check(&mut release, expected)?;
+    if keys == 0 {
+        return Err(Error::VerifyFailed(format!(
+            "no keys found in `keys/` of {}",
+            path.display()
+        )));
+    }
+
+    let problems = rows.iter().filter(|r| r.status.is_some()).count();
+    print_table(&rows);
+    if problems == 0 {
+        info!("Verified {} signatures against {} keys", rows.len(), keys);
+        Ok(())
+    } else {
+        Err(Error::VerifyFailed(format!(
+            "found {problems} problems in {}",
+            path.display()
+        )))
+    }
+}
+
+/// Check the signatures of a release, returning a row for each signature or problem,
+/// and the number of keys found
+fn check(
+    release: &mut Release,
+    expected: Option<BISignVersion>,
+) -> Result<(Vec<VerifyRow>, usize), Error> {
+    let mut files = release.files()?;
+    files.sort_by_key(|f| f.to_lowercase());
+
+    let mut keys = Vec::new();
+    for file in files.iter().filter(|f| is_key(f)) {
+        let key = BIPublicKey::read(&mut release.open_file(file)?)?;
+        debug!("found key {} in {}", key.authority(), file);
+        keys.push(key);
+    }
+    if keys.is_empty() {
+        return Ok((Vec::new(), 0));
+    }
+
+    let mut signatures: HashMap<String, Vec<Signature>> = HashMap::new();
+    for signature in files
+        .iter()
+        .filter(|f| in_addons(f))
+        .filter_map(|f| Signature::parse(f))
+    {
+        signatures
+            .entry(signature.pbo.to_lowercase())
+            .or_default()
+            .push(signature);
+    }
+
+    let mut rows = Vec::new();
+    let mut versions = Vec::new();
+    for file in files
+        .iter()
+        .filter(|f| in_addons(f) && has_extension(f, "pbo"))
+    {
+        let pbo_signatures = signatures.remove(&file.to_lowercase()).unwrap_or_default();
+        let mut pbo = match ReadablePbo::from(release.open_file(file)?) {
+            Ok(pbo) => pbo,
+            Err(e) => {
+                rows.push(VerifyRow::new(file, "", &format!("Invalid PBO: {e}")));
+                continue;
+            }
+        };
+        for key in &keys {
+            let Some(signature) = pbo_signatures
+                .iter()
+                .find(|s| s.authority == key.authority())
+            else {
+                rows.push(VerifyRow::new(file, key.authority(), "Missing signature"));
+                continue;
+            };
+            let bisign = BISign::read(&mut release.open_file(&signature.path)?)?;
+            versions.push(bisign.version());
+            let status = match key.verify(&mut pbo, &bisign) {
+                Ok(()) => None,
+                Err(hemtt_signing::Error::AuthorityMismatch { sig, .. }) => {
+                    Some(format!("Authority mismatch, signed by `{sig}`"))
+                }
+                Err(hemtt_signing::Error::HashMismatch { .. }) => {
+                    Some("Stale signature".to_string())
+                }
+                Err(e) => Some(e.to_string()),
+            };
+            rows.push(VerifyRow {
+                pbo: file.clone(),
+                authority: key.authority().to_string(),
+                version: Some(bisign.version()),
+                status,
+            });
+        }
+        for signature in pbo_signatures
+            .iter()
+            .filter(|s| !keys.iter().any(|k| k.authority() == s.authority))
+        {
+            rows.push(VerifyRow::new(
+                file,
+                &signature.authority,
+                "No key for authority",
+            ));
+        }
+    }
+    let mut orphans = signatures.into_values().flatten().collect::<Vec<_>>();
+    orphans.sort_by(|a, b| a.path.cmp(&b.path));
+    for signature in orphans {
+        rows.push(VerifyRow::new(
+            &signature.pbo,
+            &signature.authority,
+            "Signature without PBO",
+        ));
+    }
+
+    let expected = expected.or_else(|| most_common(&versions));
+    if let Some(expected) = expected {
+        for row in &mut rows {
+            if row.status.is_none() && row.version.is_some_and(|v| v != expected) {
+                row.status = Some(format!("Expected {}", version_name(expected)));
+            }
+        }
+    }
+
+    Ok((rows, keys.len()))
+}
+
+struct VerifyRow {
+    pbo: String,
+    authority: String,
+    version: Option<BISignVersion>,
+    /// The problem with the signature, if there is one
+    status: Option<String>,
+}
+
+impl VerifyRow {
+    fn new(pbo: &str, authority: &str, status: &str) -> Self {
+        Self {
+            pbo: pbo.to_string(),
+            authority: authority.to_string(),
+            version: None,
+            status: Some(status.to_string()),
+        }
+    }
+}
+
+fn print_table(rows: &[VerifyRow]) {
+    let mut table = Table::new();
+    table.style = TableStyle::thin();
+    table.add_row(Row::new(
+        ["PBO", "Authority", "Version", "Status"]
+            .into_iter()
+            .map(|h| TableCell::builder(h).alignment(Alignment::Center).build()),
+    ));
+    for row in rows {
+        table.add_row(Row::new(vec![
+            TableCell::new(&row.pbo),
+            TableCell::new(&row.authority),
+            TableCell::new(row.version.map(version_name).unwrap_or_default()),
+            TableCell::new(row.status.as_deref().unwrap_or("Verified")),
+        ]));
+    }
+    println!("{}", table.render());
+}
+
+fn is_key(file: &str) -> bool {
+    has_extension(file, "bikey")
+        && Path::new(file)
+            .parent()
+            .and_then(Path::file_name)
+            .is_some_and(|dir| dir.eq_ignore_ascii_case("keys"))
+}
+
+/// Is the file directly in an `addons` folder
+fn in_addons(file: &str) -> bool {
+    Path::new(file)
+        .parent()
+        .and_then(Path::file_name)
+        .is_some_and(|dir| dir.eq_ignore_ascii_case("addons"))
+}
+
+fn has_extension(file: &str, extension: &str) -> bool {
+    Path::new(file)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+}
+
+fn most_common(versions: &[BISignVersion]) -> Option<BISignVersion> {
+    let v2 = versions.iter().filter(|v| **v == BISignVersion::V2).count();
+    let v3 = versions.len() - v2;
+    if versions.is_empty() {
+        None
+    } else if v2 > v3 {
+        Some(BISignVersion::V2)
+    } else {
+        Some(BISignVersion::V3)
+    }
+}
+
+const fn version_name(version: BISignVersion) -> &'static str {
+    match version {
+        BISignVersion::V2 => "V2",
+        BISignVersion::V3 => "V3",
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::io::Write;
+
+    use hemtt_pbo::WritablePbo;
+    use hemtt_signing::BIPrivateKey;
+    use sealed_test::prelude::*;
+
+    use super::*;
+
+    fn pbo(content: &str) -> Vec<u8> {
+        let mut pbo = WritablePbo::new();
+        pbo.add_property("prefix", "z\\test\\addons\\main");
+        pbo.add_file("config.cpp", Cursor::new(content.as_bytes().to_vec()))
+            .unwrap();
+        let mut output = Vec::new();
+        pbo.write(&mut output, true).unwrap();
+        output
+    }
+
+    fn sign(key: &BIPrivateKey, pbo: &[u8], version: BISignVersion) -> Vec<u8> {
+        let signature = key
+            .sign(&mut ReadablePbo::from(Cursor::new(pbo)).unwrap(), version)
+            .unwrap();
+        let mut output = Vec::new();
+        signature.write(&mut output).unwrap();
+        output
+    }
+
+    /// The files of a release with one of each problem
+    fn release() -> Vec<(&'static str, Vec<u8>)> {
+        let key = BIPrivateKey::generate(1024, "mod").unwrap();
+        let other = BIPrivateKey::generate(1024, "other").unwrap();
+        let mut bikey = Vec::new();
+        key.to_public_key().write(&mut bikey).unwrap();
+        let valid = pbo("valid");
+        let stale = pbo("stale");
+        vec![
+            ("keys/mod.bikey", bikey),
+            ("addons/valid.pbo", valid.clone()),
+            (
+                "addons/valid.pbo.mod.bisign",
+                sign(&key, &valid, BISignVersion::V3),
+            ),
+            ("addons/Upper.PBO", valid.clone()),
+            (
+                "addons/upper.pbo.mod.BISIGN",
+                sign(&key, &valid, BISignVersion::V3),
+            ),
+            ("addons/missing.pbo", pbo("missing")),
+            ("addons/stale.pbo", stale),
+            (
+                "addons/stale.pbo.mod.bisign",
+                sign(&key, &pbo("before"), BISignVersion::V3),
+            ),
+            ("addons/wrong.pbo", valid.clone()),
+            (
+                "addons/wrong.pbo.mod.bisign",
+                sign(&other, &valid, BISignVersion::V3),
+            ),
+            ("addons/unknown.pbo", valid.clone()),
+            (
+                "addons/unknown.pbo.mod.bisign",
+                sign(&key, &valid, BISignVersion::V3),
+            ),
+            (
+                "addons/unknown.pbo.other.bisign",
+                sign(&other, &valid, BISignVersion::V3),
+            ),
+            (
+                "addons/orphan.pbo.mod.bisign",
+                sign(&key, &valid, BISignVersion::V3),
+            ),
+            ("addons/v2.pbo", valid.clone()),
+            (
+                "addons/v2.pbo.mod.bisign",
+                sign(&key, &valid, BISignVersion::V2),
+            ),
+            ("optionals/ignored.pbo", pbo("ignored")),
+        ]
+    }
+
+    fn rows(release: &mut Release, prefix: &str) -> Vec<(String, String, Option<String>)> {
+        let (rows, keys) = check(release, None).unwrap();
+        assert_eq!(keys, 1);
+        rows.into_iter()
+            .map(|r| {
+                (
+                    r.pbo.strip_prefix(prefix).unwrap().to_string(),
+                    r.authority,
+                    r.status,
+                )
+            })
+            .collect()
+    }
+
+    fn expected() -> Vec<(String, String, Option<String>)> {
+        [
+            ("addons/missing.pbo", "mod", Some("Missing signature")),
+            ("addons/stale.pbo", "mod", Some("Stale signature")),
+            ("addons/unknown.pbo", "mod", None),
+            ("addons/unknown.pbo", "other", Some("No key for authority")),
+            ("addons/Upper.PBO", "mod", None),
+            ("addons/v2.pbo", "mod", Some("Expected V3")),
+            ("addons/valid.pbo", "mod", None),
+            (
+                "addons/wrong.pbo",
+                "mod",
+                Some("Authority mismatch, signed by `other`"),
+            ),
+            ("addons/orphan.pbo", "mod", Some("Signature without PBO")),
+        ]
+        .into_iter()
+        .map(|(pbo, authority, status)| {
+            (
+                pbo.to_string(),
+                authority.to_string(),
+                status.map(ToString::to_string),
+            )
+        })
+        .collect()
+    }
+
+    #[sealed_test]
+    fn folder_and_zip() {
+        let files = release();
+
+        for (name, data) in &files {
+            let path = Path::new("@mod").join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, data).unwrap();
+        }
+        let mut folder = Release::open(Path::new("@mod")).unwrap();
+        assert_eq!(rows(&mut folder, ""), expected());
+        assert!(verify(Path::new("@mod"), None).is_err());
+
+        let mut zip = zip::ZipWriter::new(File::create("release.zip").unwrap());
+        for (name, data) in &files {
+            zip.start_file(
+                format!("@mod/{name}"),
+                zip::write::SimpleFileOptions::default(),
+            )
+            .unwrap();
+            zip.write_all(data).unwrap();
+        }
+        zip.finish().unwrap();
+        let mut zip = Release::open(Path::new("release.zip")).unwrap();
+        assert_eq!(rows(&mut zip, "@mod/"), expected());
+    }
+
+    #[sealed_test]
+    fn no_keys() {
+        std::fs::create_dir_all("@mod/addons").unwrap();
+        std::fs::write("@mod/addons/main.pbo", pbo("main")).unwrap();
+        assert!(verify(Path::new("@mod"), None).is_err());
+    }
+}
@@ -0,0 +1,83 @@
+#![allow(clippy::unwrap_used)]
+
+use std::{fs::File, io::Read};
+
+use sealed_test::prelude::*;
+
+use hemtt::cli;
+use hemtt_pbo::ReadablePbo;
+use hemtt_signing::{BIPrivateKey, BISign};
+
+fn pack(args: &[&str]) {
+    let matches = cli().get_matches_from(
+        ["hemtt", "utils", "pbo", "pack"]
+            .iter()
+            .chain(args.iter())
+            .copied(),
+    );
+    hemtt::commands::utils::execute(matches.subcommand_matches("utils").unwrap()).unwrap();
+}
+
+fn folder() {
+    std::fs::create_dir_all("folder/data").unwrap();
+    std::fs::write("folder/$PBOPREFIX$", "z\\test\\addons\\main").unwrap();
+    std::fs::write("folder/properties.txt", "author=Someone\nprefix=ignored\n").unwrap();
+    std::fs::write("folder/config.cpp", "class CfgPatches {};").unwrap();
+    std::fs::write("folder/data/readme.txt", "hello").unwrap();
+}
+
+#[sealed_test]
+fn pack_folder() {
+    folder();
+    pack(&["folder", "main.pbo", "--property", "version=1.2.3"]);
+
+    let mut pbo = ReadablePbo::from(File::open("main.pbo").unwrap()).unwrap();
+    assert_eq!(
+        pbo.properties().get("prefix").map(String::as_str),
+        Some("z\\test\\addons\\main")
+    );
+    assert_eq!(
+        pbo.properties().get("author").map(String::as_str),
+        Some("Someone")
+    );
+    assert_eq!(
+        pbo.properties().get("version").map(String::as_str),
+        Some("1.2.3")
+    );
+    assert!(pbo.is_sorted().is_ok());
+
+    let mut filenames = pbo
+        .files()
+        .iter()
+        .map(|h| h.filename().to_string())
+        .collect::<Vec<_>>();
+    filenames.sort();
+    assert_eq!(filenames, vec!["config.cpp", "data\\readme.txt"]);
+
+    let mut content = String::new();
+    pbo.file("data\\readme.txt")
+        .unwrap()
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    assert_eq!(content, "hello");
+}
+
+#[sealed_test]
+fn pack_sign() {
+    folder();
+    let key = BIPrivateKey::generate(1024, "test_authority").unwrap();
+    key.write_danger(&mut File::create("test.biprivatekey").unwrap())
+        .unwrap();
+    pack(&["folder", "main.pbo", "--sign", "test.biprivatekey"]);
+
+    let signature =
+        BISign::read(&mut File::open("main.pbo.test_authority.bisign").unwrap()).unwrap();
+    assert_eq!(signature.authority(), "test_authority");
+    key.to_public_key()
+        .verify(
+            &mut ReadablePbo::from(File::open("main.pbo").unwrap()).unwrap(),
+            &signature,
+        )
+        .unwrap();
+}
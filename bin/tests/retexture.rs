@@ -0,0 +1,46 @@
+#![allow(clippy::unwrap_used)]
+
+use std::path::Path;
+
+use sealed_test::prelude::*;
+
+use hemtt::cli;
+use hemtt_p3d::P3D;
+
+#[sealed_test]
+fn retexture_in_place() {
+    std::fs::copy(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../libs/p3d/tests/ace_gunbag.p3d"),
+        "gunbag.p3d",
+    )
+    .unwrap();
+    let matches = cli().get_matches_from([
+        "hemtt",
+        "utils",
+        "p3d",
+        "retexture",
+        "gunbag.p3d",
+        "--from",
+        "z\\ace\\addons\\gunbag",
+        "--to",
+        "z\\abe\\addons\\bag",
+    ]);
+    hemtt::commands::utils::execute(matches.subcommand_matches("utils").unwrap()).unwrap();
+
+    assert!(!Path::new("gunbag.p3d.tmp").exists());
+    let p3d = P3D::read(&mut std::fs::File::open("gunbag.p3d").unwrap()).unwrap();
+    let textures = p3d
+        .lods
+        .iter()
+        .flat_map(|lod| &lod.faces)
+        .map(|face| face.texture.to_lowercase())
+        .filter(|texture| !texture.is_empty())
+        .collect::<Vec<_>>();
+    assert!(!textures.is_empty());
+    assert!(textures
+        .iter()
+        .all(|texture| !texture.contains("z\\ace\\addons\\gunbag")));
+    assert!(textures
+        .iter()
+        .any(|texture| texture.contains("z\\abe\\addons\\bag")));
+}
@@ -0,0 +1,137 @@
+#![allow(clippy::unwrap_used)]
+
+use std::{fs::File, io::Read, path::Path};
+
+use sealed_test::prelude::*;
+
+use hemtt::{cli, report::Report};
+use hemtt_paa::{PaXType, Paa};
+use hemtt_pbo::ReadablePbo;
+use image::{Rgba, RgbaImage};
+
+fn project(addons: &[(&str, Option<&str>)]) {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Textures\"\nprefix = \"tex\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    for (addon, config) in addons {
+        std::fs::create_dir_all(format!("addons/{addon}")).unwrap();
+        std::fs::write(
+            format!("addons/{addon}/config.cpp"),
+            format!("class CfgPatches {{ class tex_{addon} {{ units[] = {{}}; weapons[] = {{}}; requiredAddons[] = {{}}; }}; }};"),
+        )
+        .unwrap();
+        std::fs::write(
+            format!("addons/{addon}/$PBOPREFIX$"),
+            format!("z\\tex\\addons\\{addon}"),
+        )
+        .unwrap();
+        if let Some(config) = config {
+            std::fs::write(format!("addons/{addon}/addon.toml"), config).unwrap();
+        }
+    }
+}
+
+fn image(path: &str, size: u32, alpha: u8) {
+    let path = Path::new(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    RgbaImage::from_pixel(size, size, Rgba([200, 100, 50, alpha]))
+        .save(path)
+        .unwrap();
+}
+
+fn build() -> Report {
+    let matches = cli().get_matches_from(vec!["hemtt", "build", "--in-test"]);
+    hemtt::commands::build::execute(matches.subcommand_matches("build").unwrap()).unwrap()
+}
+
+fn pbo(addon: &str) -> ReadablePbo<File> {
+    ReadablePbo::from(File::open(format!(".hemttout/build/addons/tex_{addon}.pbo")).unwrap())
+        .unwrap()
+}
+
+fn file(pbo: &mut ReadablePbo<File>, name: &str) -> Option<Vec<u8>> {
+    pbo.file(name).unwrap().map(|mut file| {
+        let mut data = Vec::new();
+        file.read_to_end(&mut data).unwrap();
+        data
+    })
+}
+
+fn format(pbo: &mut ReadablePbo<File>, name: &str) -> PaXType {
+    Paa::read(std::io::Cursor::new(file(pbo, name).unwrap()))
+        .unwrap()
+        .format()
+        .clone()
+}
+
+#[sealed_test]
+fn convert_textures() {
+    project(&[
+        (
+            "main",
+            Some("[textures]\nenabled = true\nexclude = [\"raw/*\"]\n"),
+        ),
+        ("other", None),
+    ]);
+    image("addons/main/data/box_co.png", 8, 255);
+    image("addons/main/data/icon_ca.png", 8, 255);
+    image("addons/main/data/glass_co.png", 8, 128);
+    image("addons/main/raw/skip_co.png", 8, 255);
+    image("addons/main/data/hand_co.png", 8, 255);
+    let mut hand = Vec::new();
+    Paa::write_as(
+        &RgbaImage::from_pixel(8, 8, Rgba([0, 0, 0, 255])),
+        &PaXType::DXT5,
+        &mut hand,
+    )
+    .unwrap();
+    std::fs::write("addons/main/data/hand_co.paa", &hand).unwrap();
+    image("addons/other/data/thing_co.png", 8, 255);
+
+    let report = build();
+    assert!(report.errors().is_empty());
+
+    let mut main = pbo("main");
+    assert_eq!(format(&mut main, "data\\box_co.paa"), PaXType::DXT1);
+    assert_eq!(format(&mut main, "data\\icon_ca.paa"), PaXType::DXT5);
+    assert_eq!(format(&mut main, "data\\glass_co.paa"), PaXType::DXT5);
+    for source in [
+        "data\\box_co.png",
+        "data\\icon_ca.png",
+        "data\\glass_co.png",
+    ] {
+        assert!(file(&mut main, source).is_none(), "{source} was packed");
+    }
+
+    // a hand made PAA is kept, and its source image is left out
+    assert_eq!(file(&mut main, "data\\hand_co.paa").unwrap(), hand);
+    assert!(file(&mut main, "data\\hand_co.png").is_none());
+
+    // excluded images are packed as they are
+    assert!(file(&mut main, "raw\\skip_co.png").is_some());
+    assert!(file(&mut main, "raw\\skip_co.paa").is_none());
+
+    // conversion is opt-in
+    let mut other = pbo("other");
+    assert!(file(&mut other, "data\\thing_co.png").is_some());
+    assert!(file(&mut other, "data\\thing_co.paa").is_none());
+
+    // sources are not removed from the project
+    assert!(Path::new("addons/main/data/box_co.png").exists());
+    assert!(!Path::new("addons/main/data/box_co.paa").exists());
+}
+
+#[sealed_test]
+fn convert_textures_failed() {
+    project(&[("main", Some("[textures]\nenabled = true\n"))]);
+    image("addons/main/data/odd_co.png", 3, 255);
+
+    let report = build();
+    assert!(report.failed());
+    assert_eq!(report.errors().len(), 1);
+    assert_eq!(report.errors()[0].ident(), "BTE1");
+    assert!(Path::new("addons/main/data/odd_co.png").exists());
+}
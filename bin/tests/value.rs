@@ -0,0 +1,15 @@
+#![allow(clippy::unwrap_used)]
+
+use sealed_test::prelude::*;
+
+use hemtt::cli;
+
+#[sealed_test]
+fn value_config_absolute_include() {
+    std::env::set_current_dir(format!("{}/tests/alpha", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    // `main` includes `\a\b\addons\secondary\something.hpp`, which defines the value
+    let matches = cli().get_matches_from(vec!["hemtt", "value", "config", "MyMod/value"]);
+    let report =
+        hemtt::commands::value::execute(matches.subcommand_matches("value").unwrap()).unwrap();
+    assert!(report.errors().is_empty());
+}
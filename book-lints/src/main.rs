@@ -2,12 +2,15 @@ use std::sync::Arc;
 
 use arma3_wiki::Wiki;
 use hemtt_config::analyze::CONFIG_LINTS;
+use hemtt_p3d::analyze::MODEL_LINTS;
+use hemtt_paa::analyze::TEXTURE_LINTS;
 use hemtt_sqf::analyze::{
     lints::s02_event_handlers::{
         LintS02EventIncorrectCommand, LintS02EventInsufficientVersion, LintS02EventUnknown,
     },
     SqfLintData, SQF_LINTS,
 };
+use hemtt_stringtable::analyze::STRINGTABLE_LINTS;
 use hemtt_workspace::lint::Lints;
 use mdbook::{book::Chapter, preprocess::CmdPreprocessor};
 
@@ -30,6 +33,15 @@ fn main() {
                         if chapter.name == "SQF" {
                             sqf(chapter);
                         }
+                        if chapter.name == "Textures" {
+                            textures(chapter);
+                        }
+                        if chapter.name == "Models" {
+                            models(chapter);
+                        }
+                        if chapter.name == "Stringtables" {
+                            stringtables(chapter);
+                        }
                     }
                 }
             }
@@ -113,6 +125,99 @@ fn sqf(chapter: &mut Chapter) {
     chapter.content = output;
 }
 
+fn textures(chapter: &mut Chapter) {
+    let mut output = String::from("# Lints - Textures\n\n");
+    let mut lint_text: Vec<(u32, String)> = Vec::new();
+    for lint in TEXTURE_LINTS.iter() {
+        let mut text = String::new();
+        text.push_str(&format!("\n***\n## {}\n", lint.ident()));
+        text.push_str(&format!("Code: **L-T{}**  \n", lint.doc_ident()));
+        text.push_str(&format!(
+            "Default Severity: **{:?}** {}  \n",
+            lint.default_config().severity(),
+            if lint.default_config().enabled() {
+                ""
+            } else {
+                "(Disabled)"
+            },
+        ));
+        text.push_str(&format!(
+            "Minimum Severity: {:?}  \n",
+            lint.minimum_severity()
+        ));
+        text.push_str(&format!("\n{}\n", lint.description()));
+        text.push_str(&format!("\n{}\n", lint.documentation()));
+        lint_text.push((lint.sort(), text));
+    }
+    lint_text.sort_by(|a, b| a.0.cmp(&b.0));
+    for (_, text) in lint_text {
+        output.push_str(&text);
+    }
+    chapter.content = output;
+}
+
+fn models(chapter: &mut Chapter) {
+    let mut output = String::from("# Lints - Models\n\n");
+    let mut lint_text: Vec<(u32, String)> = Vec::new();
+    for lint in MODEL_LINTS.iter() {
+        let mut text = String::new();
+        text.push_str(&format!("\n***\n## {}\n", lint.ident()));
+        text.push_str(&format!("Code: **L-M{}**  \n", lint.doc_ident()));
+        text.push_str(&format!(
+            "Default Severity: **{:?}** {}  \n",
+            lint.default_config().severity(),
+            if lint.default_config().enabled() {
+                ""
+            } else {
+                "(Disabled)"
+            },
+        ));
+        text.push_str(&format!(
+            "Minimum Severity: {:?}  \n",
+            lint.minimum_severity()
+        ));
+        text.push_str(&format!("\n{}\n", lint.description()));
+        text.push_str(&format!("\n{}\n", lint.documentation()));
+        lint_text.push((lint.sort(), text));
+    }
+    lint_text.sort_by(|a, b| a.0.cmp(&b.0));
+    for (_, text) in lint_text {
+        output.push_str(&text);
+    }
+    chapter.content = output;
+}
+
+fn stringtables(chapter: &mut Chapter) {
+    let mut output = String::from("# Lints - Stringtables\n\n");
+    let mut lint_text: Vec<(u32, String)> = Vec::new();
+    for lint in STRINGTABLE_LINTS.iter() {
+        let mut text = String::new();
+        text.push_str(&format!("\n***\n## {}\n", lint.ident()));
+        text.push_str(&format!("Code: **L-L{}**  \n", lint.doc_ident()));
+        text.push_str(&format!(
+            "Default Severity: **{:?}** {}  \n",
+            lint.default_config().severity(),
+            if lint.default_config().enabled() {
+                ""
+            } else {
+                "(Disabled)"
+            },
+        ));
+        text.push_str(&format!(
+            "Minimum Severity: {:?}  \n",
+            lint.minimum_severity()
+        ));
+        text.push_str(&format!("\n{}\n", lint.description()));
+        text.push_str(&format!("\n{}\n", lint.documentation()));
+        lint_text.push((lint.sort(), text));
+    }
+    lint_text.sort_by(|a, b| a.0.cmp(&b.0));
+    for (_, text) in lint_text {
+        output.push_str(&text);
+    }
+    chapter.content = output;
+}
+
 fn highlight() {
     let wiki = Wiki::load(true);
 
@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 pub struct FilesConfig {
     /// Files to exclude from the PBO
     exclude: Vec<String>,
+    /// Files to compress in the PBO
+    compress: Vec<String>,
 }
 
 impl FilesConfig {
@@ -18,6 +20,11 @@ impl FilesConfig {
     pub(crate) fn exclude_mut(&mut self) -> &mut Vec<String> {
         &mut self.exclude
     }
+
+    /// Files to compress in the PBO
+    pub const fn compress(&self) -> &Vec<String> {
+        &self.compress
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -27,12 +34,16 @@ pub struct FilesSectionFile {
     #[serde(default)]
     /// Files to exclude from the PBO
     pub exclude: Vec<String>,
+    #[serde(default)]
+    /// Files to compress in the PBO
+    pub compress: Vec<String>,
 }
 
 impl From<FilesSectionFile> for FilesConfig {
     fn from(file: FilesSectionFile) -> Self {
         Self {
             exclude: file.exclude,
+            compress: file.compress,
         }
     }
 }
@@ -45,10 +56,12 @@ mod tests {
     fn fully_defined() {
         let toml = r#"
 exclude = ["test"]
+compress = ["*.sqf"]
 "#;
         let file: FilesSectionFile = toml::from_str(toml).expect("failed to deserialize");
         let config = FilesConfig::from(file);
         assert_eq!(config.exclude(), &["test"]);
+        assert_eq!(config.compress(), &["*.sqf"]);
     }
 
     #[test]
@@ -57,5 +70,6 @@ exclude = ["test"]
         let file: FilesSectionFile = toml::from_str(toml).expect("failed to deserialize");
         let config = FilesConfig::from(file);
         assert!(config.exclude().is_empty());
+        assert!(config.compress().is_empty());
     }
 }
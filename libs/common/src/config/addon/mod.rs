@@ -1,6 +1,7 @@
 mod binarize;
 mod files;
 mod rapify;
+mod textures;
 
 use std::{collections::HashMap, sync::Once};
 
@@ -24,6 +25,9 @@ pub struct AddonConfig {
 
     /// Files to exclude from the pbo
     files: files::FilesConfig,
+
+    /// Texture conversion config
+    textures: textures::TexturesConfig,
 }
 
 impl AddonConfig {
@@ -51,6 +55,12 @@ impl AddonConfig {
         &self.files
     }
 
+    #[must_use]
+    /// Texture conversion config
+    pub const fn textures(&self) -> &textures::TexturesConfig {
+        &self.textures
+    }
+
     /// Load a configuration from a file.
     ///
     /// # Errors
@@ -83,6 +93,9 @@ pub struct AddonFile {
 
     #[serde(default)]
     files: files::FilesSectionFile,
+
+    #[serde(default)]
+    textures: textures::TexturesSectionFile,
 }
 
 static DEPRECATION: Once = Once::new();
@@ -134,6 +147,7 @@ impl From<AddonFile> for AddonConfig {
                 files.exclude_mut().extend(file.exclude);
                 files
             },
+            textures: file.textures.into(),
         }
     }
 }
@@ -157,6 +171,10 @@ test = "test"
 [files]
 exclude = ["test"]
 
+[textures]
+enabled = false
+exclude = ["data/ui/*.png"]
+
 "#;
         let file: AddonFile = toml::from_str(toml).expect("failed to deserialize");
         let config = AddonConfig::from(file);
@@ -164,6 +182,8 @@ exclude = ["test"]
         assert!(config.binarize().enabled());
         assert_eq!(config.properties().get("test"), Some(&"test".to_string()));
         assert_eq!(config.files().exclude(), &["test"]);
+        assert!(!config.textures().enabled());
+        assert_eq!(config.textures().exclude(), &["data/ui/*.png"]);
     }
 
     #[test]
@@ -175,6 +195,7 @@ exclude = ["test"]
         assert!(config.binarize().enabled());
         assert!(config.properties().is_empty());
         assert!(config.files().exclude().is_empty());
+        assert!(!config.textures().enabled());
     }
 
     #[test]
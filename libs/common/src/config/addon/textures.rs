@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+/// Texture conversion config
+pub struct TexturesConfig {
+    enabled: bool,
+    exclude: Vec<String>,
+}
+
+impl TexturesConfig {
+    #[must_use]
+    /// Is texture conversion enabled
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[must_use]
+    /// Images to exclude from texture conversion
+    pub const fn exclude(&self) -> &Vec<String> {
+        &self.exclude
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// Section of the addon.toml file for texture conversion
+pub struct TexturesSectionFile {
+    #[serde(default)]
+    enabled: Option<bool>,
+    #[serde(default)]
+    exclude: Vec<String>,
+}
+
+impl From<TexturesSectionFile> for TexturesConfig {
+    fn from(file: TexturesSectionFile) -> Self {
+        Self {
+            enabled: file.enabled.unwrap_or(false),
+            exclude: file.exclude,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_defined() {
+        let toml = r#"
+enabled = true
+exclude = ["test"]
+"#;
+        let file: TexturesSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = TexturesConfig::from(file);
+        assert!(config.enabled());
+        assert_eq!(config.exclude(), &["test"]);
+    }
+
+    #[test]
+    fn default() {
+        let toml = "";
+        let file: TexturesSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = TexturesConfig::from(file);
+        assert!(!config.enabled());
+        assert!(config.exclude().is_empty());
+    }
+}
@@ -11,6 +11,7 @@ mod project;
 pub use addon::AddonConfig;
 pub use pdrive::PDriveOption;
 pub use project::{
+    format::FormatConfig,
     hemtt::launch::LaunchOptions,
     lint::{LintConfig, LintConfigOverride},
     ProjectConfig,
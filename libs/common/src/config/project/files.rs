@@ -8,6 +8,8 @@ pub struct FilesConfig {
     include: Vec<String>,
     /// Files to exclude from the PBO
     exclude: Vec<String>,
+    /// Files to compress in the PBO, supports glob patterns
+    compress: Vec<String>,
 }
 
 impl FilesConfig {
@@ -20,6 +22,11 @@ impl FilesConfig {
     pub fn exclude(&self) -> &[String] {
         &self.exclude
     }
+
+    /// Files to compress in the PBO, supports glob patterns
+    pub fn compress(&self) -> &[String] {
+        &self.compress
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -31,6 +38,9 @@ pub struct FilesSectionFile {
     #[serde(default)]
     /// Files to be excluded from being included in PBO files, supports glob patterns
     exclude: Vec<String>,
+    #[serde(default)]
+    /// Files to be compressed in PBO files, supports glob patterns
+    compress: Vec<String>,
 }
 
 impl From<FilesSectionFile> for FilesConfig {
@@ -65,6 +75,7 @@ impl From<FilesSectionFile> for FilesConfig {
                 files
             },
             exclude: file.exclude,
+            compress: file.compress,
         }
     }
 }
@@ -78,11 +89,13 @@ mod tests {
         let toml = r#"
 include = ["test"]
 exclude = ["test"]
+compress = ["*.sqf"]
 "#;
         let file: FilesSectionFile = toml::from_str(toml).expect("failed to deserialize");
         let config = FilesConfig::from(file);
         assert!(config.include().contains(&"/test".to_string()));
         assert_eq!(config.exclude(), &["test"]);
+        assert_eq!(config.compress(), &["*.sqf"]);
     }
 
     #[test]
@@ -92,5 +105,6 @@ exclude = ["test"]
         let config = FilesConfig::from(file);
         assert!(config.include().contains(&"/mod.cpp".to_string()));
         assert!(config.exclude().is_empty());
+        assert!(config.compress().is_empty());
     }
 }
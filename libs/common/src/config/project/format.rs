@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Configuration for `hemtt fmt`
+pub struct FormatConfig {
+    /// Number of spaces for each level of indentation
+    indent_size: usize,
+    /// Indent with tabs instead of spaces
+    use_tabs: bool,
+    /// Arrays longer than this are wrapped to one value per line
+    max_width: usize,
+    /// Files that are not formatted, supports glob patterns
+    exclude: Vec<String>,
+}
+
+impl FormatConfig {
+    #[must_use]
+    /// Number of spaces for each level of indentation
+    pub const fn indent_size(&self) -> usize {
+        self.indent_size
+    }
+
+    #[must_use]
+    /// Indent with tabs instead of spaces
+    pub const fn use_tabs(&self) -> bool {
+        self.use_tabs
+    }
+
+    #[must_use]
+    /// Arrays longer than this are wrapped to one value per line
+    pub const fn max_width(&self) -> usize {
+        self.max_width
+    }
+
+    #[must_use]
+    /// Files that are not formatted, supports glob patterns
+    pub fn exclude(&self) -> &[String] {
+        &self.exclude
+    }
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        FormatSectionFile::default().into()
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(PartialEq, Eq, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct FormatSectionFile {
+    #[serde(default)]
+    /// Number of spaces for each level of indentation, defaults to 4
+    indent_size: Option<usize>,
+    #[serde(default)]
+    /// Indent with tabs instead of spaces
+    use_tabs: Option<bool>,
+    #[serde(default)]
+    /// Arrays longer than this are wrapped to one value per line, defaults to 100
+    max_width: Option<usize>,
+    #[serde(default)]
+    /// Files that are not formatted, supports glob patterns
+    exclude: Vec<String>,
+}
+
+impl From<FormatSectionFile> for FormatConfig {
+    fn from(file: FormatSectionFile) -> Self {
+        Self {
+            indent_size: file.indent_size.unwrap_or(4),
+            use_tabs: file.use_tabs.unwrap_or_default(),
+            max_width: file.max_width.unwrap_or(100),
+            exclude: file.exclude,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_defined() {
+        let toml = r#"
+indent_size = 2
+use_tabs = true
+max_width = 80
+exclude = ["addons/main/include/*.hpp"]
+"#;
+        let file: FormatSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = FormatConfig::from(file);
+        assert_eq!(config.indent_size(), 2);
+        assert!(config.use_tabs());
+        assert_eq!(config.max_width(), 80);
+        assert_eq!(config.exclude(), &["addons/main/include/*.hpp"]);
+    }
+
+    #[test]
+    fn default() {
+        let toml = "";
+        let file: FormatSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = FormatConfig::from(file);
+        assert_eq!(config.indent_size(), 4);
+        assert!(!config.use_tabs());
+        assert_eq!(config.max_width(), 100);
+        assert!(config.exclude().is_empty());
+    }
+}
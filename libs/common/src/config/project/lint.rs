@@ -10,6 +10,8 @@ pub struct LintGroupConfig {
     config: HashMap<String, LintConfigOverride>,
     sqf: HashMap<String, LintConfigOverride>,
     stringtables: HashMap<String, LintConfigOverride>,
+    textures: HashMap<String, LintConfigOverride>,
+    models: HashMap<String, LintConfigOverride>,
 }
 
 impl LintGroupConfig {
@@ -31,8 +33,24 @@ impl LintGroupConfig {
         &self.stringtables
     }
 
+    #[must_use]
+    /// Get the textures lints
+    pub const fn textures(&self) -> &HashMap<String, LintConfigOverride> {
+        &self.textures
+    }
+
+    #[must_use]
+    /// Get the models lints
+    pub const fn models(&self) -> &HashMap<String, LintConfigOverride> {
+        &self.models
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.config.is_empty() && self.sqf.is_empty()
+        self.config.is_empty()
+            && self.sqf.is_empty()
+            && self.stringtables.is_empty()
+            && self.textures.is_empty()
+            && self.models.is_empty()
     }
 }
 
@@ -154,6 +172,8 @@ pub struct LintSectionFile {
     pub config: Option<HashMap<String, LintConfigFile>>,
     pub sqf: Option<HashMap<String, LintConfigFile>>,
     pub stringtables: Option<HashMap<String, LintConfigFile>>,
+    pub textures: Option<HashMap<String, LintConfigFile>>,
+    pub models: Option<HashMap<String, LintConfigFile>>,
 }
 
 impl From<LintSectionFile> for LintGroupConfig {
@@ -177,6 +197,18 @@ impl From<LintSectionFile> for LintGroupConfig {
                 .into_iter()
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
+            textures: file
+                .textures
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect(),
+            models: file
+                .models
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect(),
         }
     }
 }
@@ -269,6 +301,36 @@ options.test = true
         assert_eq!(example.option("test"), Some(&toml::Value::Boolean(true)));
     }
 
+    #[test]
+    fn textures() {
+        let toml = "
+[textures.max_resolution]
+options.max = 2048
+";
+        let file: LintSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = LintGroupConfig::from(file);
+        let max = config
+            .textures()
+            .get("max_resolution")
+            .expect("max_resolution exists");
+        assert_eq!(max.option("max"), Some(&toml::Value::Integer(2048)));
+    }
+
+    #[test]
+    fn models() {
+        let toml = "
+[models.missing_geometry_lods]
+enabled = false
+";
+        let file: LintSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = LintGroupConfig::from(file);
+        let missing = config
+            .models()
+            .get("missing_geometry_lods")
+            .expect("missing_geometry_lods exists");
+        assert_eq!(missing.enabled(), Some(false));
+    }
+
     #[test]
     fn empty() {
         let toml = "";
@@ -277,6 +339,19 @@ options.test = true
         assert!(config.is_empty());
     }
 
+    #[test]
+    fn not_empty() {
+        for toml in [
+            "[stringtables]\nexample = false\n",
+            "[textures]\nexample = false\n",
+            "[models]\nexample = false\n",
+        ] {
+            let file: LintSectionFile = toml::from_str(toml).expect("failed to deserialize");
+            let config = LintGroupConfig::from(file);
+            assert!(!config.is_empty(), "{toml}");
+        }
+    }
+
     #[test]
     fn default() {
         let toml = "
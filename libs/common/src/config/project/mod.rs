@@ -10,6 +10,7 @@ use crate::error::Error;
 use super::deprecated;
 
 pub mod files;
+pub mod format;
 pub mod hemtt;
 pub mod lint;
 pub mod signing;
@@ -35,6 +36,9 @@ pub struct ProjectConfig {
     /// Files to be included in the root of the project, supports glob patterns
     files: files::FilesConfig,
 
+    /// Configuration for `hemtt fmt`
+    format: format::FormatConfig,
+
     /// Configuration for lints
     lints: lint::LintGroupConfig,
 
@@ -82,6 +86,12 @@ impl ProjectConfig {
         &self.files
     }
 
+    #[must_use]
+    /// Configuration for `hemtt fmt`
+    pub const fn format(&self) -> &format::FormatConfig {
+        &self.format
+    }
+
     #[must_use]
     /// Configuration for lints
     pub const fn lints(&self) -> &lint::LintGroupConfig {
@@ -134,6 +144,10 @@ pub struct ProjectFile {
     /// Files to be included in the root of the project, supports glob patterns
     files: files::FilesSectionFile,
 
+    #[serde(default)]
+    /// Configuration for `hemtt fmt`
+    format: format::FormatSectionFile,
+
     #[serde(default)]
     /// Lint configuration
     lints: lint::LintSectionFile,
@@ -195,6 +209,7 @@ impl TryFrom<ProjectFile> for ProjectConfig {
             version: file.version.try_into()?,
             properties: file.properties,
             files: file.files.into(),
+            format: file.format.into(),
             lints: file.lints.into(),
             signing: file.signing.into(),
         };
@@ -227,7 +242,7 @@ impl TryFrom<ProjectFile> for ProjectConfig {
 mod test_helper {
     use std::collections::HashMap;
 
-    use super::{files, hemtt, lint, signing, version};
+    use super::{files, format, hemtt, lint, signing, version};
 
     impl super::ProjectConfig {
         #[must_use]
@@ -243,6 +258,7 @@ mod test_helper {
                 version: version::VersionSectionFile::default(),
                 properties: HashMap::default(),
                 files: files::FilesSectionFile::default(),
+                format: format::FormatSectionFile::default(),
                 lints: lint::LintSectionFile::default(),
                 hemtt: hemtt::HemttSectionFile::default(),
                 signing: signing::SigningSectionFile::default(),
@@ -8,6 +8,12 @@ pub struct SigningConfig {
     version: BISignVersion,
 
     authority: Option<String>,
+
+    private_key: Option<String>,
+
+    private_key_env: Option<String>,
+
+    additional_keys: Vec<String>,
 }
 
 impl SigningConfig {
@@ -18,6 +24,21 @@ impl SigningConfig {
     pub const fn authority(&self) -> Option<&String> {
         self.authority.as_ref()
     }
+
+    /// Path to the private key to sign with, instead of generating one for each release
+    pub const fn private_key(&self) -> Option<&String> {
+        self.private_key.as_ref()
+    }
+
+    /// Environment variable containing the path to the private key, used before `private_key`
+    pub const fn private_key_env(&self) -> Option<&String> {
+        self.private_key_env.as_ref()
+    }
+
+    /// Paths to additional private keys to sign with, such as the previous key while rotating
+    pub fn additional_keys(&self) -> &[String] {
+        &self.additional_keys
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -27,6 +48,15 @@ pub struct SigningSectionFile {
 
     #[serde(default)]
     authority: Option<String>,
+
+    #[serde(default)]
+    private_key: Option<String>,
+
+    #[serde(default)]
+    private_key_env: Option<String>,
+
+    #[serde(default)]
+    additional_keys: Vec<String>,
 }
 
 impl From<SigningSectionFile> for SigningConfig {
@@ -34,6 +64,9 @@ impl From<SigningSectionFile> for SigningConfig {
         Self {
             version: file.version.unwrap_or_default(),
             authority: file.authority,
+            private_key: file.private_key,
+            private_key_env: file.private_key_env,
+            additional_keys: file.additional_keys,
         }
     }
 }
@@ -47,11 +80,23 @@ mod tests {
         let toml = r#"
 version = 2
 authority = "test"
+private_key = "keys/test.biprivatekey"
+private_key_env = "TEST_PRIVATE_KEY"
+additional_keys = ["keys/old.biprivatekey"]
 "#;
         let file: SigningSectionFile = toml::from_str(toml).expect("failed to deserialize");
         let config = SigningConfig::from(file);
         assert_eq!(config.version(), BISignVersion::V2);
         assert_eq!(config.authority(), Some(&"test".to_string()));
+        assert_eq!(
+            config.private_key(),
+            Some(&"keys/test.biprivatekey".to_string())
+        );
+        assert_eq!(
+            config.private_key_env(),
+            Some(&"TEST_PRIVATE_KEY".to_string())
+        );
+        assert_eq!(config.additional_keys(), &["keys/old.biprivatekey"]);
     }
 
     #[test]
@@ -61,5 +106,8 @@ authority = "test"
         let config = SigningConfig::from(file);
         assert_eq!(config.version(), BISignVersion::V3);
         assert!(config.authority().is_none());
+        assert!(config.private_key().is_none());
+        assert!(config.private_key_env().is_none());
+        assert!(config.additional_keys().is_empty());
     }
 }
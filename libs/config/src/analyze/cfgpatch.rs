@@ -1,19 +1,31 @@
 use hemtt_common::version::Version;
 
-use crate::Ident;
+use crate::{Ident, Str};
 
 #[derive(Debug, Clone)]
 pub struct CfgPatch {
     name: Ident,
     required_version: Version,
+    required_addons: Vec<Str>,
+    units: Vec<Str>,
+    weapons: Vec<Str>,
 }
 
 impl CfgPatch {
     #[must_use]
-    pub const fn new(name: Ident, required_version: Version) -> Self {
+    pub const fn new(
+        name: Ident,
+        required_version: Version,
+        required_addons: Vec<Str>,
+        units: Vec<Str>,
+        weapons: Vec<Str>,
+    ) -> Self {
         Self {
             name,
             required_version,
+            required_addons,
+            units,
+            weapons,
         }
     }
 
@@ -26,4 +38,22 @@ impl CfgPatch {
     pub const fn required_version(&self) -> &Version {
         &self.required_version
     }
+
+    #[must_use]
+    /// The patches that must be loaded before this one
+    pub fn required_addons(&self) -> &[Str] {
+        &self.required_addons
+    }
+
+    #[must_use]
+    /// The `CfgVehicles` classes added by the patch
+    pub fn units(&self) -> &[Str] {
+        &self.units
+    }
+
+    #[must_use]
+    /// The `CfgWeapons` classes added by the patch
+    pub fn weapons(&self) -> &[Str] {
+        &self.weapons
+    }
 }
@@ -101,7 +101,7 @@ impl LintRunner<SqfLintData> for Runner {
         })) = target
             .0
             .iter()
-            .find(|p| p.name().value.to_lowercase() == "cfgmagazinewells")
+            .find(|p| p.name().is_some_and(|name| name.value.to_lowercase() == "cfgmagazinewells"))
         else {
             return codes;
         };
@@ -111,7 +111,7 @@ impl LintRunner<SqfLintData> for Runner {
         })) = target
             .0
             .iter()
-            .find(|p| p.name().value.to_lowercase() == "cfgmagazines")
+            .find(|p| p.name().is_some_and(|name| name.value.to_lowercase() == "cfgmagazines"))
         else {
             return codes;
         };
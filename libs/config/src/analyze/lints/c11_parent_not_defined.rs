@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Processed, Severity},
+};
+
+use crate::{
+    analyze::SqfLintData,
+    database::{BrokenKind, BrokenParent, Database},
+};
+
+crate::analyze::lint!(LintC11ParentNotDefined);
+
+impl Lint<SqfLintData> for LintC11ParentNotDefined {
+    fn ident(&self) -> &str {
+        "parent_not_defined"
+    }
+
+    fn sort(&self) -> u32 {
+        110
+    }
+
+    fn description(&self) -> &str {
+        "Reports on classes whose parent is not defined by any addon in the project"
+    }
+
+    fn documentation(&self) -> &str {
+        "### Example
+
+**Incorrect**
+```hpp
+// addons/main/config.cpp
+class CfgVehicles {
+    class Car;
+    class abe_car_base: Car {};
+};
+```
+```hpp
+// addons/vehicles/config.cpp
+class CfgVehicles {
+    class abe_car;
+    class abe_hatchback: abe_car {};
+};
+```
+
+**Correct**
+```hpp
+// addons/vehicles/config.cpp
+class CfgVehicles {
+    class abe_car_base;
+    class abe_hatchback: abe_car_base {};
+};
+```
+
+### Explanation
+
+The configs of every addon are merged in the order of their `requiredAddons`, the same way the game loads them. This lint reports on classes whose parent can not be found after merging:
+
+- the parent is not declared by any addon, usually because another addon deleted it
+- the parent is using the project's prefix (abe in this case), but is only ever declared as external, usually a typo or a class that was renamed
+- the class inherits from itself through its parents
+
+Read more about [class inheritance](https://community.bistudio.com/wiki/Class_Inheritance).
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::error()
+    }
+
+    fn minimum_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<SqfLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<SqfLintData> for Runner {
+    type Target = Database;
+    fn run(
+        &self,
+        project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        target: &Database,
+        _data: &SqfLintData,
+    ) -> Codes {
+        target
+            .broken_parents()
+            .into_iter()
+            .filter(|broken| {
+                broken.kind != BrokenKind::External
+                    || project.is_some_and(|project| {
+                        broken
+                            .parent
+                            .to_lowercase()
+                            .starts_with(&project.prefix().to_lowercase())
+                    })
+            })
+            .map(|broken| {
+                Arc::new(CodeC11ParentNotDefined::new(broken, config.severity())) as Arc<dyn Code>
+            })
+            .collect()
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeC11ParentNotDefined {
+    broken: BrokenParent,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeC11ParentNotDefined {
+    fn ident(&self) -> &'static str {
+        "L-C11"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#parent_not_defined")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        match self.broken.kind {
+            BrokenKind::Missing => format!(
+                "parent `{}` of `{}` is not defined by any addon",
+                self.broken.parent, self.broken.class
+            ),
+            BrokenKind::External => format!(
+                "parent `{}` of `{}` is only declared as external",
+                self.broken.parent, self.broken.class
+            ),
+            BrokenKind::Cycle => format!(
+                "`{}` inherits from itself through `{}`",
+                self.broken.class, self.broken.parent
+            ),
+        }
+    }
+
+    fn label_message(&self) -> String {
+        match self.broken.kind {
+            BrokenKind::Missing | BrokenKind::External => "parent not defined".to_string(),
+            BrokenKind::Cycle => "inheritance cycle".to_string(),
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        match self.broken.kind {
+            BrokenKind::Missing => Some(format!(
+                "check that no addon deletes `{}`",
+                self.broken.parent
+            )),
+            BrokenKind::External => Some(format!(
+                "check the name, and that the addon defining `{}` is in `requiredAddons`",
+                self.broken.parent
+            )),
+            BrokenKind::Cycle => None,
+        }
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC11ParentNotDefined {
+    #[must_use]
+    pub fn new(broken: BrokenParent, severity: Severity) -> Self {
+        Self {
+            broken,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        let mut diagnostic = Diagnostic::from_code(&self);
+        if let Some(origin) = &self.broken.origin {
+            diagnostic = diagnostic.with_label(
+                Label::primary(origin.path.clone(), origin.span.clone())
+                    .with_message(self.label_message()),
+            );
+        }
+        self.diagnostic = Some(diagnostic);
+        self
+    }
+}
@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Processed, Severity},
+};
+
+use crate::{
+    analyze::SqfLintData,
+    database::{Database, Origin},
+};
+
+crate::analyze::lint!(LintC12RequiredAddonsCycle);
+
+impl Lint<SqfLintData> for LintC12RequiredAddonsCycle {
+    fn ident(&self) -> &str {
+        "required_addons_cycle"
+    }
+
+    fn sort(&self) -> u32 {
+        120
+    }
+
+    fn description(&self) -> &str {
+        "Reports on addons that require each other through `requiredAddons`"
+    }
+
+    fn documentation(&self) -> &str {
+        r#"### Example
+
+**Incorrect**
+```hpp
+// addons/main/config.cpp
+class CfgPatches {
+    class abe_main {
+        requiredAddons[] = {"abe_vehicles"};
+    };
+};
+```
+```hpp
+// addons/vehicles/config.cpp
+class CfgPatches {
+    class abe_vehicles {
+        requiredAddons[] = {"abe_main"};
+    };
+};
+```
+
+**Correct**
+```hpp
+// addons/main/config.cpp
+class CfgPatches {
+    class abe_main {
+        requiredAddons[] = {"A3_Data_F_Decade_Loadorder"};
+    };
+};
+```
+
+### Explanation
+
+The game can not decide which addon to load first when addons require each other, the load order of their configs is undefined.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::error()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<SqfLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<SqfLintData> for Runner {
+    type Target = Database;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        target: &Database,
+        _data: &SqfLintData,
+    ) -> Codes {
+        target
+            .required_cycles()
+            .into_iter()
+            .map(|cycle| {
+                let first = &cycle[0].name.name;
+                let last = cycle[cycle.len() - 1];
+                let origin = last
+                    .required_addons
+                    .iter()
+                    .find(|required| required.name.eq_ignore_ascii_case(first))
+                    .and_then(|required| required.origin.clone());
+                let mut names = cycle
+                    .iter()
+                    .map(|patch| patch.name.name.clone())
+                    .collect::<Vec<_>>();
+                names.push(first.clone());
+                Arc::new(CodeC12RequiredAddonsCycle::new(
+                    names,
+                    origin,
+                    config.severity(),
+                )) as Arc<dyn Code>
+            })
+            .collect()
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeC12RequiredAddonsCycle {
+    cycle: Vec<String>,
+    origin: Option<Origin>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeC12RequiredAddonsCycle {
+    fn ident(&self) -> &'static str {
+        "L-C12"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#required_addons_cycle")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("`requiredAddons` form a cycle: {}", self.cycle.join(" -> "))
+    }
+
+    fn label_message(&self) -> String {
+        "completes the cycle".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("remove one of the addons from `requiredAddons`".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC12RequiredAddonsCycle {
+    #[must_use]
+    pub fn new(cycle: Vec<String>, origin: Option<Origin>, severity: Severity) -> Self {
+        Self {
+            cycle,
+            origin,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        let mut diagnostic = Diagnostic::from_code(&self);
+        if let Some(origin) = &self.origin {
+            diagnostic = diagnostic.with_label(
+                Label::primary(origin.path.clone(), origin.span.clone())
+                    .with_message(self.label_message()),
+            );
+        }
+        self.diagnostic = Some(diagnostic);
+        self
+    }
+}
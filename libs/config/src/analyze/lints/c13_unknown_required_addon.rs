@@ -0,0 +1,223 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Processed, Severity},
+};
+
+use crate::{
+    analyze::SqfLintData,
+    database::{Database, Reference},
+};
+
+/// Patches of the base game that do not start with `A3_`
+const VANILLA_PATCHES: &[&str] = &["core", "a3data", "3den"];
+
+/// Prefixes of the patches of the base game, its DLCs and the Creator DLCs
+const VANILLA_PREFIXES: &[&str] = &[
+    "a3_",
+    "curatoronly_",
+    // Global Mobilization
+    "gm_",
+    // S.O.G. Prairie Fire
+    "vn_",
+    // Western Sahara
+    "ws_",
+    // CSLA Iron Curtain
+    "csla",
+    "us85",
+    // Spearhead 1944
+    "spe_",
+    "ww2_spe_",
+];
+
+/// Suffixes of the patches of the Creator DLCs
+const VANILLA_SUFFIXES: &[&str] = &[
+    // S.O.G. Prairie Fire
+    "_f_vietnam",
+    // Western Sahara
+    "_lxws",
+];
+
+crate::analyze::lint!(LintC13UnknownRequiredAddon);
+
+impl Lint<SqfLintData> for LintC13UnknownRequiredAddon {
+    fn ident(&self) -> &str {
+        "unknown_required_addon"
+    }
+
+    fn sort(&self) -> u32 {
+        130
+    }
+
+    fn description(&self) -> &str {
+        "Reports on `requiredAddons` that are not part of the project or the base game"
+    }
+
+    fn documentation(&self) -> &str {
+        r#"### Configuration
+
+- **known**: An array of addons from other mods that can be required, or vanilla and Creator DLC addons that are not recognized
+
+```toml
+[lints.config.unknown_required_addon]
+options.known = [
+    "cba_main",
+    "ace_common",
+]
+```
+
+### Example
+
+**Incorrect**
+```hpp
+class CfgPatches {
+    class abe_vehicles {
+        requiredAddons[] = {"abe_mian"};
+    };
+};
+```
+
+**Correct**
+```hpp
+class CfgPatches {
+    class abe_vehicles {
+        requiredAddons[] = {"abe_main"};
+    };
+};
+```
+
+### Explanation
+
+Addons in `requiredAddons` must be loaded for the addon to load. An addon that is neither a `CfgPatches` class of the project, nor part of the base game or a Creator DLC, is usually a typo or an addon that was renamed.
+
+The base game is recognized by `A3_`, `CuratorOnly_`, `Core`, `A3Data` and `3DEN`. Creator DLCs are recognized by the prefixes `gm_` (Global Mobilization), `vn_` and the suffix `_f_vietnam` (S.O.G. Prairie Fire), `ws_` and the suffix `_lxWS` (Western Sahara), `csla` and `us85` (CSLA Iron Curtain), and `spe_` and `ww2_spe_` (Spearhead 1944).
+
+Addons from other mods, or vanilla addons that are not recognized, can be added to the `known` option.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<SqfLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<SqfLintData> for Runner {
+    type Target = Database;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        target: &Database,
+        _data: &SqfLintData,
+    ) -> Codes {
+        let known = if let Some(toml::Value::Array(known)) = config.option("known") {
+            known
+                .iter()
+                .filter_map(|k| k.as_str().map(str::to_lowercase))
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+        let mut codes: Codes = Vec::new();
+        for patch in target.patches() {
+            for required in &patch.required_addons {
+                let name = required.name.to_lowercase();
+                if target.patch(&name).is_some() || is_vanilla(&name) || known.contains(&name) {
+                    continue;
+                }
+                codes.push(Arc::new(CodeC13UnknownRequiredAddon::new(
+                    patch.name.name.clone(),
+                    required.clone(),
+                    config.severity(),
+                )));
+            }
+        }
+        codes
+    }
+}
+
+/// Is the lowercase patch name from the base game, a DLC or a Creator DLC
+fn is_vanilla(name: &str) -> bool {
+    VANILLA_PATCHES.contains(&name)
+        || VANILLA_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+        || VANILLA_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeC13UnknownRequiredAddon {
+    patch: String,
+    required: Reference,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeC13UnknownRequiredAddon {
+    fn ident(&self) -> &'static str {
+        "L-C13"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#unknown_required_addon")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` requires unknown addon `{}`",
+            self.patch, self.required.name
+        )
+    }
+
+    fn label_message(&self) -> String {
+        "not in the project, the base game or a Creator DLC".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "if `{}` is from another mod, add it to `known` in `[lints.config.unknown_required_addon]`",
+            self.required.name
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC13UnknownRequiredAddon {
+    #[must_use]
+    pub fn new(patch: String, required: Reference, severity: Severity) -> Self {
+        Self {
+            patch,
+            required,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        let mut diagnostic = Diagnostic::from_code(&self);
+        if let Some(origin) = &self.required.origin {
+            diagnostic = diagnostic.with_label(
+                Label::primary(origin.path.clone(), origin.span.clone())
+                    .with_message(self.label_message()),
+            );
+        }
+        self.diagnostic = Some(diagnostic);
+        self
+    }
+}
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Processed, Severity},
+};
+
+use crate::{
+    analyze::SqfLintData,
+    database::{Database, Origin},
+};
+
+crate::analyze::lint!(LintC14ParentNotRequired);
+
+impl Lint<SqfLintData> for LintC14ParentNotRequired {
+    fn ident(&self) -> &str {
+        "parent_not_required"
+    }
+
+    fn sort(&self) -> u32 {
+        140
+    }
+
+    fn description(&self) -> &str {
+        "Reports on classes that inherit from a class defined in an addon that is not in `requiredAddons`"
+    }
+
+    fn documentation(&self) -> &str {
+        r#"### Example
+
+**Incorrect**
+```hpp
+// addons/vehicles/config.cpp
+class CfgPatches {
+    class abe_vehicles {
+        requiredAddons[] = {"A3_Soft_F"};
+    };
+};
+class CfgVehicles {
+    class abe_car_base; // defined in addons/main
+    class abe_hatchback: abe_car_base {};
+};
+```
+
+**Correct**
+```hpp
+// addons/vehicles/config.cpp
+class CfgPatches {
+    class abe_vehicles {
+        requiredAddons[] = {"abe_main"};
+    };
+};
+class CfgVehicles {
+    class abe_car_base;
+    class abe_hatchback: abe_car_base {};
+};
+```
+
+### Explanation
+
+An addon is only loaded after the addons in its `requiredAddons`, directly or through the addons they require. Inheriting from a class of an addon that is not required only works if the game happens to load that addon first.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::error()
+    }
+
+    fn minimum_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<SqfLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<SqfLintData> for Runner {
+    type Target = Database;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        target: &Database,
+        _data: &SqfLintData,
+    ) -> Codes {
+        let mut codes: Codes = Vec::new();
+        for (path, class) in target.classes() {
+            let (Some(parent), Some(origin)) = (class.parent(), class.parent_origin()) else {
+                continue;
+            };
+            let Some(parent_class) = target
+                .parents(&path)
+                .first()
+                .and_then(|parent| target.class(parent))
+            else {
+                continue;
+            };
+            let definitions = parent_class.definitions();
+            if definitions.is_empty()
+                || definitions
+                    .iter()
+                    .any(|definition| target.requires(&origin.addon, &definition.addon))
+            {
+                continue;
+            }
+            let defined_in = &definitions[0].addon;
+            let patch = target
+                .patches()
+                .iter()
+                .find(|patch| &patch.addon == defined_in)
+                .map(|patch| patch.name.name.clone());
+            codes.push(Arc::new(CodeC14ParentNotRequired::new(
+                path,
+                parent.to_string(),
+                defined_in.clone(),
+                patch,
+                origin.clone(),
+                config.severity(),
+            )));
+        }
+        codes
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeC14ParentNotRequired {
+    class: String,
+    parent: String,
+    defined_in: String,
+    patch: Option<String>,
+    origin: Origin,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeC14ParentNotRequired {
+    fn ident(&self) -> &'static str {
+        "L-C14"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#parent_not_required")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` inherits from `{}` of addon `{}`, which `{}` does not require",
+            self.class, self.parent, self.defined_in, self.origin.addon
+        )
+    }
+
+    fn label_message(&self) -> String {
+        format!("defined in `{}`", self.defined_in)
+    }
+
+    fn help(&self) -> Option<String> {
+        self.patch.as_ref().map(|patch| {
+            format!(
+                "add `{patch}` to `requiredAddons` of `{}`",
+                self.origin.addon
+            )
+        })
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC14ParentNotRequired {
+    #[must_use]
+    pub fn new(
+        class: String,
+        parent: String,
+        defined_in: String,
+        patch: Option<String>,
+        origin: Origin,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            class,
+            parent,
+            defined_in,
+            patch,
+            origin,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(
+            Diagnostic::from_code(&self).with_label(
+                Label::primary(self.origin.path.clone(), self.origin.span.clone())
+                    .with_message(self.label_message()),
+            ),
+        );
+        self
+    }
+}
@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Processed, Severity},
+};
+
+use crate::{
+    analyze::SqfLintData,
+    database::{Database, Reference},
+    Number, Value,
+};
+
+crate::analyze::lint!(LintC15UnitNotPublic);
+
+impl Lint<SqfLintData> for LintC15UnitNotPublic {
+    fn ident(&self) -> &str {
+        "unit_not_public"
+    }
+
+    fn sort(&self) -> u32 {
+        150
+    }
+
+    fn description(&self) -> &str {
+        "Reports on `units[]` entries that are not public `CfgVehicles` classes of the addon"
+    }
+
+    fn documentation(&self) -> &str {
+        r#"### Example
+
+**Incorrect**
+```hpp
+class CfgPatches {
+    class abe_vehicles {
+        units[] = {"abe_car_base", "abe_hatchbak"};
+    };
+};
+class CfgVehicles {
+    class abe_car_base {
+        scope = 1;
+    };
+    class abe_hatchback: abe_car_base {
+        scope = 2;
+    };
+};
+```
+
+**Correct**
+```hpp
+class CfgPatches {
+    class abe_vehicles {
+        units[] = {"abe_hatchback"};
+    };
+};
+class CfgVehicles {
+    class abe_car_base {
+        scope = 1;
+    };
+    class abe_hatchback: abe_car_base {
+        scope = 2;
+    };
+};
+```
+
+### Explanation
+
+`units[]` lists the vehicles an addon adds, and is used by the editor and Zeus. Each entry should be a `CfgVehicles` class defined by the addon, with `scope = 2`.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<SqfLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<SqfLintData> for Runner {
+    type Target = Database;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        target: &Database,
+        _data: &SqfLintData,
+    ) -> Codes {
+        let mut codes: Codes = Vec::new();
+        for patch in target.patches() {
+            for unit in &patch.units {
+                let path = format!("CfgVehicles >> {}", unit.name);
+                let defined = target.class(&path).is_some_and(|class| {
+                    class
+                        .definitions()
+                        .iter()
+                        .any(|definition| definition.addon == patch.addon)
+                });
+                let problem = if !defined {
+                    Problem::NotDefined
+                } else if matches!(
+                    target.value(&format!("{path} >> scope")),
+                    Some(Value::Number(Number::Int32 { value: 2, .. }))
+                ) {
+                    continue;
+                } else {
+                    Problem::NotPublic
+                };
+                codes.push(Arc::new(CodeC15UnitNotPublic::new(
+                    patch.name.name.clone(),
+                    unit.clone(),
+                    problem,
+                    config.severity(),
+                )));
+            }
+        }
+        codes
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Problem {
+    /// The class is not defined by the addon
+    NotDefined,
+    /// The class does not have `scope = 2`
+    NotPublic,
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeC15UnitNotPublic {
+    patch: String,
+    unit: Reference,
+    problem: Problem,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeC15UnitNotPublic {
+    fn ident(&self) -> &'static str {
+        "L-C15"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#unit_not_public")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        match self.problem {
+            Problem::NotDefined => format!(
+                "unit `{}` of `{}` is not a `CfgVehicles` class of the addon",
+                self.unit.name, self.patch
+            ),
+            Problem::NotPublic => format!(
+                "unit `{}` of `{}` does not have `scope = 2`",
+                self.unit.name, self.patch
+            ),
+        }
+    }
+
+    fn label_message(&self) -> String {
+        match self.problem {
+            Problem::NotDefined => "not defined by the addon".to_string(),
+            Problem::NotPublic => "not public".to_string(),
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(match self.problem {
+            Problem::NotDefined => "check the name, or remove it from `units[]`".to_string(),
+            Problem::NotPublic => {
+                "set `scope = 2` on the class, or remove it from `units[]`".to_string()
+            }
+        })
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC15UnitNotPublic {
+    #[must_use]
+    pub fn new(patch: String, unit: Reference, problem: Problem, severity: Severity) -> Self {
+        Self {
+            patch,
+            unit,
+            problem,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        let mut diagnostic = Diagnostic::from_code(&self);
+        if let Some(origin) = &self.unit.origin {
+            diagnostic = diagnostic.with_label(
+                Label::primary(origin.path.clone(), origin.span.clone())
+                    .with_message(self.label_message()),
+            );
+        }
+        self.diagnostic = Some(diagnostic);
+        self
+    }
+}
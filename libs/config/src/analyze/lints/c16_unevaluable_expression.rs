@@ -0,0 +1,148 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::SqfLintData, Expression};
+
+crate::analyze::lint!(LintC16UnevaluableExpression);
+
+impl Lint<SqfLintData> for LintC16UnevaluableExpression {
+    fn ident(&self) -> &str {
+        "unevaluable_expression"
+    }
+
+    fn sort(&self) -> u32 {
+        160
+    }
+
+    fn description(&self) -> &str {
+        "Reports on `__EVAL` and `__EXEC` that can not be evaluated at build time"
+    }
+
+    fn documentation(&self) -> &str {
+        r#"### Example
+
+**Incorrect**
+```hpp
+class MyClass {
+    width = __EVAL(getResolution select 0);
+};
+```
+
+**Correct**
+```hpp
+__EXEC(_base = 100)
+class MyClass {
+    width = __EVAL(_base * 2);
+    name = __EVAL(format ["%1_%2", "abe", _base]);
+};
+```
+
+### Explanation
+
+HEMTT evaluates `__EVAL` and `__EXEC` while building, and writes the result into the rapified config. Only a small part of SQF can be evaluated: numbers, strings, booleans and arrays, the `+ - * / % mod ^` operators, `format`, `str` and variables assigned by an earlier `__EXEC`.
+
+An `__EVAL` that uses anything else is written to the config unevaluated, and an `__EXEC` that uses anything else does not assign its variables.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<SqfLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+
+impl LintRunner<SqfLintData> for Runner {
+    type Target = Expression;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Expression,
+        _data: &SqfLintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        let Some(unevaluable) = target.unevaluable() else {
+            return vec![];
+        };
+        vec![Arc::new(CodeC16UnevaluableExpression::new(
+            unevaluable.span(),
+            unevaluable.reason().to_string(),
+            processed,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeC16UnevaluableExpression {
+    span: Range<usize>,
+    reason: String,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeC16UnevaluableExpression {
+    fn ident(&self) -> &'static str {
+        "L-C16"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#unevaluable_expression")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        "expression can not be evaluated at build time".to_string()
+    }
+
+    fn label_message(&self) -> String {
+        self.reason.clone()
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("only numbers, strings, arrays, arithmetic, `format`, `str` and variables assigned by `__EXEC` can be evaluated".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC16UnevaluableExpression {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        reason: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            reason,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
@@ -19,7 +19,25 @@ lint_manager!(config, vec![]);
 pub use cfgpatch::CfgPatch;
 pub use chumsky::ChumskyCode;
 
-use crate::{Array, Class, Config, Expression, Item, Number, Property, Str, Value};
+use crate::{
+    database::Database, Array, Class, Config, Expression, Item, Number, Property, Str, Value,
+};
+
+/// Run the lints that need the configs of every addon, on the merged database
+pub fn lint_database(database: &Database, project: Option<&ProjectConfig>) -> Codes {
+    let mut manager = LintManager::new(
+        project.map_or_else(Default::default, |project| project.lints().config().clone()),
+    );
+    if let Err(e) = manager.extend(
+        CONFIG_LINTS
+            .iter()
+            .map(|l| (**l).clone())
+            .collect::<Vec<_>>(),
+    ) {
+        return e;
+    }
+    manager.run(&SqfLintData {}, project, None, database)
+}
 
 /// Trait for rapifying objects
 pub trait Analyze: Sized + 'static {
@@ -94,6 +112,7 @@ impl Analyze for Property {
         codes.extend(match self {
             Self::Entry { value, .. } => value.analyze(data, project, processed, manager),
             Self::Class(c) => c.analyze(data, project, processed, manager),
+            Self::Exec(e) => e.analyze(data, project, processed, manager),
             Self::Delete(_) | Self::MissingSemicolon(_, _) => vec![],
         });
         codes
@@ -0,0 +1,160 @@
+//! The dependency graph of the addons, from their `CfgPatches`
+
+use std::collections::HashSet;
+
+use hemtt_workspace::reporting::Processed;
+
+use crate::{analyze::CfgPatch, Str};
+
+use super::{Database, Origin};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A name written in a config, and where it was written
+pub struct Reference {
+    pub name: String,
+    pub origin: Option<Origin>,
+}
+
+impl Reference {
+    fn new(addon: &str, name: &str, span: &std::ops::Range<usize>, processed: &Processed) -> Self {
+        Self {
+            name: name.to_string(),
+            origin: Origin::new(addon, span, processed),
+        }
+    }
+
+    fn from_str(addon: &str, value: &Str, processed: &Processed) -> Self {
+        Self::new(addon, value.value(), &value.span(), processed)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A class of `CfgPatches`, and the addon that defines it
+pub struct Patch {
+    pub addon: String,
+    pub name: Reference,
+    pub required_addons: Vec<Reference>,
+    pub units: Vec<Reference>,
+    pub weapons: Vec<Reference>,
+}
+
+impl Patch {
+    pub(super) fn new(addon: &str, patch: &CfgPatch, processed: &Processed) -> Self {
+        let references = |values: &[Str]| {
+            values
+                .iter()
+                .map(|value| Reference::from_str(addon, value, processed))
+                .collect()
+        };
+        Self {
+            addon: addon.to_string(),
+            name: Reference::new(addon, patch.name().as_str(), &patch.name().span, processed),
+            required_addons: references(patch.required_addons()),
+            units: references(patch.units()),
+            weapons: references(patch.weapons()),
+        }
+    }
+}
+
+impl Database {
+    #[must_use]
+    /// The patches of every addon, in load order
+    pub fn patches(&self) -> &[Patch] {
+        &self.patches
+    }
+
+    #[must_use]
+    /// The patch with a name, if it is defined by an addon of the project
+    pub fn patch(&self, name: &str) -> Option<&Patch> {
+        self.patch_index(name).map(|index| &self.patches[index])
+    }
+
+    fn patch_index(&self, name: &str) -> Option<usize> {
+        self.patches
+            .iter()
+            .position(|patch| patch.name.name.eq_ignore_ascii_case(name))
+    }
+
+    #[must_use]
+    /// Does `addon` require `other`, directly or through the addons it requires
+    ///
+    /// An addon always requires itself
+    pub fn requires(&self, addon: &str, other: &str) -> bool {
+        if addon.eq_ignore_ascii_case(other) {
+            return true;
+        }
+        let mut visited = HashSet::new();
+        let mut queue = self
+            .patches
+            .iter()
+            .filter(|patch| patch.addon.eq_ignore_ascii_case(addon))
+            .collect::<Vec<_>>();
+        while let Some(patch) = queue.pop() {
+            for required in &patch.required_addons {
+                let Some(index) = self.patch_index(&required.name) else {
+                    continue;
+                };
+                if !visited.insert(index) {
+                    continue;
+                }
+                let required = &self.patches[index];
+                if required.addon.eq_ignore_ascii_case(other) {
+                    return true;
+                }
+                queue.push(required);
+            }
+        }
+        false
+    }
+
+    #[must_use]
+    /// Cycles in the `requiredAddons` of the project's patches
+    ///
+    /// Each cycle is the patches in it, in order, the last patch requires the first
+    pub fn required_cycles(&self) -> Vec<Vec<&Patch>> {
+        let mut cycles = Vec::new();
+        let mut state = vec![Visit::New; self.patches.len()];
+        for index in 0..self.patches.len() {
+            if state[index] == Visit::New {
+                self.visit(index, &mut state, &mut Vec::new(), &mut cycles);
+            }
+        }
+        cycles
+    }
+
+    fn visit<'a>(
+        &'a self,
+        index: usize,
+        state: &mut [Visit],
+        stack: &mut Vec<usize>,
+        cycles: &mut Vec<Vec<&'a Patch>>,
+    ) {
+        state[index] = Visit::Open;
+        stack.push(index);
+        for required in &self.patches[index].required_addons {
+            let Some(next) = self.patch_index(&required.name) else {
+                continue;
+            };
+            match state[next] {
+                Visit::New => self.visit(next, state, stack, cycles),
+                Visit::Open => {
+                    let start = stack
+                        .iter()
+                        .position(|i| *i == next)
+                        .expect("open patches are on the stack");
+                    cycles.push(stack[start..].iter().map(|i| &self.patches[*i]).collect());
+                }
+                Visit::Done => {}
+            }
+        }
+        stack.pop();
+        state[index] = Visit::Done;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    New,
+    Open,
+    Done,
+}
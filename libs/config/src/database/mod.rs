@@ -0,0 +1,631 @@
+//! A project wide view of every addon's config
+//!
+//! Each `config.cpp` is parsed in isolation, the database merges them in
+//! `requiredAddons` load order, the same way the game does, so that the
+//! effective value of a property and the parent chain of a class can be
+//! resolved across addons.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    ops::Range,
+};
+
+use hemtt_workspace::{reporting::Processed, WorkspacePath};
+
+use crate::{analyze::CfgPatch, Array, Class, Config, Item, Property, Value};
+
+mod graph;
+
+pub use graph::{Patch, Reference};
+
+/// Limit for following parents, to stop on cycles
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Where a class or property was written
+pub struct Origin {
+    /// The addon the config belongs to
+    pub addon: String,
+    /// The file, mapped back from the preprocessed config
+    pub path: WorkspacePath,
+    /// The span in the file
+    pub span: Range<usize>,
+    /// The line in the file, from 1
+    pub line: usize,
+}
+
+impl Origin {
+    fn new(addon: &str, span: &Range<usize>, processed: &Processed) -> Option<Self> {
+        let map = processed.mapping(span.start)?;
+        Some(Self {
+            addon: addon.to_string(),
+            path: map.original().path().clone(),
+            span: map.original().span(),
+            line: map.original().start().line(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Entry {
+    Class(ClassEntry),
+    Property(PropertyEntry),
+}
+
+#[derive(Debug, Clone)]
+/// A property, after every config that sets it has been merged
+pub struct PropertyEntry {
+    name: String,
+    value: Value,
+    definitions: Vec<Origin>,
+}
+
+impl PropertyEntry {
+    #[must_use]
+    /// The name of the property, as it was last written
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    /// The value set by the last config
+    ///
+    /// `+=` in a later config is appended to the value of the earlier configs,
+    /// `+=` from the parent class is not applied
+    pub const fn value(&self) -> &Value {
+        &self.value
+    }
+
+    #[must_use]
+    /// Every place the property was defined or overridden, in load order
+    ///
+    /// The last one is the definition that is used
+    pub fn definitions(&self) -> &[Origin] {
+        &self.definitions
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// A class, after every config that defines it has been merged
+pub struct ClassEntry {
+    name: String,
+    parent: Option<String>,
+    parent_origin: Option<Origin>,
+    definitions: Vec<Origin>,
+    order: Vec<String>,
+    entries: HashMap<String, Entry>,
+}
+
+impl ClassEntry {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    /// The name of the class, as it was first written
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    /// The parent, as written by the last config to define the class
+    pub fn parent(&self) -> Option<&str> {
+        self.parent.as_deref()
+    }
+
+    #[must_use]
+    /// Where the parent was set
+    pub const fn parent_origin(&self) -> Option<&Origin> {
+        self.parent_origin.as_ref()
+    }
+
+    #[must_use]
+    /// Every place the class body was defined, in load order
+    pub fn definitions(&self) -> &[Origin] {
+        &self.definitions
+    }
+
+    #[must_use]
+    /// The class is only declared with `class Name;`, and never defined
+    pub const fn is_external(&self) -> bool {
+        self.definitions.is_empty()
+    }
+
+    /// The properties defined in the class, not including inherited properties
+    pub fn properties(&self) -> impl Iterator<Item = &PropertyEntry> {
+        self.order
+            .iter()
+            .filter_map(|key| match self.entries.get(key) {
+                Some(Entry::Property(property)) => Some(property),
+                _ => None,
+            })
+    }
+
+    /// The classes defined in the class, not including inherited classes
+    pub fn classes(&self) -> impl Iterator<Item = &Self> {
+        self.order
+            .iter()
+            .filter_map(|key| match self.entries.get(key) {
+                Some(Entry::Class(class)) => Some(class),
+                _ => None,
+            })
+    }
+
+    fn insert(&mut self, key: String, entry: Entry) {
+        if self.entries.insert(key.clone(), entry).is_none() {
+            self.order.push(key);
+        }
+    }
+
+    fn merge(&mut self, properties: &[Property], addon: &str, processed: &Processed) {
+        for property in properties {
+            match property {
+                Property::Entry { name, value, .. } => {
+                    let key = name.as_str().to_lowercase();
+                    let origin = Origin::new(addon, &name.span, processed);
+                    if let Some(Entry::Property(existing)) = self.entries.get_mut(&key) {
+                        match (&mut existing.value, value) {
+                            // `+=` in a later config appends to the merged array
+                            (Value::Array(existing), Value::Array(array)) if array.expand => {
+                                existing.items.extend(array.items.iter().cloned());
+                            }
+                            (existing, value) => *existing = value.clone(),
+                        }
+                        existing.definitions.extend(origin);
+                    } else {
+                        self.insert(
+                            key,
+                            Entry::Property(PropertyEntry {
+                                name: name.value.clone(),
+                                value: value.clone(),
+                                definitions: origin.into_iter().collect(),
+                            }),
+                        );
+                    }
+                }
+                Property::Class(Class::Local {
+                    name,
+                    parent,
+                    properties,
+                    ..
+                }) => {
+                    let key = name.as_str().to_lowercase();
+                    if !matches!(self.entries.get(&key), Some(Entry::Class(_))) {
+                        self.insert(key.clone(), Entry::Class(Self::new(name.as_str())));
+                    }
+                    let Some(Entry::Class(class)) = self.entries.get_mut(&key) else {
+                        unreachable!("class was just inserted");
+                    };
+                    // the game replaces the parent, even when it is left out
+                    class.parent = parent.as_ref().map(|parent| parent.value.clone());
+                    class.parent_origin = parent
+                        .as_ref()
+                        .and_then(|parent| Origin::new(addon, &parent.span, processed));
+                    class
+                        .definitions
+                        .extend(Origin::new(addon, &name.span, processed));
+                    class.merge(properties, addon, processed);
+                }
+                Property::Class(Class::External { name }) => {
+                    let key = name.as_str().to_lowercase();
+                    if !self.entries.contains_key(&key) {
+                        self.insert(key, Entry::Class(Self::new(name.as_str())));
+                    }
+                }
+                Property::Delete(name) => {
+                    let key = name.as_str().to_lowercase();
+                    if self.entries.remove(&key).is_some() {
+                        self.order.retain(|k| k != &key);
+                    }
+                }
+                Property::Class(Class::Root { .. })
+                | Property::MissingSemicolon(..)
+                | Property::Exec(_) => {}
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Why a parent could not be used
+pub enum BrokenKind {
+    /// No addon declares the parent
+    Missing,
+    /// The parent is only declared as external, it may come from an addon outside the project
+    External,
+    /// The class inherits from itself through its parents
+    Cycle,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A class whose parent chain is broken
+pub struct BrokenParent {
+    /// The path of the class, `CfgVehicles >> B_MyCar`
+    pub class: String,
+    /// The parent, as written
+    pub parent: String,
+    /// Where the parent was set
+    pub origin: Option<Origin>,
+    pub kind: BrokenKind,
+}
+
+#[derive(Debug, Default)]
+/// The merged configs of a project
+pub struct Database {
+    root: ClassEntry,
+    load_order: Vec<String>,
+    patches: Vec<Patch>,
+}
+
+impl Database {
+    #[must_use]
+    /// Merge the configs of each addon, given as the addon's name, its `config.cpp`,
+    /// and the preprocessed file it was parsed from
+    ///
+    /// Configs are merged after the configs of the addons in their `requiredAddons`,
+    /// otherwise in the order they are given
+    ///
+    /// # Panics
+    /// if the load order does not place each config exactly once
+    pub fn new(sources: Vec<(String, Config, Processed)>) -> Self {
+        let mut database = Self::default();
+        let order = load_order(&sources);
+        let mut sources = sources.into_iter().map(Some).collect::<Vec<_>>();
+        for index in order {
+            let (addon, config, processed) =
+                sources[index].take().expect("each config is placed once");
+            database.root.merge(&config.0, &addon, &processed);
+            database.patches.extend(
+                config
+                    .get_patches()
+                    .iter()
+                    .map(|patch| Patch::new(&addon, patch, &processed)),
+            );
+            database.load_order.push(addon);
+        }
+        database
+    }
+
+    #[must_use]
+    /// The addons, in the order their configs were merged
+    pub fn load_order(&self) -> &[String] {
+        &self.load_order
+    }
+
+    #[must_use]
+    /// The root of the merged config
+    pub const fn root(&self) -> &ClassEntry {
+        &self.root
+    }
+
+    #[must_use]
+    /// Every class, with its path from the root, parents before their children
+    pub fn classes(&self) -> Vec<(String, &ClassEntry)> {
+        let mut classes = Vec::new();
+        let mut stack = vec![(String::new(), &self.root)];
+        while let Some((path, class)) = stack.pop() {
+            for child in class.classes().collect::<Vec<_>>().into_iter().rev() {
+                let child_path = if path.is_empty() {
+                    child.name.clone()
+                } else {
+                    format!("{path} >> {}", child.name)
+                };
+                stack.push((child_path, child));
+            }
+            if !path.is_empty() {
+                classes.push((path, class));
+            }
+        }
+        classes
+    }
+
+    #[must_use]
+    /// Get a class, such as `CfgVehicles >> B_MyCar >> Turrets`
+    ///
+    /// Classes that are inherited from a parent are found as well
+    pub fn class(&self, path: &str) -> Option<&ClassEntry> {
+        self.class_at(&self.resolve(&split(path))?)
+    }
+
+    #[must_use]
+    /// Get the entry that provides a property, such as `CfgVehicles >> B_MyCar >> maxSpeed`
+    ///
+    /// This is the entry of the closest class in the parent chain that defines the property
+    pub fn property(&self, path: &str) -> Option<&PropertyEntry> {
+        let (class, name) = self.split_property(path)?;
+        self.find_property(&class, &name, 0)
+            .map(|(_, property)| property)
+    }
+
+    #[must_use]
+    /// Get the entry that provides a property, and the path of the class that defines it
+    ///
+    /// The class is the one named in the path, or the parent it is inherited from
+    pub fn property_owner(&self, path: &str) -> Option<(String, &PropertyEntry)> {
+        let (class, name) = self.split_property(path)?;
+        self.find_property(&class, &name, 0)
+            .map(|(found, property)| (self.display(&found), property))
+    }
+
+    #[must_use]
+    /// Get the effective value of a property, such as `CfgVehicles >> B_MyCar >> maxSpeed`
+    ///
+    /// Arrays that are expanded with `+=` include the items of the inherited array
+    pub fn value(&self, path: &str) -> Option<Value> {
+        let (class, name) = self.split_property(path)?;
+        self.value_at(&class, &name, 0)
+    }
+
+    #[must_use]
+    /// The parent chain of a class, as paths from the root
+    ///
+    /// Stops at the first parent that can not be found
+    pub fn parents(&self, path: &str) -> Vec<String> {
+        let mut parents = Vec::new();
+        let Some(mut current) = self.resolve(&split(path)) else {
+            return parents;
+        };
+        while let Some(parent) = self.parent_path(&current, 0) {
+            if parents.len() >= MAX_DEPTH {
+                break;
+            }
+            parents.push(self.display(&parent));
+            current = parent;
+        }
+        parents
+    }
+
+    #[must_use]
+    /// Every class whose parent is missing, only declared as external, or part of a cycle
+    pub fn broken_parents(&self) -> Vec<BrokenParent> {
+        let mut broken = Vec::new();
+        self.collect_broken(&mut Vec::new(), &mut Vec::new(), &self.root, &mut broken);
+        broken
+    }
+
+    fn collect_broken(
+        &self,
+        path: &mut Vec<String>,
+        names: &mut Vec<String>,
+        class: &ClassEntry,
+        broken: &mut Vec<BrokenParent>,
+    ) {
+        for key in &class.order {
+            let Some(Entry::Class(child)) = class.entries.get(key) else {
+                continue;
+            };
+            path.push(key.clone());
+            names.push(child.name.clone());
+            if let Some(parent) = &child.parent {
+                let kind = match self.parent_path(path, 0) {
+                    None => Some(BrokenKind::Missing),
+                    Some(found) if self.in_cycle(path, found.clone()) => Some(BrokenKind::Cycle),
+                    Some(found) if self.class_at(&found).is_some_and(ClassEntry::is_external) => {
+                        Some(BrokenKind::External)
+                    }
+                    Some(_) => None,
+                };
+                if let Some(kind) = kind {
+                    broken.push(BrokenParent {
+                        class: names.join(" >> "),
+                        parent: parent.clone(),
+                        origin: child.parent_origin.clone(),
+                        kind,
+                    });
+                }
+            }
+            self.collect_broken(path, names, child, broken);
+            path.pop();
+            names.pop();
+        }
+    }
+
+    /// Does following the parents from `parent` lead back to `path`
+    fn in_cycle(&self, path: &[String], mut parent: Vec<String>) -> bool {
+        for _ in 0..MAX_DEPTH {
+            if parent == path {
+                return true;
+            }
+            let Some(next) = self.parent_path(&parent, 0) else {
+                return false;
+            };
+            parent = next;
+        }
+        false
+    }
+
+    fn class_at(&self, path: &[String]) -> Option<&ClassEntry> {
+        let mut class = &self.root;
+        for key in path {
+            let Some(Entry::Class(child)) = class.entries.get(key) else {
+                return None;
+            };
+            class = child;
+        }
+        Some(class)
+    }
+
+    /// Resolve a path of class names, following inherited classes
+    fn resolve(&self, names: &[String]) -> Option<Vec<String>> {
+        let mut current = Vec::new();
+        for name in names {
+            current = self.find_class(&current, name, false, 0)?;
+        }
+        Some(current)
+    }
+
+    fn split_property(&self, path: &str) -> Option<(Vec<String>, String)> {
+        let mut names = split(path);
+        let name = names.pop()?;
+        Some((self.resolve(&names)?, name))
+    }
+
+    /// Find a class in a container, or in the classes it inherits
+    ///
+    /// `skip_own` skips the container's own class of that name, used for
+    /// `class Turrets: Turrets`, which refers to the inherited class
+    fn find_class(
+        &self,
+        container: &[String],
+        name: &str,
+        skip_own: bool,
+        depth: usize,
+    ) -> Option<Vec<String>> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let class = self.class_at(container)?;
+        if !skip_own && matches!(class.entries.get(name), Some(Entry::Class(_))) {
+            let mut path = container.to_vec();
+            path.push(name.to_string());
+            return Some(path);
+        }
+        let parent = self.parent_path(container, depth + 1)?;
+        self.find_class(&parent, name, false, depth + 1)
+    }
+
+    /// Find the parent of a class, looking in its container first, then the
+    /// containers around it
+    fn parent_path(&self, path: &[String], depth: usize) -> Option<Vec<String>> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let (own, container) = path.split_last()?;
+        let parent = self.class_at(path)?.parent.as_ref()?.to_lowercase();
+        let mut scope = container;
+        let mut skip_own = &parent == own;
+        loop {
+            if let Some(found) = self.find_class(scope, &parent, skip_own, depth + 1) {
+                if !path.starts_with(&found) {
+                    return Some(found);
+                }
+            }
+            let (_, outer) = scope.split_last()?;
+            scope = outer;
+            skip_own = false;
+        }
+    }
+
+    fn find_property(
+        &self,
+        class: &[String],
+        name: &str,
+        depth: usize,
+    ) -> Option<(Vec<String>, &PropertyEntry)> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        match self.class_at(class)?.entries.get(name) {
+            Some(Entry::Property(property)) => Some((class.to_vec(), property)),
+            Some(Entry::Class(_)) => None,
+            None => {
+                let parent = self.parent_path(class, 0)?;
+                self.find_property(&parent, name, depth + 1)
+            }
+        }
+    }
+
+    fn value_at(&self, class: &[String], name: &str, depth: usize) -> Option<Value> {
+        let (found, property) = self.find_property(class, name, depth)?;
+        let Value::Array(array) = &property.value else {
+            return Some(property.value.clone());
+        };
+        if !array.expand {
+            return Some(property.value.clone());
+        }
+        let mut items: Vec<Item> = self
+            .parent_path(&found, 0)
+            .and_then(|parent| self.value_at(&parent, name, depth + 1))
+            .and_then(|inherited| match inherited {
+                Value::Array(inherited) => Some(inherited.items),
+                _ => None,
+            })
+            .unwrap_or_default();
+        items.extend(array.items.iter().cloned());
+        Some(Value::Array(Array {
+            expand: false,
+            items,
+            span: array.span.clone(),
+        }))
+    }
+
+    /// The path of a class with the names as they were written
+    fn display(&self, path: &[String]) -> String {
+        let mut class = &self.root;
+        let mut names = Vec::new();
+        for key in path {
+            let Some(Entry::Class(child)) = class.entries.get(key) else {
+                break;
+            };
+            names.push(child.name.as_str());
+            class = child;
+        }
+        names.join(" >> ")
+    }
+}
+
+fn split(path: &str) -> Vec<String> {
+    path.split(">>")
+        .map(|name| name.trim().to_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// The order to merge the configs in, each config after the configs that provide its `requiredAddons`
+///
+/// Configs that are part of a cycle are merged in the order they are given
+fn load_order(sources: &[(String, Config, Processed)]) -> Vec<usize> {
+    let patches = sources
+        .iter()
+        .map(|(_, config, _)| config.get_patches())
+        .collect::<Vec<_>>();
+    let mut providers: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, patches) in patches.iter().enumerate() {
+        for patch in patches {
+            providers
+                .entry(patch.name().as_str().to_lowercase())
+                .or_default()
+                .push(index);
+        }
+    }
+    // the configs each config waits for, and the configs waiting for it
+    let mut waiting = vec![0; sources.len()];
+    let mut dependents = vec![Vec::new(); sources.len()];
+    for (index, patches) in patches.iter().enumerate() {
+        let mut required = patches
+            .iter()
+            .flat_map(CfgPatch::required_addons)
+            .filter_map(|addon| providers.get(&addon.value().to_lowercase()))
+            .flatten()
+            .copied()
+            .filter(|other| *other != index)
+            .collect::<Vec<_>>();
+        required.sort_unstable();
+        required.dedup();
+        waiting[index] = required.len();
+        for other in required {
+            dependents[other].push(index);
+        }
+    }
+    let mut ready = (0..sources.len())
+        .filter(|index| waiting[*index] == 0)
+        .collect::<BTreeSet<_>>();
+    let mut unplaced = (0..sources.len()).collect::<BTreeSet<_>>();
+    let mut order = Vec::with_capacity(sources.len());
+    while let Some(index) = ready.pop_first().or_else(|| unplaced.first().copied()) {
+        if !unplaced.remove(&index) {
+            continue;
+        }
+        order.push(index);
+        for dependent in &dependents[index] {
+            waiting[*dependent] -= 1;
+            if waiting[*dependent] == 0 {
+                ready.insert(*dependent);
+            }
+        }
+    }
+    order
+}
@@ -0,0 +1,105 @@
+use std::{
+    collections::HashSet,
+    io::{Error, Read, Seek, SeekFrom},
+};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use hemtt_common::io::ReadExt;
+
+use crate::{Class, Ident, Property, Value};
+
+use super::{invalid, value, MAX_DEPTH};
+
+/// Read a class body at the current position
+///
+/// Without a name, the body is read as the root class
+///
+/// `visited` holds the offsets of the bodies that have been read
+pub fn read_body<I: Read + Seek>(
+    input: &mut I,
+    name: Option<Ident>,
+    depth: usize,
+    visited: &mut HashSet<u64>,
+) -> Result<Class, Error> {
+    // class offsets can point back to a parent or share a body, each body
+    // is written once, so reading one twice would loop or repeat work
+    if !visited.insert(input.stream_position()?) {
+        return Err(invalid("class body is used more than once"));
+    }
+    if depth > MAX_DEPTH {
+        return Err(invalid("classes are nested too deeply"));
+    }
+    let parent = input.read_cstring()?;
+    let count = input.read_compressed_int()?;
+    // the count is not trusted to preallocate
+    let mut properties = Vec::new();
+    for _ in 0..count {
+        properties.push(read_property(input, depth, visited)?);
+    }
+    Ok(match name {
+        Some(name) => Class::Local {
+            name,
+            parent: if parent.is_empty() {
+                None
+            } else {
+                Some(ident(parent))
+            },
+            properties,
+            err_missing_braces: false,
+        },
+        None => Class::Root { properties },
+    })
+}
+
+fn read_property<I: Read + Seek>(
+    input: &mut I,
+    depth: usize,
+    visited: &mut HashSet<u64>,
+) -> Result<Property, Error> {
+    match input.read_u8()? {
+        0 => {
+            let name = ident(input.read_cstring()?);
+            let offset = input.read_u32::<LittleEndian>()?;
+            let resume = input.stream_position()?;
+            input.seek(SeekFrom::Start(u64::from(offset)))?;
+            let class = read_body(input, Some(name), depth + 1, visited)?;
+            input.seek(SeekFrom::Start(resume))?;
+            Ok(Property::Class(class))
+        }
+        1 => {
+            let code = input.read_u8()?;
+            let name = ident(input.read_cstring()?);
+            Ok(Property::Entry {
+                name,
+                value: value::read_value(input, code)?,
+                expected_array: false,
+            })
+        }
+        2 => {
+            let name = ident(input.read_cstring()?);
+            Ok(Property::Entry {
+                name,
+                value: Value::Array(value::read_array(input, false)?),
+                expected_array: true,
+            })
+        }
+        3 => Ok(Property::Class(Class::External {
+            name: ident(input.read_cstring()?),
+        })),
+        4 => Ok(Property::Delete(ident(input.read_cstring()?))),
+        5 => {
+            let _flags = input.read_u32::<LittleEndian>()?;
+            let name = ident(input.read_cstring()?);
+            Ok(Property::Entry {
+                name,
+                value: Value::Array(value::read_array(input, true)?),
+                expected_array: true,
+            })
+        }
+        code => Err(invalid(&format!("unknown property type {code}"))),
+    }
+}
+
+const fn ident(value: String) -> Ident {
+    Ident { value, span: 0..0 }
+}
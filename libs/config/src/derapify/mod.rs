@@ -0,0 +1,102 @@
+//! Read rapified configs back into the config model
+
+mod class;
+mod value;
+
+use std::{
+    collections::HashSet,
+    io::{Error, ErrorKind, Read, Seek, SeekFrom},
+};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use hemtt_common::io::ReadExt;
+
+use crate::{Class, Config};
+
+/// Trait for reading rapified objects
+pub trait Derapify: Sized {
+    /// Read the object from the rapified input stream
+    ///
+    /// # Errors
+    /// if the input stream fails, or does not contain valid rapified data
+    fn derapify<I: Read + Seek>(input: &mut I) -> Result<Self, Error>;
+}
+
+/// How deep classes and arrays can be nested before the input is rejected
+const MAX_DEPTH: usize = 128;
+
+#[derive(Debug, PartialEq)]
+/// A rapified config, with its enum table
+///
+/// The enums are not part of [`Config`], and are not printed with it,
+/// as the parser does not support `enum` blocks
+pub struct Rapified {
+    config: Config,
+    enums: Vec<(String, i32)>,
+}
+
+impl Rapified {
+    #[must_use]
+    pub const fn new(config: Config, enums: Vec<(String, i32)>) -> Self {
+        Self { config, enums }
+    }
+
+    #[must_use]
+    /// Get the config
+    pub const fn config(&self) -> &Config {
+        &self.config
+    }
+
+    #[must_use]
+    /// Get the config, consuming the rapified config
+    pub fn into_config(self) -> Config {
+        self.config
+    }
+
+    #[must_use]
+    /// Get the enum table, as names and values
+    pub fn enums(&self) -> &[(String, i32)] {
+        &self.enums
+    }
+}
+
+impl Derapify for Rapified {
+    fn derapify<I: Read + Seek>(input: &mut I) -> Result<Self, Error> {
+        let mut magic = [0; 4];
+        input.read_exact(&mut magic)?;
+        if &magic != b"\0raP" {
+            return Err(invalid("missing raP signature"));
+        }
+        let mut reserved = [0; 8];
+        input.read_exact(&mut reserved)?;
+        let enum_offset = input.read_u32::<LittleEndian>()?;
+        let Class::Root { properties } = class::read_body(input, None, 0, &mut HashSet::new())?
+        else {
+            unreachable!("read_body without a name returns a root class");
+        };
+        input.seek(SeekFrom::Start(u64::from(enum_offset)))?;
+        let count = input.read_u32::<LittleEndian>()?;
+        // the count is not trusted to preallocate
+        let mut enums = Vec::new();
+        for _ in 0..count {
+            enums.push((input.read_cstring()?, input.read_i32::<LittleEndian>()?));
+        }
+        Ok(Self::new(Config(properties), enums))
+    }
+}
+
+impl Derapify for Config {
+    fn derapify<I: Read + Seek>(input: &mut I) -> Result<Self, Error> {
+        Rapified::derapify(input).map(Rapified::into_config)
+    }
+}
+
+#[must_use]
+/// Check if the data is a rapified config
+pub fn is_rapified(data: &[u8]) -> bool {
+    data.starts_with(b"\0raP")
+}
+
+fn invalid(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
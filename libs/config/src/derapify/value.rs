@@ -0,0 +1,72 @@
+use std::io::{Error, Read};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use hemtt_common::io::ReadExt;
+
+use crate::{Array, Expression, Item, Number, Str, Value};
+
+use super::{invalid, MAX_DEPTH};
+
+/// Read a value of the given type code
+pub fn read_value<I: Read>(input: &mut I, code: u8) -> Result<Value, Error> {
+    match code {
+        0 => Ok(Value::Str(read_str(input)?)),
+        4 => Ok(Value::Expression(Expression {
+            value: input.read_cstring()?,
+            span: 0..0,
+            unevaluable: None,
+        })),
+        _ => Ok(Value::Number(read_number(input, code)?)),
+    }
+}
+
+/// Read an array, the items are prefixed with their type code
+pub fn read_array<I: Read>(input: &mut I, expand: bool) -> Result<Array, Error> {
+    Ok(Array {
+        expand,
+        items: read_items(input, 0)?,
+        span: 0..0,
+    })
+}
+
+fn read_items<I: Read>(input: &mut I, depth: usize) -> Result<Vec<Item>, Error> {
+    if depth > MAX_DEPTH {
+        return Err(invalid("arrays are nested too deeply"));
+    }
+    let count = input.read_compressed_int()?;
+    // the count is not trusted to preallocate
+    let mut items = Vec::new();
+    for _ in 0..count {
+        items.push(match input.read_u8()? {
+            0 => Item::Str(read_str(input)?),
+            3 => Item::Array(read_items(input, depth + 1)?),
+            code => Item::Number(read_number(input, code)?),
+        });
+    }
+    Ok(items)
+}
+
+fn read_str<I: Read>(input: &mut I) -> Result<Str, Error> {
+    Ok(Str {
+        value: input.read_cstring()?,
+        span: 0..0,
+    })
+}
+
+fn read_number<I: Read>(input: &mut I, code: u8) -> Result<Number, Error> {
+    match code {
+        1 => Ok(Number::Float32 {
+            value: input.read_f32::<LittleEndian>()?,
+            span: 0..0,
+        }),
+        2 => Ok(Number::Int32 {
+            value: input.read_i32::<LittleEndian>()?,
+            span: 0..0,
+        }),
+        6 => Ok(Number::Int64 {
+            value: input.read_i64::<LittleEndian>()?,
+            span: 0..0,
+        }),
+        code => Err(invalid(&format!("unknown value type {code}"))),
+    }
+}
@@ -0,0 +1,416 @@
+//! Evaluates `__EVAL` and `__EXEC` at build time
+//!
+//! Only a small subset of SQF can be evaluated: numbers, strings, booleans and arrays,
+//! the `+ - * / % mod ^` operators, `format`, `str` and variables assigned by `__EXEC`.
+//! Expressions that use anything else are left as they are, with the reason stored on them.
+
+use std::{collections::HashMap, ops::Range, sync::LazyLock};
+
+use hemtt_sqf::{
+    parser::{database::Database, lexer},
+    BinaryCommand, Expression as Sqf, Statement, Statements, UnaryCommand,
+};
+use hemtt_workspace::reporting::Processed;
+
+use crate::{Class, Config, Expression, Number, Property, Str, Unevaluable, Value};
+
+/// Only loaded once a config uses `__EVAL` or `__EXEC`
+static DATABASE: LazyLock<Database> = LazyLock::new(|| {
+    let mut database = Database::a3(false);
+    // the commands that can be evaluated must parse, even without the wiki
+    database.add_unary_command("format");
+    database.add_unary_command("str");
+    database
+});
+
+#[derive(Debug, Clone, PartialEq)]
+enum Evaluated {
+    Number(f32),
+    String(String),
+    Boolean(bool),
+    Array(Vec<Self>),
+}
+
+impl Evaluated {
+    const fn kind(&self) -> &'static str {
+        match self {
+            Self::Number(_) => "a number",
+            Self::String(_) => "a string",
+            Self::Boolean(_) => "a boolean",
+            Self::Array(_) => "an array",
+        }
+    }
+
+    /// The value as `str` would return it in game
+    fn str(&self) -> String {
+        match self {
+            Self::Number(number) => str_number(*number),
+            Self::String(string) => format!("\"{}\"", string.replace('"', "\"\"")),
+            Self::Boolean(boolean) => boolean.to_string(),
+            Self::Array(items) => format!(
+                "[{}]",
+                items.iter().map(Self::str).collect::<Vec<_>>().join(",")
+            ),
+        }
+    }
+}
+
+/// Variables assigned by `__EXEC`, by their lowercase name
+type Variables = HashMap<String, Evaluated>;
+
+/// Evaluate the `__EVAL` and `__EXEC` of a config, in the order they appear
+///
+/// Expressions that evaluate to a number or a string are replaced by the value
+pub fn evaluate(config: &mut Config, processed: &Processed) {
+    walk(&mut config.0, processed, &mut Variables::new());
+}
+
+fn walk(properties: &mut [Property], processed: &Processed, variables: &mut Variables) {
+    for property in properties {
+        match property {
+            Property::Exec(expression) => {
+                if let Err(unevaluable) = run(expression, processed, variables) {
+                    expression.unevaluable = Some(unevaluable);
+                }
+            }
+            Property::Entry { value, .. } => evaluate_value(value, processed, variables),
+            Property::Class(Class::Local { properties, .. }) => {
+                walk(properties, processed, variables);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn evaluate_value(value: &mut Value, processed: &Processed, variables: &mut Variables) {
+    let Value::Expression(expression) = value else {
+        return;
+    };
+    let span = expression.span.clone();
+    let result = run(expression, processed, variables).and_then(|result| {
+        result.ok_or_else(|| Unevaluable {
+            reason: "does not return a value".to_string(),
+            span: inner(expression),
+        })
+    });
+    match result {
+        Ok(Evaluated::Number(value_number)) => {
+            *value = Value::Number(Number::Float32 {
+                value: value_number,
+                span,
+            });
+        }
+        Ok(Evaluated::String(string)) => {
+            *value = Value::Str(Str {
+                value: string,
+                span,
+            });
+        }
+        Ok(other) => {
+            expression.unevaluable = Some(Unevaluable {
+                reason: format!(
+                    "evaluates to {}, only numbers and strings can be stored",
+                    other.kind()
+                ),
+                span: inner(expression),
+            });
+        }
+        Err(unevaluable) => expression.unevaluable = Some(unevaluable),
+    }
+}
+
+/// The span of the SQF, inside the brackets
+///
+/// The expression ends with the closing bracket, and its value is the text inside
+/// the brackets, so the span does not depend on the whitespace before the opening bracket
+fn inner(expression: &Expression) -> Range<usize> {
+    let end = expression.span.end - 1;
+    end - expression.value.chars().count()..end
+}
+
+/// Run the statements of an expression, returning the value of the last one
+fn run(
+    expression: &Expression,
+    processed: &Processed,
+    variables: &mut Variables,
+) -> Result<Option<Evaluated>, Unevaluable> {
+    let mut last = None;
+    for statement in parse(expression, processed)?.content() {
+        last = match statement {
+            Statement::AssignGlobal(name, value, _) | Statement::AssignLocal(name, value, _) => {
+                let value = eval(value, variables)?;
+                variables.insert(name.to_lowercase(), value);
+                None
+            }
+            Statement::Expression(value, _) => Some(eval(value, variables)?),
+        };
+    }
+    Ok(last)
+}
+
+/// Parse the SQF of an expression, with spans in the processed output
+fn parse(expression: &Expression, processed: &Processed) -> Result<Statements, Unevaluable> {
+    let span = inner(expression);
+    let invalid = |span| Unevaluable {
+        reason: "is not valid SQF".to_string(),
+        span,
+    };
+    let mut tokens = lexer::run(&expression.value).map_err(|_| invalid(span.clone()))?;
+    lexer::strip_comments(&mut tokens);
+    lexer::strip_noop(&mut tokens);
+    let tokens = tokens
+        .into_iter()
+        .map(|(token, range)| (token, range.start + span.start..range.end + span.start));
+    hemtt_sqf::parser::run_for_tokens(&DATABASE, processed, tokens)
+        .map(Statements::optimize)
+        .map_err(|errors| {
+            invalid(
+                errors
+                    .first()
+                    .map(chumsky::error::Simple::span)
+                    .filter(|error| error.end <= span.end)
+                    .unwrap_or(span),
+            )
+        })
+}
+
+fn eval(expression: &Sqf, variables: &Variables) -> Result<Evaluated, Unevaluable> {
+    match expression {
+        Sqf::Number(number, _) => Ok(Evaluated::Number(number.0)),
+        Sqf::String(string, _, _) => Ok(Evaluated::String(string.to_string())),
+        Sqf::Boolean(boolean, _) => Ok(Evaluated::Boolean(*boolean)),
+        Sqf::Array(items, _) | Sqf::ConsumeableArray(items, _) => items
+            .iter()
+            .map(|item| eval(item, variables))
+            .collect::<Result<_, _>>()
+            .map(Evaluated::Array),
+        Sqf::Variable(name, span) => variables
+            .get(&name.to_lowercase())
+            .cloned()
+            .ok_or_else(|| unevaluable(format!("`{name}` is not assigned by an `__EXEC`"), span)),
+        Sqf::UnaryCommand(command, right, span) => unary(command, eval(right, variables)?, span),
+        Sqf::BinaryCommand(command, left, right, span) => binary(
+            command,
+            eval(left, variables)?,
+            eval(right, variables)?,
+            span,
+        ),
+        Sqf::NularCommand(command, span) => Err(unevaluable(
+            format!("`{}` can not be evaluated at build time", command.as_str()),
+            span,
+        )),
+        Sqf::Code(statements) => Err(unevaluable(
+            "code can not be run at build time".to_string(),
+            &statements.span(),
+        )),
+    }
+}
+
+fn unary(
+    command: &UnaryCommand,
+    right: Evaluated,
+    span: &Range<usize>,
+) -> Result<Evaluated, Unevaluable> {
+    match (command, right) {
+        (UnaryCommand::Minus, Evaluated::Number(number)) => Ok(Evaluated::Number(-number)),
+        (UnaryCommand::Plus, right @ (Evaluated::Number(_) | Evaluated::Array(_))) => Ok(right),
+        (UnaryCommand::Named(name), right) => match name.to_lowercase().as_str() {
+            "str" => Ok(Evaluated::String(right.str())),
+            "format" => match right {
+                Evaluated::Array(arguments) => format(&arguments, span),
+                right => Err(unevaluable(
+                    format!("`format` can not be used with {}", right.kind()),
+                    span,
+                )),
+            },
+            _ => Err(unevaluable(
+                format!("`{name}` can not be evaluated at build time"),
+                span,
+            )),
+        },
+        (command, right) => Err(unevaluable(
+            format!(
+                "`{}` can not be used with {}",
+                command.as_str(),
+                right.kind()
+            ),
+            span,
+        )),
+    }
+}
+
+fn binary(
+    command: &BinaryCommand,
+    left: Evaluated,
+    right: Evaluated,
+    span: &Range<usize>,
+) -> Result<Evaluated, Unevaluable> {
+    match (command, left, right) {
+        (BinaryCommand::Add, Evaluated::String(left), Evaluated::String(right)) => {
+            Ok(Evaluated::String(left + &right))
+        }
+        (BinaryCommand::Add, Evaluated::Array(mut left), Evaluated::Array(right)) => {
+            left.extend(right);
+            Ok(Evaluated::Array(left))
+        }
+        (command, Evaluated::Number(left), Evaluated::Number(right)) => {
+            arithmetic(command, left, right, span)
+        }
+        (BinaryCommand::Named(name), _, _) => Err(unevaluable(
+            format!("`{name}` can not be evaluated at build time"),
+            span,
+        )),
+        (command, left, right) => Err(unevaluable(
+            format!(
+                "`{}` can not be used with {} and {}",
+                command.as_str(),
+                left.kind(),
+                right.kind()
+            ),
+            span,
+        )),
+    }
+}
+
+fn arithmetic(
+    command: &BinaryCommand,
+    left: f32,
+    right: f32,
+    span: &Range<usize>,
+) -> Result<Evaluated, Unevaluable> {
+    let number = match command {
+        BinaryCommand::Add => left + right,
+        BinaryCommand::Sub => left - right,
+        BinaryCommand::Mul => left * right,
+        BinaryCommand::Div => left / right,
+        BinaryCommand::Rem | BinaryCommand::Mod => left % right,
+        BinaryCommand::Exp => left.powf(right),
+        _ => {
+            return Err(unevaluable(
+                format!("`{}` can not be evaluated at build time", command.as_str()),
+                span,
+            ))
+        }
+    };
+    if number.is_finite() {
+        Ok(Evaluated::Number(number))
+    } else {
+        Err(unevaluable(
+            format!(
+                "`{left} {} {right}` is not a finite number",
+                command.as_str()
+            ),
+            span,
+        ))
+    }
+}
+
+/// `format`, replacing `%1`, `%2`, ... with the arguments after the format string
+fn format(arguments: &[Evaluated], span: &Range<usize>) -> Result<Evaluated, Unevaluable> {
+    let Some(Evaluated::String(template)) = arguments.first() else {
+        return Err(unevaluable(
+            "`format` needs a string as the first element".to_string(),
+            span,
+        ));
+    };
+    let mut output = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' || !chars.peek().is_some_and(char::is_ascii_digit) {
+            output.push(c);
+            continue;
+        }
+        let mut index = String::new();
+        while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+            index.push(digit);
+        }
+        let argument = index
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| arguments.get(index))
+            .ok_or_else(|| unevaluable(format!("`format` has no argument for `%{index}`"), span))?;
+        match argument {
+            Evaluated::String(string) => output.push_str(string),
+            other => output.push_str(&other.str()),
+        }
+    }
+    Ok(Evaluated::String(output))
+}
+
+/// A number as `str` would return it in game, with up to 6 significant digits
+fn str_number(number: f32) -> String {
+    fn trim(number: &str) -> &str {
+        if number.contains('.') {
+            number.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            number
+        }
+    }
+    let scientific = format!("{number:.5e}");
+    let (mantissa, exponent) = scientific
+        .split_once('e')
+        .expect("scientific notation has an exponent");
+    let exponent = exponent.parse::<i32>().expect("the exponent is a number");
+    if (-4..6).contains(&exponent) {
+        let decimals = usize::try_from(5 - exponent).unwrap_or_default();
+        trim(&format!("{number:.decimals$}")).to_string()
+    } else {
+        format!(
+            "{}e{}{:03}",
+            trim(mantissa),
+            if exponent < 0 { '-' } else { '+' },
+            exponent.abs()
+        )
+    }
+}
+
+fn unevaluable(reason: String, span: &Range<usize>) -> Unevaluable {
+    Unevaluable {
+        reason,
+        span: span.clone(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(str_number(0.0), "0");
+        assert_eq!(str_number(1.0), "1");
+        assert_eq!(str_number(-1.5), "-1.5");
+        assert_eq!(str_number(0.1), "0.1");
+        assert_eq!(str_number(0.0001), "0.0001");
+        assert_eq!(str_number(123_456.0), "123456");
+        assert_eq!(str_number(1_234_567.0), "1.23457e+006");
+        assert_eq!(str_number(0.000_01), "1e-005");
+    }
+
+    #[test]
+    fn formatting() {
+        let span = 0..0;
+        assert_eq!(
+            format(
+                &[
+                    Evaluated::String("%1_%2 %3 %%".to_string()),
+                    Evaluated::String("abe".to_string()),
+                    Evaluated::Number(2.5),
+                    Evaluated::Array(vec![
+                        Evaluated::String("a".to_string()),
+                        Evaluated::Boolean(true)
+                    ]),
+                ],
+                &span
+            ),
+            Ok(Evaluated::String("abe_2.5 [\"a\",true] %%".to_string()))
+        );
+        assert_eq!(
+            format(&[Evaluated::String("%2".to_string())], &span)
+                .unwrap_err()
+                .reason,
+            "`format` has no argument for `%2`"
+        );
+    }
+}
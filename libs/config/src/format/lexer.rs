@@ -0,0 +1,207 @@
+//! Splits config source into tokens, keeping comments and preprocessor directives
+
+use super::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    /// A `//` or `/* */` comment, verbatim
+    Comment(String),
+    /// A preprocessor directive, including its continuation lines
+    Directive(String),
+    /// A quoted string, including its quotes
+    Str(String),
+    /// Anything else, names, numbers and macros with their arguments
+    Word(String),
+    /// One of `{ } [ ] ; = , :`
+    Punct(char),
+    /// `+=`
+    Append,
+}
+
+#[derive(Debug, Clone)]
+pub struct Lexeme {
+    pub token: Token,
+    /// Line breaks between the previous token and this one
+    pub newlines: usize,
+    /// Whitespace between the previous token and this one
+    pub space: bool,
+    /// Line the token starts on, from 1
+    pub line: usize,
+}
+
+const PUNCT: &[char] = &['{', '}', '[', ']', ';', '=', ',', ':'];
+
+pub fn lex(source: &str) -> Result<Vec<Lexeme>, Error> {
+    let chars = source.chars().collect::<Vec<_>>();
+    let mut lexemes = Vec::new();
+    let mut i = 0;
+    let mut line = 1;
+    let mut newlines = 0;
+    let mut space = false;
+    // only whitespace since the start of the line
+    let mut line_start = true;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\n' {
+            newlines += 1;
+            line += 1;
+            space = true;
+            line_start = true;
+            i += 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            space = true;
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let start_line = line;
+        let token = if c == '#' && line_start {
+            i = directive_end(&chars, i, &mut line);
+            Token::Directive(
+                chars[start..i]
+                    .iter()
+                    .collect::<String>()
+                    .lines()
+                    .map(str::trim_end)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            Token::Comment(
+                chars[start..i]
+                    .iter()
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string(),
+            )
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            loop {
+                if i + 1 >= chars.len() {
+                    return Err(Error::new("unterminated block comment", start_line));
+                }
+                if chars[i] == '*' && chars[i + 1] == '/' {
+                    i += 2;
+                    break;
+                }
+                if chars[i] == '\n' {
+                    line += 1;
+                }
+                i += 1;
+            }
+            Token::Comment(chars[start..i].iter().collect())
+        } else if c == '"' || c == '\'' {
+            i = string_end(&chars, i, &mut line)?;
+            Token::Str(chars[start..i].iter().collect())
+        } else if c == '+' && chars.get(i + 1) == Some(&'=') {
+            i += 2;
+            Token::Append
+        } else if PUNCT.contains(&c) {
+            i += 1;
+            Token::Punct(c)
+        } else {
+            i = word_end(&chars, i, &mut line)?;
+            Token::Word(chars[start..i].iter().collect())
+        };
+        lexemes.push(Lexeme {
+            token,
+            newlines,
+            space,
+            line: start_line,
+        });
+        newlines = 0;
+        space = false;
+        line_start = false;
+    }
+    Ok(lexemes)
+}
+
+/// The end of a directive starting at `start`, including its continuation lines
+fn directive_end(chars: &[char], start: usize, line: &mut usize) -> usize {
+    let mut i = start;
+    while i < chars.len() && chars[i] != '\n' {
+        if chars[i] == '\\' && chars.get(i + 1) == Some(&'\n') {
+            *line += 1;
+            i += 1;
+        }
+        i += 1;
+    }
+    i
+}
+
+/// The end of a word starting at `start`, including the arguments of macros
+fn word_end(chars: &[char], start: usize, line: &mut usize) -> Result<usize, Error> {
+    let mut i = start;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace()
+            || PUNCT.contains(&c)
+            || c == '"'
+            || (c == '+' && chars.get(i + 1) == Some(&'='))
+            || (c == '/' && matches!(chars.get(i + 1), Some('/' | '*')))
+        {
+            break;
+        }
+        if c == '(' {
+            i = arguments_end(chars, i, line)?;
+            continue;
+        }
+        i += 1;
+    }
+    Ok(i)
+}
+
+/// The end of a string starting at `start`, a doubled quote does not end it
+fn string_end(chars: &[char], start: usize, line: &mut usize) -> Result<usize, Error> {
+    let quote = chars[start];
+    let start_line = *line;
+    let mut i = start + 1;
+    loop {
+        match chars.get(i) {
+            None => return Err(Error::new("unterminated string", start_line)),
+            Some(c) if *c == quote => {
+                if chars.get(i + 1) == Some(&quote) {
+                    i += 2;
+                } else {
+                    return Ok(i + 1);
+                }
+            }
+            Some('\n') => {
+                *line += 1;
+                i += 1;
+            }
+            Some(_) => i += 1,
+        }
+    }
+}
+
+/// The end of the macro arguments starting at `start`, including nested parentheses and strings
+fn arguments_end(chars: &[char], start: usize, line: &mut usize) -> Result<usize, Error> {
+    let start_line = *line;
+    let mut depth = 0;
+    let mut i = start;
+    while i < chars.len() {
+        match chars[i] {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(i + 1);
+                }
+            }
+            '"' => {
+                i = string_end(chars, i, line)?;
+                continue;
+            }
+            '\n' => *line += 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    Err(Error::new("unclosed `(`", start_line))
+}
@@ -0,0 +1,292 @@
+//! Formats config source files
+//!
+//! The formatter works on the source before it is preprocessed, so comments,
+//! directives and macros are kept where they were written.
+
+use hemtt_common::config::FormatConfig;
+
+mod lexer;
+mod tree;
+
+use lexer::Token;
+use tree::{Array, Item, Node, Value};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A source that could not be formatted
+pub struct Error {
+    message: String,
+    line: usize,
+}
+
+impl Error {
+    fn new(message: impl Into<String>, line: usize) -> Self {
+        Self {
+            message: message.into(),
+            line,
+        }
+    }
+
+    #[must_use]
+    /// Why the source could not be formatted
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    #[must_use]
+    /// The line the problem was found on, from 1
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on line {}", self.message, self.line)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Format a config source
+///
+/// # Errors
+/// [`Error`] if the source has a structure the formatter does not understand,
+/// such as unbalanced braces or comments inside a property
+pub fn format(source: &str, config: &FormatConfig) -> Result<String, Error> {
+    let (bom, source) = source
+        .strip_prefix('\u{feff}')
+        .map_or((false, source), |source| (true, source));
+    let crlf = source.contains("\r\n");
+    let source = source.replace("\r\n", "\n");
+
+    let lexemes = lexer::lex(&source)?;
+    let items = tree::parse(&lexemes)?;
+    let mut printer = Printer {
+        config,
+        lines: Vec::new(),
+    };
+    printer.items(&items, 0, false);
+    let mut output = printer.lines.join("\n");
+    if !output.is_empty() {
+        output.push('\n');
+    }
+
+    // the formatter may only change whitespace and the spelling of values
+    let before = lexemes
+        .into_iter()
+        .map(|lexeme| normalize(lexeme.token))
+        .collect::<Vec<_>>();
+    let after = lexer::lex(&output)?
+        .into_iter()
+        .map(|lexeme| lexeme.token)
+        .collect::<Vec<_>>();
+    if before != after {
+        return Err(Error::new("formatting would change the config", 1));
+    }
+
+    if crlf {
+        output = output.replace('\n', "\r\n");
+    }
+    if bom {
+        output.insert(0, '\u{feff}');
+    }
+    Ok(output)
+}
+
+fn normalize(token: Token) -> Token {
+    match token {
+        Token::Word(word) => Token::Word(number(&word).unwrap_or(word)),
+        Token::Str(string) => Token::Str(quote(string)),
+        token => token,
+    }
+}
+
+/// Write a number as `0.5` and `1e-05` instead of `.5` and `1E-05`
+fn number(word: &str) -> Option<String> {
+    let (sign, digits) = word
+        .strip_prefix('-')
+        .map_or(("", word), |digits| ("-", digits));
+    let (mantissa, exponent) = digits
+        .split_once(['e', 'E'])
+        .map_or((digits, None), |(mantissa, exponent)| {
+            (mantissa, Some(exponent))
+        });
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    // integers are left alone
+    let has_digits = if fraction.is_empty() {
+        !whole.is_empty() && exponent.is_some()
+    } else {
+        true
+    };
+    if !has_digits || !is_digits(whole) || !is_digits(fraction) {
+        return None;
+    }
+    let mut number = format!(
+        "{sign}{}{mantissa}",
+        if whole.is_empty() { "0" } else { "" }
+    );
+    if let Some(exponent) = exponent {
+        let exponent_digits = exponent.strip_prefix(['-', '+']).unwrap_or(exponent);
+        if exponent_digits.is_empty() || !is_digits(exponent_digits) {
+            return None;
+        }
+        number.push('e');
+        number.push_str(exponent);
+    }
+    Some(number)
+}
+
+/// Write strings with double quotes
+fn quote(string: String) -> String {
+    if !string.starts_with('\'') {
+        return string;
+    }
+    let content = string[1..string.len() - 1]
+        .replace("''", "'")
+        .replace('"', "\"\"");
+    format!("\"{content}\"")
+}
+
+struct Printer<'a> {
+    config: &'a FormatConfig,
+    lines: Vec<String>,
+}
+
+impl Printer<'_> {
+    fn indent(&self, depth: usize) -> String {
+        if self.config.use_tabs() {
+            "\t".repeat(depth)
+        } else {
+            " ".repeat(depth * self.config.indent_size())
+        }
+    }
+
+    fn width(&self, line: &str) -> usize {
+        line.chars()
+            .map(|c| {
+                if c == '\t' {
+                    self.config.indent_size()
+                } else {
+                    1
+                }
+            })
+            .sum()
+    }
+
+    fn push(&mut self, depth: usize, line: &str) {
+        let indent = self.indent(depth);
+        self.lines.push(format!("{indent}{line}"));
+    }
+
+    fn items(&mut self, items: &[Item], depth: usize, trailing_comma: bool) {
+        let last_value = items
+            .iter()
+            .rposition(|item| matches!(item.node, Node::Value(_)));
+        // no blank line after the opening brace
+        let mut first = true;
+        for (index, item) in items.iter().enumerate() {
+            if let Node::Comment {
+                text,
+                trailing: true,
+            } = &item.node
+            {
+                if let Some(line) = self.lines.last_mut() {
+                    line.push(' ');
+                    line.push_str(text);
+                    continue;
+                }
+            }
+            if item.blank_before && !first {
+                self.lines.push(String::new());
+            }
+            first = false;
+            match &item.node {
+                Node::Comment { text, .. } | Node::Directive(text) | Node::Other(text) => {
+                    self.push(depth, text);
+                }
+                Node::Delete(name) => self.push(depth, &format!("delete {name};")),
+                Node::Class { name, parent, body } => {
+                    let header = parent.as_ref().map_or_else(
+                        || format!("class {name}"),
+                        |parent| format!("class {name}: {parent}"),
+                    );
+                    match body {
+                        None => self.push(depth, &format!("{header};")),
+                        Some(body) if body.is_empty() => {
+                            self.push(depth, &format!("{header} {{}};"));
+                        }
+                        Some(body) => {
+                            self.push(depth, &format!("{header} {{"));
+                            self.items(body, depth + 1, false);
+                            self.push(depth, "};");
+                        }
+                    }
+                }
+                Node::Property {
+                    name,
+                    array,
+                    append,
+                    value,
+                } => {
+                    let prefix = format!(
+                        "{name}{} {} ",
+                        if *array { "[]" } else { "" },
+                        if *append { "+=" } else { "=" }
+                    );
+                    self.value(&prefix, value, depth, ";");
+                }
+                Node::Enum(array) => self.array("enum ", array, depth, ";", true),
+                Node::Value(value) => {
+                    let suffix = if last_value == Some(index) && !trailing_comma {
+                        ""
+                    } else {
+                        ","
+                    };
+                    self.value("", value, depth, suffix);
+                }
+            }
+        }
+    }
+
+    fn value(&mut self, prefix: &str, value: &Value, depth: usize, suffix: &str) {
+        match value {
+            Value::Expression(expression) => {
+                self.push(depth, &format!("{prefix}{expression}{suffix}"));
+            }
+            Value::Array(array) => self.array(prefix, array, depth, suffix, false),
+        }
+    }
+
+    fn array(&mut self, prefix: &str, array: &Array, depth: usize, suffix: &str, wrap: bool) {
+        if !wrap {
+            if let Some(inline) = inline(array) {
+                let line = format!("{}{prefix}{inline}{suffix}", self.indent(depth));
+                if self.width(&line) <= self.config.max_width() {
+                    self.lines.push(line);
+                    return;
+                }
+            }
+        }
+        self.push(depth, &format!("{prefix}{{"));
+        self.items(&array.items, depth + 1, array.trailing_comma);
+        self.push(depth, &format!("}}{suffix}"));
+    }
+}
+
+/// An array on a single line, if it has no comments or directives
+fn inline(array: &Array) -> Option<String> {
+    let mut values = Vec::new();
+    for item in &array.items {
+        match &item.node {
+            Node::Value(Value::Expression(expression)) => values.push(expression.clone()),
+            Node::Value(Value::Array(array)) => values.push(inline(array)?),
+            _ => return None,
+        }
+    }
+    Some(format!(
+        "{{{}{}}}",
+        values.join(", "),
+        if array.trailing_comma { "," } else { "" }
+    ))
+}
@@ -0,0 +1,332 @@
+//! The statements of a config source, as written
+
+use super::{
+    lexer::{Lexeme, Token},
+    normalize, Error,
+};
+
+#[derive(Debug)]
+pub struct Item {
+    pub node: Node,
+    /// There was a blank line before the item
+    pub blank_before: bool,
+}
+
+#[derive(Debug)]
+pub enum Node {
+    Comment {
+        text: String,
+        /// On the same line as the previous token
+        trailing: bool,
+    },
+    Directive(String),
+    Class {
+        name: String,
+        parent: Option<String>,
+        /// `None` for `class name;`
+        body: Option<Vec<Item>>,
+    },
+    Delete(String),
+    Property {
+        name: String,
+        array: bool,
+        append: bool,
+        value: Value,
+    },
+    Enum(Array),
+    /// A value in an array
+    Value(Value),
+    /// A statement that is not part of the config grammar, usually a macro
+    Other(String),
+}
+
+#[derive(Debug)]
+pub enum Value {
+    Expression(String),
+    Array(Array),
+}
+
+#[derive(Debug)]
+pub struct Array {
+    pub items: Vec<Item>,
+    pub trailing_comma: bool,
+}
+
+pub fn parse(lexemes: &[Lexeme]) -> Result<Vec<Item>, Error> {
+    Parser { lexemes, pos: 0 }.body(false)
+}
+
+struct Parser<'a> {
+    lexemes: &'a [Lexeme],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Lexeme> {
+        self.lexemes.get(self.pos)
+    }
+
+    fn peek_token(&self) -> Option<&Token> {
+        self.peek().map(|lexeme| &lexeme.token)
+    }
+
+    fn line(&self) -> usize {
+        self.peek()
+            .or_else(|| self.lexemes.last())
+            .map_or(1, |lexeme| lexeme.line)
+    }
+
+    fn error<T>(&self, message: &str) -> Result<T, Error> {
+        Err(Error::new(message, self.line()))
+    }
+
+    fn next(&mut self) -> Option<&Lexeme> {
+        let lexeme = self.lexemes.get(self.pos);
+        self.pos += 1;
+        lexeme
+    }
+
+    fn expect(&mut self, punct: char) -> Result<(), Error> {
+        if self.peek_token() == Some(&Token::Punct(punct)) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(&format!("expected `{punct}`"))
+        }
+    }
+
+    fn word(&mut self) -> Result<String, Error> {
+        if let Some(Token::Word(word)) = self.peek_token() {
+            let word = word.clone();
+            self.pos += 1;
+            Ok(word)
+        } else {
+            self.error("expected a name")
+        }
+    }
+
+    /// Comments and directives, which can be between any statements or array values
+    fn trivia(&mut self) -> Option<Node> {
+        let lexeme = self.peek()?;
+        let node = match &lexeme.token {
+            Token::Comment(text) => Node::Comment {
+                text: text.clone(),
+                trailing: lexeme.newlines == 0 && self.pos != 0,
+            },
+            Token::Directive(text) => Node::Directive(text.clone()),
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(node)
+    }
+
+    /// The statements of a class, or of the file when `nested` is false
+    fn body(&mut self, nested: bool) -> Result<Vec<Item>, Error> {
+        let mut items = Vec::new();
+        loop {
+            let Some(lexeme) = self.peek() else {
+                if nested {
+                    return self.error("expected `}`");
+                }
+                return Ok(items);
+            };
+            let blank_before = lexeme.newlines > 1;
+            if lexeme.token == Token::Punct('}') {
+                if nested {
+                    return Ok(items);
+                }
+                return self.error("unexpected `}`");
+            }
+            let node = match self.trivia() {
+                Some(node) => node,
+                None => self.statement()?,
+            };
+            items.push(Item { node, blank_before });
+        }
+    }
+
+    fn statement(&mut self) -> Result<Node, Error> {
+        let Some(Token::Word(word)) = self.peek_token() else {
+            return self.error("expected a statement");
+        };
+        match word.as_str() {
+            "class" => {
+                self.pos += 1;
+                self.class()
+            }
+            "delete" => {
+                self.pos += 1;
+                let name = self.word()?;
+                self.expect(';')?;
+                Ok(Node::Delete(name))
+            }
+            "enum" => {
+                self.pos += 1;
+                self.expect('{')?;
+                let array = self.array()?;
+                self.expect(';')?;
+                Ok(Node::Enum(array))
+            }
+            _ => match self.lexemes.get(self.pos + 1).map(|lexeme| &lexeme.token) {
+                Some(Token::Punct('[' | '=') | Token::Append) => self.property(),
+                _ => self.other(),
+            },
+        }
+    }
+
+    fn class(&mut self) -> Result<Node, Error> {
+        let name = self.word()?;
+        let parent = if self.peek_token() == Some(&Token::Punct(':')) {
+            self.pos += 1;
+            Some(self.word()?)
+        } else {
+            None
+        };
+        let body = if self.peek_token() == Some(&Token::Punct('{')) {
+            self.pos += 1;
+            let body = self.body(true)?;
+            self.expect('}')?;
+            Some(body)
+        } else {
+            None
+        };
+        self.expect(';')?;
+        Ok(Node::Class { name, parent, body })
+    }
+
+    fn property(&mut self) -> Result<Node, Error> {
+        let name = self.word()?;
+        let array = if self.peek_token() == Some(&Token::Punct('[')) {
+            self.pos += 1;
+            self.expect(']')?;
+            true
+        } else {
+            false
+        };
+        let append = match self.next().map(|lexeme| &lexeme.token) {
+            Some(Token::Punct('=')) => false,
+            Some(Token::Append) => true,
+            _ => {
+                self.pos -= 1;
+                return self.error("expected `=` or `+=`");
+            }
+        };
+        let value = if self.peek_token() == Some(&Token::Punct('{')) {
+            self.pos += 1;
+            Value::Array(self.array()?)
+        } else {
+            Value::Expression(self.expression(&[';'])?)
+        };
+        self.expect(';')?;
+        Ok(Node::Property {
+            name,
+            array,
+            append,
+            value,
+        })
+    }
+
+    /// The items of an array, after its `{`, including the closing `}`
+    fn array(&mut self) -> Result<Array, Error> {
+        let mut items = Vec::new();
+        let mut trailing_comma = false;
+        // a value was read, and the next value needs a comma first
+        let mut needs_comma = false;
+        loop {
+            let Some(lexeme) = self.peek() else {
+                return self.error("expected `}`");
+            };
+            let blank_before = lexeme.newlines > 1;
+            match lexeme.token {
+                Token::Punct('}') => {
+                    self.pos += 1;
+                    return Ok(Array {
+                        items,
+                        trailing_comma,
+                    });
+                }
+                Token::Punct(',') if needs_comma => {
+                    self.pos += 1;
+                    needs_comma = false;
+                    trailing_comma = true;
+                    continue;
+                }
+                _ => {}
+            }
+            let node = if let Some(node) = self.trivia() {
+                node
+            } else if needs_comma {
+                return self.error("expected `,` or `}`");
+            } else {
+                needs_comma = true;
+                trailing_comma = false;
+                if self.peek_token() == Some(&Token::Punct('{')) {
+                    self.pos += 1;
+                    Node::Value(Value::Array(self.array()?))
+                } else {
+                    Node::Value(Value::Expression(self.expression(&[',', '}'])?))
+                }
+            };
+            items.push(Item { node, blank_before });
+        }
+    }
+
+    /// The tokens of a value, up to one of `stop`, as written with single spaces
+    fn expression(&mut self, stop: &[char]) -> Result<String, Error> {
+        let mut expression = String::new();
+        while let Some(lexeme) = self.peek() {
+            let text = match &lexeme.token {
+                Token::Punct(c) if stop.contains(c) => break,
+                Token::Punct('{' | '}') => return self.error("unexpected brace in a value"),
+                Token::Comment(_) | Token::Directive(_) => {
+                    return self.error("comments and directives are not supported inside a value")
+                }
+                token => text(token),
+            };
+            if !expression.is_empty() && lexeme.space {
+                expression.push(' ');
+            }
+            expression.push_str(&text);
+            self.pos += 1;
+        }
+        if expression.is_empty() {
+            return self.error("expected a value");
+        }
+        Ok(expression)
+    }
+
+    /// A statement that is not a class or property, such as a macro,
+    /// up to its `;` or the end of the line
+    fn other(&mut self) -> Result<Node, Error> {
+        let mut statement = String::new();
+        while let Some(lexeme) = self.peek() {
+            if !statement.is_empty() && lexeme.newlines > 0 {
+                break;
+            }
+            let text = match &lexeme.token {
+                Token::Punct('}') | Token::Comment(_) | Token::Directive(_) => break,
+                Token::Punct('{') => return self.error("unexpected `{`"),
+                token => text(token),
+            };
+            if !statement.is_empty() && lexeme.space {
+                statement.push(' ');
+            }
+            statement.push_str(&text);
+            self.pos += 1;
+            if text == ";" {
+                break;
+            }
+        }
+        Ok(Node::Other(statement))
+    }
+}
+
+fn text(token: &Token) -> String {
+    match normalize(token.clone()) {
+        Token::Comment(text) | Token::Directive(text) | Token::Str(text) | Token::Word(text) => {
+            text
+        }
+        Token::Punct(c) => c.to_string(),
+        Token::Append => "+=".to_string(),
+    }
+}
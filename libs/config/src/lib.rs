@@ -7,6 +7,10 @@
 use std::sync::Arc;
 
 pub mod analyze;
+pub mod database;
+pub mod derapify;
+mod evaluate;
+pub mod format;
 mod model;
 pub mod parse;
 pub mod rapify;
@@ -41,7 +45,8 @@ pub fn parse(
                 })
                 .collect())
         },
-        |config| {
+        |mut config| {
+            evaluate::evaluate(&mut config, processed);
             let mut manager = LintManager::new(
                 project.map_or_else(Default::default, |project| project.lints().config().clone()),
             );
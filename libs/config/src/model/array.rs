@@ -10,6 +10,20 @@ pub struct Array {
     pub(crate) span: Range<usize>,
 }
 
+impl Array {
+    #[must_use]
+    /// Is the array expanding its parent (`+=`)
+    pub const fn expand(&self) -> bool {
+        self.expand
+    }
+
+    #[must_use]
+    /// Get the items
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 /// An array value
 pub enum Item {
@@ -22,3 +36,31 @@ pub enum Item {
     /// An invalid value
     Invalid(Range<usize>),
 }
+
+impl std::fmt::Display for Array {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_items(f, &self.items)
+    }
+}
+
+impl std::fmt::Display for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Str(s) => write!(f, "{s}"),
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Array(items) => write_items(f, items),
+            Self::Invalid(_) => write!(f, "<invalid>"),
+        }
+    }
+}
+
+fn write_items(f: &mut std::fmt::Formatter<'_>, items: &[Item]) -> std::fmt::Result {
+    write!(f, "{{")?;
+    for (i, item) in items.iter().enumerate() {
+        if i != 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    write!(f, "}}")
+}
@@ -1,6 +1,6 @@
 use hemtt_common::version::Version;
 
-use crate::{analyze::CfgPatch, Class, Number, Property, Value};
+use crate::{analyze::CfgPatch, Array, Class, Item, Number, Property, Str, Value};
 
 #[derive(Debug, PartialEq)]
 /// A config file
@@ -15,6 +15,64 @@ impl Config {
     }
 }
 
+impl std::fmt::Display for Config {
+    /// Writes the config as source, as it would be written in a `config.cpp`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_properties(f, &self.0, 0)
+    }
+}
+
+const INDENT: &str = "    ";
+
+fn write_properties(
+    f: &mut std::fmt::Formatter<'_>,
+    properties: &[Property],
+    depth: usize,
+) -> std::fmt::Result {
+    let indent = INDENT.repeat(depth);
+    for property in properties {
+        match property {
+            Property::Entry { name, value, .. } => match value {
+                Value::Array(array) => writeln!(
+                    f,
+                    "{indent}{}[] {} {array};",
+                    name.as_str(),
+                    if array.expand() { "+=" } else { "=" }
+                )?,
+                _ => writeln!(f, "{indent}{} = {value};", name.as_str())?,
+            },
+            Property::Class(Class::External { name }) => {
+                writeln!(f, "{indent}class {};", name.as_str())?;
+            }
+            Property::Class(Class::Local {
+                name,
+                parent,
+                properties,
+                ..
+            }) => {
+                write!(f, "{indent}class {}", name.as_str())?;
+                if let Some(parent) = parent {
+                    write!(f, ": {}", parent.as_str())?;
+                }
+                if properties.is_empty() {
+                    writeln!(f, " {{}};")?;
+                } else {
+                    writeln!(f, " {{")?;
+                    write_properties(f, properties, depth + 1)?;
+                    writeln!(f, "{indent}}};")?;
+                }
+            }
+            Property::Class(Class::Root { properties }) => {
+                write_properties(f, properties, depth)?;
+            }
+            Property::Delete(name) => writeln!(f, "{indent}delete {};", name.as_str())?,
+            Property::MissingSemicolon(name, _) => writeln!(f, "{indent}{};", name.as_str())?,
+            Property::Exec(expression) => writeln!(f, "{indent}__EXEC({})", expression.value)?,
+        }
+    }
+    Ok(())
+}
+
 impl Config {
     #[must_use]
     /// Get the patches
@@ -32,17 +90,38 @@ impl Config {
                         }) = patch
                         {
                             let mut required_version = Version::new(0, 0, 0, None);
+                            let mut required_addons = Vec::new();
+                            let mut units = Vec::new();
+                            let mut weapons = Vec::new();
                             for property in properties {
                                 if let Property::Entry { name, value, .. } = property {
-                                    if name.as_str().to_lowercase() == "requiredversion" {
-                                        if let Value::Number(Number::Float32 { value, .. }) = value
-                                        {
+                                    match (name.as_str().to_lowercase().as_str(), value) {
+                                        (
+                                            "requiredversion",
+                                            Value::Number(Number::Float32 { value, .. }),
+                                        ) => {
                                             required_version = Version::from(*value);
                                         }
+                                        ("requiredaddons", Value::Array(array)) => {
+                                            required_addons = strings(array);
+                                        }
+                                        ("units", Value::Array(array)) => {
+                                            units = strings(array);
+                                        }
+                                        ("weapons", Value::Array(array)) => {
+                                            weapons = strings(array);
+                                        }
+                                        _ => {}
                                     }
                                 }
                             }
-                            patches.push(CfgPatch::new(name.clone(), required_version));
+                            patches.push(CfgPatch::new(
+                                name.clone(),
+                                required_version,
+                                required_addons,
+                                units,
+                                weapons,
+                            ));
                         }
                     }
                 }
@@ -50,4 +129,62 @@ impl Config {
         }
         patches
     }
+
+    #[must_use]
+    /// Get the strings that reference a stringtable key, such as `"$STR_abe_main_name"`
+    pub fn localizations(&self) -> Vec<Str> {
+        let mut localizations = Vec::new();
+        localized_properties(&self.0, &mut localizations);
+        localizations
+    }
+}
+
+fn localized_properties(properties: &[Property], localizations: &mut Vec<Str>) {
+    for property in properties {
+        match property {
+            Property::Entry { value, .. } => match value {
+                Value::Str(string) => localized_str(string, localizations),
+                Value::Array(array) | Value::UnexpectedArray(array) => {
+                    localized_items(&array.items, localizations);
+                }
+                _ => {}
+            },
+            Property::Class(Class::Local { properties, .. } | Class::Root { properties }) => {
+                localized_properties(properties, localizations);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn localized_items(items: &[Item], localizations: &mut Vec<Str>) {
+    for item in items {
+        match item {
+            Item::Str(string) => localized_str(string, localizations),
+            Item::Array(items) => localized_items(items, localizations),
+            _ => {}
+        }
+    }
+}
+
+fn localized_str(string: &Str, localizations: &mut Vec<Str>) {
+    if string
+        .value
+        .get(..5)
+        .is_some_and(|start| start.eq_ignore_ascii_case("$str_"))
+    {
+        localizations.push(string.clone());
+    }
+}
+
+/// The strings of an array, other items are skipped
+fn strings(array: &Array) -> Vec<Str> {
+    array
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Str(s) => Some(s.clone()),
+            _ => None,
+        })
+        .collect()
 }
@@ -5,4 +5,41 @@ use std::ops::Range;
 pub struct Expression {
     pub(crate) value: String,
     pub(crate) span: Range<usize>,
+    /// Why the expression could not be evaluated at build time
+    pub(crate) unevaluable: Option<Unevaluable>,
+}
+
+impl Expression {
+    #[must_use]
+    /// Get why the expression could not be evaluated at build time
+    pub const fn unevaluable(&self) -> Option<&Unevaluable> {
+        self.unevaluable.as_ref()
+    }
+}
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "__EVAL({})", self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The part of an expression that could not be evaluated at build time
+pub struct Unevaluable {
+    pub(crate) reason: String,
+    pub(crate) span: Range<usize>,
+}
+
+impl Unevaluable {
+    #[must_use]
+    /// Get why the expression could not be evaluated
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    #[must_use]
+    /// Get the span of the part that could not be evaluated
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
 }
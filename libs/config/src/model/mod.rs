@@ -12,7 +12,7 @@ pub use self::str::Str;
 pub use array::{Array, Item};
 pub use class::Class;
 pub use config::Config;
-pub use expression::Expression;
+pub use expression::{Expression, Unevaluable};
 pub use ident::Ident;
 pub use number::Number;
 pub use property::Property;
@@ -85,6 +85,8 @@ impl std::fmt::Display for Number {
         match self {
             Self::Int32 { value, .. } => write!(f, "{value}"),
             Self::Int64 { value, .. } => write!(f, "{value}"),
+            // keep the decimal point, so the value is read back as a float
+            Self::Float32 { value, .. } if value.fract() == 0.0 => write!(f, "{value:.1}"),
             Self::Float32 { value, .. } => write!(f, "{value}"),
         }
     }
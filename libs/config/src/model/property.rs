@@ -1,6 +1,6 @@
 use std::ops::Range;
 
-use crate::{Class, Ident, Value};
+use crate::{Class, Expression, Ident, Value};
 
 #[derive(Debug, Clone, PartialEq)]
 /// A property of a class
@@ -20,18 +20,23 @@ pub enum Property {
     Delete(Ident),
     /// A property that is missing a semicolon
     MissingSemicolon(Ident, Range<usize>),
+    /// An `__EXEC` statement, it is run at build time and is not rapified
+    Exec(Expression),
 }
 
 impl Property {
     #[must_use]
     /// Get the name of the property
     ///
-    /// # Panics
-    /// If this is a [`Class::Root`], which should never occur
-    pub fn name(&self) -> &Ident {
+    /// `None` for a [`Property::Exec`], which has no name,
+    /// or a [`Class::Root`], which should never occur
+    pub fn name(&self) -> Option<&Ident> {
         match self {
-            Self::Class(c) => c.name().expect("root should not be a property"),
-            Self::MissingSemicolon(name, _) | Self::Delete(name) | Self::Entry { name, .. } => name,
+            Self::Class(c) => c.name(),
+            Self::MissingSemicolon(name, _) | Self::Delete(name) | Self::Entry { name, .. } => {
+                Some(name)
+            }
+            Self::Exec(_) => None,
         }
     }
 
@@ -20,3 +20,13 @@ impl Str {
         self.span.clone()
     }
 }
+
+impl std::fmt::Display for Str {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "\"{}\"",
+            self.value.replace('"', "\"\"").replace('\n', "\" \\n \"")
+        )
+    }
+}
@@ -50,3 +50,15 @@ impl Value {
         }
     }
 }
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Str(s) => write!(f, "{s}"),
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Expression(e) => write!(f, "{e}"),
+            Self::Array(a) | Self::UnexpectedArray(a) => write!(f, "{a}"),
+            Self::Invalid(_) => write!(f, "<invalid>"),
+        }
+    }
+}
@@ -183,4 +183,33 @@ mod tests {
             }),]),)
         );
     }
+
+    #[test]
+    fn localizations() {
+        let config = config()
+            .parse(
+                r#"class CfgVehicles {
+    class abe_car {
+        displayName = "$STR_abe_main_car";
+        description = "STR_abe_main_not_a_reference";
+        textures[] = {"$str_abe_main_red", {"$STR_abe_main_blue"}, 1};
+    };
+};
+author = "$STR_abe_main_author";"#,
+            )
+            .expect("config should parse");
+        assert_eq!(
+            config
+                .localizations()
+                .iter()
+                .map(|string| (string.value(), string.span()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("$STR_abe_main_car", 62..81),
+                ("$str_abe_main_red", 159..178),
+                ("$STR_abe_main_blue", 181..201),
+                ("$STR_abe_main_author", 227..249),
+            ]
+        );
+    }
 }
@@ -2,7 +2,10 @@ use chumsky::prelude::*;
 
 use crate::{Class, Property, Value};
 
-use super::{ident::ident, value::value};
+use super::{
+    ident::ident,
+    value::{exec, value},
+};
 
 fn class_parent() -> impl Parser<char, crate::Ident, Error = Simple<char>> {
     just(':')
@@ -53,6 +56,7 @@ pub fn property() -> impl Parser<char, Property, Error = Simple<char>> {
             });
         let class = choice((class_local, class_missing_braces(), class_external));
         choice((
+            exec().map(Property::Exec),
             class.map(Property::Class),
             just("delete ")
                 .padded()
@@ -101,12 +105,10 @@ pub fn property() -> impl Parser<char, Property, Error = Simple<char>> {
                 }),
         ))
         .then(just(';').padded().or_not())
-        .map_with_span(|(property, semi), range| {
-            if semi.is_some() {
-                property
-            } else {
-                Property::MissingSemicolon(property.name().clone(), range)
-            }
+        .map_with_span(|(property, semi), range| match property.name() {
+            // `__EXEC` has no name, and does not need a semicolon
+            Some(name) if semi.is_none() => Property::MissingSemicolon(name.clone(), range),
+            _ => property,
         })
     })
 }
@@ -446,6 +448,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn exec_statement() {
+        let expected = Ok(Property::Exec(crate::Expression {
+            value: "_width = 10".to_string(),
+            span: 0..19,
+            unevaluable: None,
+        }));
+        assert_eq!(property().parse("__EXEC(_width = 10)"), expected);
+        assert_eq!(property().parse("__EXEC(_width = 10);"), expected);
+    }
+
     #[test]
     fn invalid_external_with_parent() {
         assert_eq!(
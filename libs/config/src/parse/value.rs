@@ -46,24 +46,34 @@ pub fn math() -> impl Parser<char, Number, Error = Simple<char>> {
 }
 
 pub fn eval() -> impl Parser<char, Expression, Error = Simple<char>> {
-    just("__EVAL".to_string())
-        .ignore_then(recursive(|eval| {
-            eval.repeated()
+    sqf("__EVAL")
+}
+
+pub fn exec() -> impl Parser<char, Expression, Error = Simple<char>> {
+    sqf("__EXEC")
+}
+
+/// The SQF in the brackets after `__EVAL` or `__EXEC`
+fn sqf(keyword: &'static str) -> impl Parser<char, Expression, Error = Simple<char>> {
+    let contents = recursive(|contents| {
+        contents
+            .delimited_by(just('('), just(')'))
+            .map(|s: String| format!("({s})"))
+            .or(none_of("()".to_string())
+                .repeated()
                 .at_least(1)
-                .map(|s| format!("({})", s.join("")))
-                .delimited_by(just("(".to_string()), just(")".to_string()))
-                .or(none_of("()".to_string())
-                    .repeated()
-                    .at_least(1)
-                    .collect::<String>())
-        }))
-        .map_with_span(|expr, span| Expression {
-            value: expr
-                .strip_prefix('(')
-                .and_then(|s| s.strip_suffix(')'))
-                .expect("eval should be wrapped in brackets")
-                .to_string(),
+                .collect::<String>())
+            .repeated()
+            .at_least(1)
+            .map(|s| s.join(""))
+    });
+    just(keyword.to_string())
+        .ignore_then(text::whitespace())
+        .ignore_then(contents.delimited_by(just('('), just(')')))
+        .map_with_span(|value, span| Expression {
+            value,
             span,
+            unevaluable: None,
         })
 }
 
@@ -111,16 +121,27 @@ mod tests {
             super::eval().parse("__EVAL(1 + 2)"),
             Ok(Expression {
                 value: "1 + 2".to_string(),
-                span: 0..13
+                span: 0..13,
+                unevaluable: None,
             })
         );
         assert_eq!(
             super::eval().parse("__EVAL(2 * (1 + 1))"),
             Ok(Expression {
                 value: "2 * (1 + 1)".to_string(),
-                span: 0..19
+                span: 0..19,
+                unevaluable: None,
+            })
+        );
+        assert_eq!(
+            super::eval().parse("__EVAL (1 + 2)"),
+            Ok(Expression {
+                value: "1 + 2".to_string(),
+                span: 0..14,
+                unevaluable: None,
             })
         );
+        assert!(super::eval().parse("__EVAL 1 + 2").is_err());
     }
 
     #[test]
@@ -16,6 +16,7 @@ impl Rapify for Class {
         let mut written = 0;
         match self {
             Self::Local { properties, .. } | Self::Root { properties } => {
+                let properties = rapified_properties(properties);
                 let parent = self.parent();
                 if let Some(parent) = &parent {
                     output.write_cstring(parent.as_str())?;
@@ -27,19 +28,19 @@ impl Rapify for Class {
 
                 let properties_len = properties
                     .iter()
-                    .map(|p| p.name().len() + 1 + p.rapified_length())
+                    .map(|(name, p)| name.len() + 1 + p.rapified_length())
                     .sum::<usize>();
                 let mut class_offset = offset + written + properties_len + 4;
                 let mut class_bodies: Vec<Cursor<Box<[u8]>>> = Vec::new();
                 let pre_properties = written;
 
-                for property in properties {
+                for (name, property) in &properties {
                     let pre_write = written;
                     let code = property.property_code();
                     output.write_all(&code)?;
                     written += code.len();
-                    output.write_cstring(property.name().as_str())?;
-                    written += property.name().len() + 1;
+                    output.write_cstring(name.as_str())?;
+                    written += name.len() + 1;
                     match property {
                         Property::Entry { value, .. } => {
                             written += value.rapify(output, offset)?;
@@ -58,11 +59,11 @@ impl Rapify for Class {
                             }
                         }
                         Property::Delete(_) => continue,
-                        Property::MissingSemicolon(_, _) => unreachable!(),
+                        Property::MissingSemicolon(_, _) | Property::Exec(_) => unreachable!(),
                     }
                     assert_eq!(
                         written - pre_write,
-                        property.rapified_length() + property.name().len() + 1
+                        property.rapified_length() + name.len() + 1
                     );
                 }
 
@@ -89,6 +90,7 @@ impl Rapify for Class {
         match self {
             Self::External { .. } => 0,
             Self::Local { properties, .. } | Self::Root { properties, .. } => {
+                let properties = rapified_properties(properties);
                 let parent_length = self.parent().map_or(0, Ident::len);
                 parent_length
                     + 1 // parent null terminator
@@ -96,8 +98,8 @@ impl Rapify for Class {
                     + compressed_int_len(properties.len() as u32)
                     + properties
                         .iter()
-                        .map(|p| {
-                            p.name().len()
+                        .map(|(name, p)| {
+                            name.len()
                                 + 1 // name null terminator
                                 + p.rapified_length()
                                 + match p {
@@ -110,3 +112,13 @@ impl Rapify for Class {
         }
     }
 }
+
+/// The properties that are written, with their names
+///
+/// `__EXEC` has no name, it only exists at build time
+fn rapified_properties(properties: &[Property]) -> Vec<(&Ident, &Property)> {
+    properties
+        .iter()
+        .filter_map(|p| p.name().map(|name| (name, p)))
+        .collect()
+}
@@ -1,8 +1,9 @@
 use std::io::Cursor;
 
 use byteorder::{LittleEndian, WriteBytesExt};
+use hemtt_common::io::WriteExt;
 
-use crate::{Class, Config};
+use crate::{derapify::Rapified, Config};
 
 use super::Rapify;
 
@@ -12,31 +13,69 @@ impl Rapify for Config {
         output: &mut O,
         _offset: usize,
     ) -> Result<usize, std::io::Error> {
-        output.write_all(b"\0raP")?;
-        output.write_all(b"\0\0\0\0\x08\0\0\0")?;
-
-        let root_class = Class::Root {
-            properties: self.0.clone(),
-        };
-        let buffer: Box<[u8]> = vec![0; root_class.rapified_length()].into_boxed_slice();
-        let mut cursor = Cursor::new(buffer);
-        let written = root_class.rapify(&mut cursor, 16)?;
-        assert_eq!(written, root_class.rapified_length());
-
-        let enum_offset = 16 + cursor.get_ref().len() as u32;
-        output.write_u32::<LittleEndian>(enum_offset)?;
+        rapify(self, &[], output)
+    }
 
-        output.write_all(cursor.get_ref())?;
+    fn rapified_length(&self) -> usize {
+        rapified_length(self, &[])
+    }
+}
 
-        output.write_all(b"\0\0\0\0")?;
-        assert_eq!(written + 20, self.rapified_length());
-        Ok(written + 20)
+impl Rapify for Rapified {
+    fn rapify<O: std::io::Write>(
+        &self,
+        output: &mut O,
+        _offset: usize,
+    ) -> Result<usize, std::io::Error> {
+        rapify(self.config(), self.enums(), output)
     }
 
     fn rapified_length(&self) -> usize {
-        let root_class = Class::Root {
-            properties: self.0.clone(),
-        };
-        root_class.rapified_length() + 20 // metadata
+        rapified_length(self.config(), self.enums())
     }
 }
+
+fn rapify<O: std::io::Write>(
+    config: &Config,
+    enums: &[(String, i32)],
+    output: &mut O,
+) -> Result<usize, std::io::Error> {
+    output.write_all(b"\0raP")?;
+    output.write_all(b"\0\0\0\0\x08\0\0\0")?;
+
+    let root_class = config.to_class();
+    let buffer: Box<[u8]> = vec![0; root_class.rapified_length()].into_boxed_slice();
+    let mut cursor = Cursor::new(buffer);
+    let written = root_class.rapify(&mut cursor, 16)?;
+    assert_eq!(written, root_class.rapified_length());
+
+    let enum_offset = 16 + cursor.get_ref().len() as u32;
+    output.write_u32::<LittleEndian>(enum_offset)?;
+
+    output.write_all(cursor.get_ref())?;
+
+    output.write_u32::<LittleEndian>(enums.len() as u32)?;
+    for (name, value) in enums {
+        output.write_cstring(name)?;
+        output.write_i32::<LittleEndian>(*value)?;
+    }
+    assert_eq!(
+        written + 16 + enums_length(enums),
+        rapified_length(config, enums)
+    );
+    Ok(written + 16 + enums_length(enums))
+}
+
+fn rapified_length(config: &Config, enums: &[(String, i32)]) -> usize {
+    config.to_class().rapified_length()
+        + 16 // metadata
+        + enums_length(enums)
+}
+
+/// The length of the enum table
+fn enums_length(enums: &[(String, i32)]) -> usize {
+    4 + enums
+        .iter()
+        .map(|(name, _)| name.len() + 1 + 4)
+        .sum::<usize>()
+}
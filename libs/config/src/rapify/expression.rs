@@ -36,6 +36,7 @@ mod tests {
         let written = Expression {
             value: "getResolution".to_string(),
             span: 0..14,
+            unevaluable: None,
         }
         .rapify(&mut buffer, 0)
         .unwrap();
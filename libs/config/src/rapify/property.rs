@@ -27,7 +27,7 @@ impl Rapify for Property {
                     Class::External { .. } => 0,
                 },
                 Self::Delete(_) => 0,
-                Self::MissingSemicolon(_, _) => unreachable!(),
+                Self::MissingSemicolon(_, _) | Self::Exec(_) => unreachable!(),
             }
     }
 }
@@ -57,7 +57,7 @@ impl Property {
             Self::Delete(_) => {
                 vec![4]
             }
-            Self::MissingSemicolon(_, _) => unreachable!(),
+            Self::MissingSemicolon(_, _) | Self::Exec(_) => unreachable!(),
         }
     }
 }
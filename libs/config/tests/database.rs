@@ -0,0 +1,247 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_common::config::ProjectConfig;
+use hemtt_config::{
+    analyze::lint_database,
+    database::{BrokenKind, Database},
+};
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::LayerType;
+
+const ROOT: &str = "tests/database/";
+
+/// The addons are given out of order, `vehicles` and `extras` require `main`
+fn database() -> Database {
+    database_of(&["vehicles", "main", "extras"])
+}
+
+fn database_of(addons: &[&str]) -> Database {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&std::path::PathBuf::from(ROOT), LayerType::Source)
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    Database::new(
+        addons
+            .iter()
+            .map(|addon| {
+                let source = workspace.join(format!("{addon}/config.cpp")).unwrap();
+                let processed = Processor::run(&source).unwrap();
+                let config = hemtt_config::parse(None, &processed).unwrap().into_config();
+                (addon.to_string(), config, processed)
+            })
+            .collect(),
+    )
+}
+
+fn value(database: &Database, path: &str) -> String {
+    database.value(path).unwrap().to_string()
+}
+
+#[test]
+fn load_order() {
+    assert_eq!(database().load_order(), ["main", "vehicles", "extras"]);
+}
+
+#[test]
+fn effective_values() {
+    let database = database();
+    assert_eq!(
+        value(&database, "CfgVehicles >> abe_car_base >> maxSpeed"),
+        "120"
+    );
+    assert_eq!(
+        value(&database, "cfgvehicles >> ABE_HATCHBACK >> MAXSPEED"),
+        "120"
+    );
+    assert_eq!(
+        value(&database, "CfgVehicles >> abe_hatchback >> fuelCapacity"),
+        "40"
+    );
+    assert_eq!(
+        value(
+            &database,
+            "CfgVehicles >> abe_hatchback >> hiddenSelections"
+        ),
+        r#"{"camo", "camo_2"}"#
+    );
+    assert_eq!(
+        value(
+            &database,
+            "CfgVehicles >> abe_car_base >> Turrets >> MainTurret >> gunnerName"
+        ),
+        r#""Gunner""#
+    );
+    assert_eq!(
+        value(
+            &database,
+            "CfgVehicles >> abe_hatchback >> Turrets >> MainTurret >> gunnerName"
+        ),
+        r#""Driver""#
+    );
+    assert!(database
+        .value("CfgVehicles >> abe_hatchback >> armor")
+        .is_none());
+    assert!(database
+        .value("CfgVehicles >> abe_hatchback >> Turrets")
+        .is_none());
+}
+
+#[test]
+fn expand_across_addons() {
+    let database = database_of(&["expand", "main"]);
+    assert_eq!(database.load_order(), ["main", "expand"]);
+    assert_eq!(
+        value(&database, "CfgVehicles >> abe_car_base >> hiddenSelections"),
+        r#"{"camo", "camo_3"}"#
+    );
+}
+
+#[test]
+fn definitions() {
+    let database = database();
+    let property = database
+        .property("CfgVehicles >> abe_hatchback >> maxSpeed")
+        .unwrap();
+    let definitions = property.definitions();
+    assert_eq!(definitions.len(), 2);
+    assert_eq!(definitions[0].addon, "main");
+    assert_eq!(definitions[0].path.as_str(), "/main/config.cpp");
+    assert_eq!(definitions[1].addon, "vehicles");
+    assert_eq!(definitions[1].path.as_str(), "/vehicles/config.cpp");
+    assert_eq!(definitions[1].line, 12);
+
+    let (owner, _) = database
+        .property_owner("CfgVehicles >> abe_hatchback >> maxSpeed")
+        .unwrap();
+    assert_eq!(owner, "CfgVehicles >> abe_car_base");
+    let (owner, _) = database
+        .property_owner("CfgVehicles >> abe_hatchback >> fuelCapacity")
+        .unwrap();
+    assert_eq!(owner, "CfgVehicles >> abe_hatchback");
+
+    let class = database.class("CfgVehicles >> abe_car_base").unwrap();
+    assert_eq!(class.parent(), Some("Car_F"));
+    assert_eq!(class.definitions().len(), 2);
+    assert!(database.class("CfgVehicles >> Car").unwrap().is_external());
+}
+
+#[test]
+fn parents() {
+    let database = database();
+    assert_eq!(
+        database.parents("CfgVehicles >> abe_hatchback"),
+        [
+            "CfgVehicles >> abe_car_base",
+            "CfgVehicles >> Car_F",
+            "CfgVehicles >> Car",
+        ]
+    );
+    assert_eq!(
+        database.parents("CfgVehicles >> abe_hatchback >> Turrets >> MainTurret"),
+        ["CfgVehicles >> abe_car_base >> Turrets >> MainTurret"]
+    );
+    assert_eq!(
+        database.parents("CfgVehicles >> abe_hatchback >> Turrets"),
+        [
+            "CfgVehicles >> abe_car_base >> Turrets",
+            "CfgVehicles >> Car_F >> Turrets",
+        ]
+    );
+}
+
+#[test]
+fn broken_parents() {
+    let broken = database()
+        .broken_parents()
+        .into_iter()
+        .map(|broken| (broken.class, broken.kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        broken,
+        [
+            ("CfgVehicles >> Car_F".to_string(), BrokenKind::External),
+            (
+                "CfgVehicles >> abe_car_base >> Turrets".to_string(),
+                BrokenKind::External
+            ),
+            ("CfgVehicles >> abe_sedan".to_string(), BrokenKind::External),
+            ("CfgVehicles >> abe_cycle_b".to_string(), BrokenKind::Cycle),
+            ("CfgVehicles >> abe_cycle_a".to_string(), BrokenKind::Cycle),
+        ]
+    );
+}
+
+fn lint(ident: &str) -> Vec<String> {
+    lint_database(&database(), Some(&ProjectConfig::test_project()))
+        .iter()
+        .filter(|code| code.ident() == ident)
+        .map(|code| code.message())
+        .collect()
+}
+
+#[test]
+fn parent_not_defined() {
+    assert_eq!(
+        lint("L-C11"),
+        [
+            "parent `abe_car` of `CfgVehicles >> abe_sedan` is only declared as external",
+            "`CfgVehicles >> abe_cycle_b` inherits from itself through `abe_cycle_a`",
+            "`CfgVehicles >> abe_cycle_a` inherits from itself through `abe_cycle_b`",
+        ]
+    );
+}
+
+#[test]
+fn patches() {
+    let database = database();
+    let patch = database.patch("ABE_VEHICLES").unwrap();
+    assert_eq!(patch.addon, "vehicles");
+    assert_eq!(
+        patch
+            .units
+            .iter()
+            .map(|unit| unit.name.as_str())
+            .collect::<Vec<_>>(),
+        ["abe_hatchback", "abe_sedan", "abe_wagon"]
+    );
+    assert_eq!(patch.required_addons[0].name, "abe_main");
+    assert!(database.requires("vehicles", "main"));
+    assert!(database.requires("extras", "main"));
+    assert!(!database.requires("extras", "vehicles"));
+    assert!(!database.requires("main", "vehicles"));
+}
+
+#[test]
+fn required_addons_cycle() {
+    assert_eq!(
+        lint("L-C12"),
+        ["`requiredAddons` form a cycle: abe_extras -> abe_extras_compat -> abe_extras"]
+    );
+}
+
+#[test]
+fn unknown_required_addon() {
+    assert_eq!(
+        lint("L-C13"),
+        ["`abe_extras` requires unknown addon `cba_main`"]
+    );
+}
+
+#[test]
+fn parent_not_required() {
+    assert_eq!(
+        lint("L-C14"),
+        ["`CfgVehicles >> abe_wagon` inherits from `abe_hatchback` of addon `vehicles`, which `extras` does not require"]
+    );
+}
+
+#[test]
+fn unit_not_public() {
+    assert_eq!(
+        lint("L-C15"),
+        [
+            "unit `abe_sedan` of `abe_vehicles` does not have `scope = 2`",
+            "unit `abe_wagon` of `abe_vehicles` is not a `CfgVehicles` class of the addon",
+        ]
+    );
+}
@@ -0,0 +1,14 @@
+class CfgPatches {
+    class abe_expand {
+        units[] = {};
+        weapons[] = {};
+        requiredVersion = 2.14;
+        requiredAddons[] = {"abe_main"};
+    };
+};
+class CfgVehicles {
+    class Car_F;
+    class abe_car_base: Car_F {
+        hiddenSelections[] += {"camo_3"};
+    };
+};
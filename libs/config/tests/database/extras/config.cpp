@@ -0,0 +1,18 @@
+class CfgPatches {
+    class abe_extras {
+        units[] = {"abe_wagon"};
+        weapons[] = {};
+        requiredVersion = 2.14;
+        requiredAddons[] = {"abe_main", "cba_main", "abe_extras_compat"};
+    };
+    class abe_extras_compat {
+        units[] = {};
+        weapons[] = {};
+        requiredVersion = 2.14;
+        requiredAddons[] = {"abe_extras"};
+    };
+};
+class CfgVehicles {
+    class abe_hatchback;
+    class abe_wagon: abe_hatchback {};
+};
@@ -0,0 +1,24 @@
+class CfgPatches {
+    class abe_main {
+        units[] = {};
+        weapons[] = {};
+        requiredVersion = 2.14;
+        requiredAddons[] = {"A3_Soft_F", "3DEN", "gm_core", "data_f_lxWS"};
+    };
+};
+class CfgVehicles {
+    class Car;
+    class Car_F: Car {
+        class Turrets;
+    };
+    class abe_car_base: Car_F {
+        maxSpeed = 100;
+        fuelCapacity = 50;
+        hiddenSelections[] = {"camo"};
+        class Turrets: Turrets {
+            class MainTurret {
+                gunnerName = "Gunner";
+            };
+        };
+    };
+};
@@ -0,0 +1,29 @@
+class CfgPatches {
+    class abe_vehicles {
+        units[] = {"abe_hatchback", "abe_sedan", "abe_wagon"};
+        weapons[] = {};
+        requiredVersion = 2.14;
+        requiredAddons[] = {"abe_main"};
+    };
+};
+class CfgVehicles {
+    class Car_F;
+    class abe_car_base: Car_F {
+        maxSpeed = 120;
+    };
+    class abe_hatchback: abe_car_base {
+        scope = 2;
+        fuelCapacity = 40;
+        hiddenSelections[] += {"camo_2"};
+        class Turrets: Turrets {
+            class MainTurret: MainTurret {
+                gunnerName = "Driver";
+            };
+        };
+    };
+    class abe_car;
+    class abe_sedan: abe_car {};
+    class abe_cycle_b;
+    class abe_cycle_a: abe_cycle_b {};
+    class abe_cycle_b: abe_cycle_a {};
+};
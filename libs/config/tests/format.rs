@@ -0,0 +1,89 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_common::config::{FormatConfig, ProjectConfig};
+
+const ROOT: &str = "tests/format/";
+
+macro_rules! format {
+    ($dir:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<config_format_ $dir>]() {
+                format(stringify!($dir));
+            }
+        }
+    };
+}
+
+format!(arrays);
+format!(basic);
+format!(comments);
+format!(macros);
+format!(tabs);
+format!(values);
+
+fn format(dir: &str) {
+    let folder = std::path::PathBuf::from(ROOT).join(dir);
+    let project = folder.join("project.toml");
+    let config = if project.exists() {
+        ProjectConfig::from_file(&project).unwrap().format().clone()
+    } else {
+        FormatConfig::default()
+    };
+    let source = std::fs::read_to_string(folder.join("source.hpp")).unwrap();
+    let formatted = hemtt_config::format::format(&source, &config).unwrap();
+    let expected_path = folder.join("expected.hpp");
+    if !expected_path.exists() {
+        std::fs::write(&expected_path, &formatted).unwrap();
+        panic!("expected file did not exist, created it");
+    }
+    let expected = std::fs::read_to_string(expected_path)
+        .unwrap()
+        .replace("\r\n", "\n");
+    assert_eq!(formatted, expected);
+    assert_eq!(
+        hemtt_config::format::format(&formatted, &config).unwrap(),
+        formatted,
+        "formatting is not idempotent"
+    );
+}
+
+#[test]
+fn line_endings() {
+    let source = "\u{feff}class abe {\r\n  value=1;\r\n};\r\n";
+    assert_eq!(
+        hemtt_config::format::format(source, &FormatConfig::default()).unwrap(),
+        "\u{feff}class abe {\r\n    value = 1;\r\n};\r\n"
+    );
+}
+
+#[test]
+fn errors() {
+    let config = FormatConfig::default();
+    for (source, message, line) in [
+        ("class abe {\n    value = 1;\n", "expected `}`", 2),
+        ("class abe {};\n};\n", "unexpected `}`", 2),
+        ("value = \"abe;\n", "unterminated string", 1),
+        (
+            "value = 1 // one\n;\n",
+            "comments and directives are not supported inside a value",
+            1,
+        ),
+    ] {
+        let error = hemtt_config::format::format(source, &config).unwrap_err();
+        assert_eq!(error.message(), message, "{source}");
+        assert_eq!(error.line(), line, "{source}");
+    }
+}
+
+#[test]
+fn rapify_sources() {
+    // the sources of the rapify tests cover what real projects write
+    for entry in std::fs::read_dir("tests/rapify").unwrap() {
+        let path = entry.unwrap().path().join("source.hpp");
+        let source = std::fs::read_to_string(&path).unwrap();
+        if let Err(error) = hemtt_config::format::format(&source, &FormatConfig::default()) {
+            panic!("{}: {error}", path.display());
+        }
+    }
+}
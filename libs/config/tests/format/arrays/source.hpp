@@ -0,0 +1,17 @@
+class abe_arrays {
+    short[] = {1,2,3};
+    strings[] = {"a","b"};
+    nested[]={{1,2},{3,4}};
+    long[] = {"abe_hatchback", "abe_sedan", "abe_wagon", "abe_coupe", "abe_convertible", "abe_minivan"};
+    longNested[] = {{"abe_hatchback", "abe_sedan", "abe_wagon"}, {"abe_coupe", "abe_convertible", "abe_minivan"}};
+    appended[] += {"abe_truck"};
+    trailing[] = {1, 2,};
+    commented[] = {
+        "abe_hatchback", // the small one
+        // the big one
+        "abe_wagon"
+    };
+    empty[] = {
+
+    };
+};
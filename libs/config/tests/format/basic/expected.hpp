@@ -0,0 +1,21 @@
+class CfgPatches {
+    class abe_main {
+        units[] = {};
+        weapons[] = {};
+        requiredVersion = 2.14;
+        requiredAddons[] = {"A3_Data_F_Decade_Loadorder"};
+    };
+};
+class CfgVehicles {
+    class Car;
+    class abe_car_base: Car {
+        scope = 1;
+        displayName = "Car Base";
+        class HitPoints {};
+    };
+    class abe_hatchback: abe_car_base {
+        scope = 2;
+        displayName = "Hatchback";
+    };
+    delete abe_old;
+};
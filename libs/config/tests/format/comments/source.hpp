@@ -0,0 +1,24 @@
+
+
+// Vehicles of the mod
+/* Block
+   comment */
+#include "script_component.hpp"
+#define ABE_SCOPE(x) \
+    scope = x; \
+    scopeCurator = x
+
+class CfgVehicles { // all vehicles
+
+
+
+    class abe_car_base {
+        scope = 1;   // hidden
+
+
+        #ifdef DEBUG
+        debug = 1;
+        #endif
+    }; // end of base
+    /* inline */ class abe_hatchback: abe_car_base {};
+};
@@ -0,0 +1,24 @@
+#include "script_component.hpp"
+
+class CfgPatches {
+    class ADDON {
+        name = COMPONENT_NAME;
+        units[] = {};
+        requiredVersion = REQUIRED_VERSION;
+        requiredAddons[] = {QUOTE(DOUBLES(PREFIX,main)), "cba_main"};
+        author = ECSTRING(common,ACETeam);
+        VERSION_CONFIG;
+        ABE_AUTHORS(
+            "Brett", "Alice"
+        )
+    };
+};
+class CfgFunctions {
+    class GVAR(functions) {
+        PATHTO_FNC(init);
+    };
+};
+class abe_eval {
+    value = __EVAL(1 + 2);
+    string = QUOTE(call FUNC(init));
+};
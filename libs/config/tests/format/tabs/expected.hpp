@@ -0,0 +1,9 @@
+class abe_tabs {
+	class inner {
+		values[] = {
+			"abe_hatchback",
+			"abe_sedan",
+			"abe_wagon"
+		};
+	};
+};
@@ -0,0 +1,5 @@
+class abe_tabs {
+    class inner {
+        values[] = {"abe_hatchback", "abe_sedan", "abe_wagon"};
+    };
+};
@@ -0,0 +1,15 @@
+class abe_values {
+    half = 0.5;
+    negative = -0.25;
+    small = 1e-05;
+    large = 2.5e+10;
+    whole = 10;
+    hex = 0xFF;
+    single = "hello";
+    quoted = "say ""hi""";
+    escaped = "say ""hi""";
+    multiline = "hello" \n "world";
+    expression = 1+2;
+    spaced = 1 + 2;
+    version = 1.0.2;
+};
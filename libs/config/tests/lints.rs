@@ -29,6 +29,7 @@ lint!(c07_expected_array);
 lint!(c08_missing_semicolon);
 lint!(c09_magwell_missing_magazine);
 lint!(c10_class_missing_braces);
+lint!(c16_unevaluable_expression);
 
 fn lint(file: &str) -> String {
     let folder = std::path::PathBuf::from(ROOT);
@@ -0,0 +1,9 @@
+__EXEC(_width = _height * 2)
+class CfgTest {
+    width = __EVAL(_width);
+    list = __EVAL([1, 2]);
+    both = __EVAL("abe" + 1);
+    code = __EVAL({ 1 });
+    invalid = __EVAL(1 +);
+    spaced = __EVAL ([3]);
+};
@@ -1,8 +1,12 @@
 #![allow(clippy::unwrap_used)]
 
-use std::io::Read;
+use std::io::{Read, Write};
 
-use hemtt_config::rapify::Rapify;
+use hemtt_config::{
+    derapify::{Derapify, Rapified},
+    rapify::Rapify,
+    Config,
+};
 use hemtt_preprocessor::Processor;
 use hemtt_workspace::LayerType;
 
@@ -23,6 +27,7 @@ rapify!(ace_main);
 rapify!(cba_multiline);
 rapify!(delete_class);
 rapify!(eval);
+rapify!(eval_exec);
 rapify!(external_class);
 rapify!(inheritence_array_extend);
 rapify!(join_digit);
@@ -68,6 +73,11 @@ fn rapify(dir: &str) {
     let written = parsed.config().rapify(&mut output, 0).unwrap();
     assert_eq!(written, parsed.config().rapified_length());
     assert_eq!(output, expected);
+    let derapified = Config::derapify(&mut std::io::Cursor::new(&output)).unwrap();
+    let mut rerapified = Vec::new();
+    derapified.rapify(&mut rerapified, 0).unwrap();
+    assert_eq!(rerapified, output);
+    assert_eq!(reparse(&derapified.to_string()), output);
     let vanilla_path = folder.join("cfgconvert.bin");
     if vanilla_path.exists() {
         let mut expected = Vec::new();
@@ -76,3 +86,69 @@ fn rapify(dir: &str) {
         assert_eq!(output, expected);
     };
 }
+
+/// Parse printed source and rapify it
+fn reparse(source: &str) -> Vec<u8> {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .memory()
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let path = workspace.join("printed.hpp").unwrap();
+    path.create_file()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .unwrap();
+    let processed = Processor::run(&path).unwrap();
+    let Ok(parsed) = hemtt_config::parse(None, &processed) else {
+        panic!("failed to parse printed source:\n{source}");
+    };
+    let mut output = Vec::new();
+    parsed.config().rapify(&mut output, 0).unwrap();
+    output
+}
+
+#[test]
+fn enums() {
+    let config = Config::derapify(
+        &mut std::fs::File::open("tests/rapify/single_class/expected.bin").unwrap(),
+    )
+    .unwrap();
+    let rapified = Rapified::new(
+        config,
+        vec![
+            ("destructno".to_string(), 0),
+            ("destructbuilding".to_string(), 1),
+        ],
+    );
+    let mut output = Vec::new();
+    let written = rapified.rapify(&mut output, 0).unwrap();
+    assert_eq!(written, rapified.rapified_length());
+    assert_eq!(written, output.len());
+    let derapified = Rapified::derapify(&mut std::io::Cursor::new(&output)).unwrap();
+    assert_eq!(derapified, rapified);
+    let mut rerapified = Vec::new();
+    derapified.rapify(&mut rerapified, 0).unwrap();
+    assert_eq!(rerapified, output);
+    // the enums are not printed, the parser does not support them
+    assert!(!derapified.config().to_string().contains("enum"));
+}
+
+#[test]
+fn derapify_untrusted() {
+    let header = |body: &[u8]| {
+        let mut data = b"\0raP\0\0\0\0\x08\0\0\0\0\0\0\0".to_vec();
+        data.extend_from_slice(body);
+        data
+    };
+    // a class whose body is the root class, nesting without end
+    let recursive = header(b"\0\x01\0a\0\x10\0\0\0");
+    assert!(Config::derapify(&mut std::io::Cursor::new(recursive)).is_err());
+    // two classes sharing one body, which would repeat work for every level
+    let mut shared = header(b"\0\x02\0a\0\x20\0\0\0\0b\0\x20\0\0\0\0\0\0\0\0\0");
+    shared[12] = 34;
+    let error = Config::derapify(&mut std::io::Cursor::new(shared)).unwrap_err();
+    assert_eq!(error.to_string(), "class body is used more than once");
+    // a count far larger than the data
+    let truncated = header(b"\0\xFF\xFF\xFF\xFF\x0F");
+    assert!(Config::derapify(&mut std::io::Cursor::new(truncated)).is_err());
+}
@@ -0,0 +1,10 @@
+__EXEC(_width = 10; ABE_prefix = "abe")
+class CfgEval {
+    number = __EVAL(1 + 2 * 3);
+    width = __EVAL(_width * 2);
+    name = __EVAL(abe_prefix + "_main");
+    formatted = __EVAL(format ["%1_%2", ABE_PREFIX, _width / 4]);
+    text = __EVAL(str 1.5 + str "one");
+    __EXEC(_width = _width + 1)
+    after = __EVAL(_width ^ 2 % 7);
+};
@@ -11,13 +11,31 @@ const unsafe extern "C" fn get_unaligned_le16(p: *const ::std::os::raw::c_void)
 }
 
 #[no_mangle]
+pub unsafe extern "C" fn lzo1x_decompress_safe(
+    in_: *const u8,
+    in_len: usize,
+    out: *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    let mut in_used = 0;
+    lzo1x_decompress_safe_used(in_, in_len, out, out_len, &mut in_used)
+}
+
+/// [`lzo1x_decompress_safe`], also returning the number of bytes of input used
+/// when the end of the stream is reached
+///
+/// # Safety
+/// `in_` must be valid for reads of `in_len` bytes, `out` must be valid for writes
+/// of as many bytes as `out_len` points to, and `out_len` and `in_used` must be
+/// valid for reads and writes. `out_len` is updated to the number of bytes written.
 #[allow(clippy::too_many_lines)]
 #[allow(clippy::similar_names)]
-pub unsafe extern "C" fn lzo1x_decompress_safe(
+pub unsafe fn lzo1x_decompress_safe_used(
     in_: *const u8,
     in_len: usize,
     out: *mut u8,
     out_len: *mut usize,
+    in_used: *mut usize,
 ) -> i32 {
     let mut current_block;
     let mut op: *mut u8;
@@ -420,6 +438,7 @@ pub unsafe extern "C" fn lzo1x_decompress_safe(
             } else if current_block == 21 {
                 *out_len = ((op as isize).wrapping_sub(out as isize)
                     / ::std::mem::size_of::<u8>() as isize) as usize;
+                *in_used = (ip as usize).wrapping_sub(in_ as usize);
                 return if t != 3usize {
                     -1i32
                 } else if ip == ip_end {
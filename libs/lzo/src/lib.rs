@@ -121,6 +121,35 @@ pub fn decompress_to_slice<'a>(in_: &[u8], out: &'a mut [u8]) -> Result<&'a mut
     }
 }
 
+#[cfg(feature = "decompress")]
+/// decompress a stream that is followed by other data, filling `out`
+///
+/// returns the number of bytes of `in_` that were used
+///
+/// # Errors
+/// [`LzoError`] if an error occurs, or the stream does not fill `out`
+pub fn decompress_stream(in_: &[u8], out: &mut [u8]) -> Result<usize, LzoError> {
+    unsafe {
+        let mut out_len = out.len();
+        let mut in_used = 0;
+        let err = decompress::lzo1x_decompress_safe_used(
+            in_.as_ptr(),
+            in_.len(),
+            out.as_mut_ptr(),
+            &mut out_len,
+            &mut in_used,
+        );
+        let res = mem::transmute::<i32, LzoError>(err);
+        if res != LzoError::Ok && res != LzoError::InputNotConsumed {
+            return Err(res);
+        }
+        if out_len != out.len() {
+            return Err(LzoError::Error);
+        }
+        Ok(in_used)
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 #[test]
@@ -156,3 +185,19 @@ fn compress_and_back() {
         }
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[test]
+fn decompress_stream_followed_by_data() {
+    let data = (0..5000u32).map(|i| (i % 7) as u8).collect::<Vec<_>>();
+    let mut compressed = Vec::with_capacity(worst_compress(data.len()));
+    compress(&data, &mut compressed).unwrap();
+    let used = compressed.len();
+    compressed.extend([1, 2, 3, 4]);
+    let mut out = vec![0; data.len()];
+    assert_eq!(decompress_stream(&compressed, &mut out).unwrap(), used);
+    assert_eq!(out, data);
+    let mut short = vec![0; data.len() + 1];
+    assert!(decompress_stream(&compressed, &mut short).is_err());
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Severity},
+};
+
+use crate::{
+    analyze::{ModelData, ModelLintData},
+    resolution,
+};
+
+crate::analyze::lint!(LintM01MissingGeometryLods);
+
+impl Lint<ModelLintData> for LintM01MissingGeometryLods {
+    fn ident(&self) -> &str {
+        "missing_geometry_lods"
+    }
+
+    fn sort(&self) -> u32 {
+        10
+    }
+
+    fn description(&self) -> &str {
+        "Checks for models without a Geometry, Fire Geometry or View Geometry LOD"
+    }
+
+    fn documentation(&self) -> &str {
+        r"### Example
+
+**Incorrect**
+
+A model with only resolution LODs and a Geometry LOD.
+
+**Correct**
+
+A model with a Geometry, Fire Geometry and View Geometry LOD.
+
+### Explanation
+
+Without a Geometry LOD the model has no collisions. Without a Fire Geometry or View Geometry LOD the engine falls back to the Geometry LOD, which is usually less accurate for bullets and more expensive for visibility checks.
+
+Models that are only used as proxies can disable this lint.
+
+```toml
+[lints.models.missing_geometry_lods]
+enabled = false
+```
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<ModelLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<ModelLintData> for Runner {
+    type Target = ModelData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &ModelData,
+        _data: &ModelLintData,
+    ) -> Codes {
+        let (p3d, path) = target;
+        let missing = [
+            resolution::GEOMETRY,
+            resolution::FIRE_GEOMETRY,
+            resolution::VIEW_GEOMETRY,
+        ]
+        .into_iter()
+        .filter(|special| {
+            !p3d.lods
+                .iter()
+                .any(|lod| resolution::is(lod.resolution, *special))
+        })
+        .map(resolution::name)
+        .collect::<Vec<_>>();
+        if missing.is_empty() {
+            return vec![];
+        }
+        vec![Arc::new(CodeM01MissingGeometryLods::new(
+            path.as_str().to_string(),
+            missing,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeM01MissingGeometryLods {
+    path: String,
+    missing: Vec<String>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeM01MissingGeometryLods {
+    fn ident(&self) -> &'static str {
+        "L-M01"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/models.html#missing_geometry_lods")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "model `{}` has no {} LOD",
+            self.path,
+            self.missing.join(", ")
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("add the missing LODs in Object Builder".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeM01MissingGeometryLods {
+    #[must_use]
+    pub fn new(path: String, missing: Vec<String>, severity: Severity) -> Self {
+        Self {
+            path,
+            missing,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Severity},
+};
+
+use crate::{
+    analyze::{ModelData, ModelLintData},
+    resolution,
+};
+
+crate::analyze::lint!(LintM02GeometryZeroMass);
+
+impl Lint<ModelLintData> for LintM02GeometryZeroMass {
+    fn ident(&self) -> &str {
+        "geometry_zero_mass"
+    }
+
+    fn sort(&self) -> u32 {
+        20
+    }
+
+    fn description(&self) -> &str {
+        "Checks for a Geometry LOD without mass"
+    }
+
+    fn documentation(&self) -> &str {
+        r"### Example
+
+**Incorrect**
+
+A Geometry LOD where no mass has been set on the points.
+
+**Correct**
+
+A Geometry LOD with mass set on the points of its components.
+
+### Explanation
+
+The mass of a model is the total mass of the points in its Geometry LOD. A model without mass can not be moved by physics, and vehicles without mass behave unpredictably.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<ModelLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<ModelLintData> for Runner {
+    type Target = ModelData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &ModelData,
+        _data: &ModelLintData,
+    ) -> Codes {
+        let (p3d, path) = target;
+        let Some(lod) = p3d
+            .lods
+            .iter()
+            .find(|lod| resolution::is(lod.resolution, resolution::GEOMETRY))
+        else {
+            return vec![];
+        };
+        if lod.points.is_empty()
+            || lod
+                .mass()
+                .is_some_and(|mass| mass.iter().sum::<f32>() > 0.0)
+        {
+            return vec![];
+        }
+        vec![Arc::new(CodeM02GeometryZeroMass::new(
+            path.as_str().to_string(),
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeM02GeometryZeroMass {
+    path: String,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeM02GeometryZeroMass {
+    fn ident(&self) -> &'static str {
+        "L-M02"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/models.html#geometry_zero_mass")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("model `{}` has no mass in LOD `Geometry`", self.path)
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("set the mass of the points in the Geometry LOD".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeM02GeometryZeroMass {
+    #[must_use]
+    pub fn new(path: String, severity: Severity) -> Self {
+        Self {
+            path,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
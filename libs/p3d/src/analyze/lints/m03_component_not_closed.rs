@@ -0,0 +1,186 @@
+use std::{collections::HashMap, sync::Arc};
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Severity},
+};
+
+use crate::{
+    analyze::{geometry_lods, ModelData, ModelLintData},
+    resolution,
+};
+
+crate::analyze::lint!(LintM03ComponentNotClosed);
+
+impl Lint<ModelLintData> for LintM03ComponentNotClosed {
+    fn ident(&self) -> &str {
+        "component_not_closed"
+    }
+
+    fn sort(&self) -> u32 {
+        30
+    }
+
+    fn description(&self) -> &str {
+        "Checks for components of geometry LODs that are not closed"
+    }
+
+    fn documentation(&self) -> &str {
+        r"### Example
+
+**Incorrect**
+
+A box in the Geometry LOD that is missing its top face.
+
+**Correct**
+
+A box in the Geometry LOD with all six faces.
+
+### Explanation
+
+Every component of the Geometry, Fire Geometry and View Geometry LODs must be a closed shape, where every edge is shared by exactly two faces. Open components cause collisions and hits to be missed.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<ModelLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<ModelLintData> for Runner {
+    type Target = ModelData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &ModelData,
+        _data: &ModelLintData,
+    ) -> Codes {
+        let (p3d, path) = target;
+        let mut codes: Codes = Vec::new();
+        for lod in geometry_lods(p3d) {
+            for (name, component) in lod.components() {
+                let faces = component
+                    .selected_faces()
+                    .filter_map(|(index, _)| lod.faces.get(index).map(|face| (index, face)))
+                    .collect::<Vec<_>>();
+                let mut edges: HashMap<(u32, u32), usize> = HashMap::new();
+                for (_, face) in &faces {
+                    for (a, b) in edges_of(face) {
+                        *edges.entry((a.min(b), a.max(b))).or_default() += 1;
+                    }
+                }
+                let open = edges.values().filter(|count| **count != 2).count();
+                if open == 0 {
+                    continue;
+                }
+                let Some((face, _)) = faces.iter().find(|(_, face)| {
+                    edges_of(face).any(|(a, b)| edges[&(a.min(b), a.max(b))] != 2)
+                }) else {
+                    continue;
+                };
+                codes.push(Arc::new(CodeM03ComponentNotClosed::new(
+                    path.as_str().to_string(),
+                    resolution::name(lod.resolution),
+                    name.to_string(),
+                    *face,
+                    open,
+                    config.severity(),
+                )));
+            }
+        }
+        codes
+    }
+}
+
+/// The edges of a face, as pairs of point indices
+fn edges_of(face: &crate::Face) -> impl Iterator<Item = (u32, u32)> + '_ {
+    let points = face
+        .vertices
+        .iter()
+        .map(|vertex| vertex.point_index)
+        .collect::<Vec<_>>();
+    (0..points.len()).map(move |i| (points[i], points[(i + 1) % points.len()]))
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeM03ComponentNotClosed {
+    path: String,
+    lod: String,
+    component: String,
+    face: usize,
+    open: usize,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeM03ComponentNotClosed {
+    fn ident(&self) -> &'static str {
+        "L-M03"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/models.html#component_not_closed")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` in LOD `{}` of `{}` is not closed at face {}",
+            self.component, self.lod, self.path, self.face
+        )
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(format!(
+            "{} edges are not shared by exactly two faces",
+            self.open
+        ))
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("close the component, or run `Structure > Topology > Find Components` in Object Builder".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeM03ComponentNotClosed {
+    #[must_use]
+    pub fn new(
+        path: String,
+        lod: String,
+        component: String,
+        face: usize,
+        open: usize,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            path,
+            lod,
+            component,
+            face,
+            open,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Severity},
+};
+
+use crate::{
+    analyze::{cross, dot, face_points, geometry_lods, length, sub, ModelData, ModelLintData},
+    resolution,
+};
+
+crate::analyze::lint!(LintM04ComponentNotConvex);
+
+/// How far a point may be on the wrong side of a face, in meters
+const TOLERANCE: f32 = 0.001;
+
+impl Lint<ModelLintData> for LintM04ComponentNotConvex {
+    fn ident(&self) -> &str {
+        "component_not_convex"
+    }
+
+    fn sort(&self) -> u32 {
+        40
+    }
+
+    fn description(&self) -> &str {
+        "Checks for components of geometry LODs that are not convex"
+    }
+
+    fn documentation(&self) -> &str {
+        r"### Example
+
+**Incorrect**
+
+An L shaped component in the Geometry LOD.
+
+**Correct**
+
+Two box shaped components in the Geometry LOD, that together form the L shape.
+
+### Explanation
+
+The engine treats every component of the Geometry, Fire Geometry and View Geometry LODs as a convex shape. A component that is not convex collides as if it was filled in, so it must be split into convex components.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<ModelLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<ModelLintData> for Runner {
+    type Target = ModelData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &ModelData,
+        _data: &ModelLintData,
+    ) -> Codes {
+        let (p3d, path) = target;
+        let mut codes: Codes = Vec::new();
+        for lod in geometry_lods(p3d) {
+            for (name, component) in lod.components() {
+                let points = component
+                    .selected_points()
+                    .filter_map(|(index, _)| lod.points.get(index))
+                    .map(|point| [point.coords.0, point.coords.1, point.coords.2])
+                    .collect::<Vec<_>>();
+                let concave = component.selected_faces().find(|(index, _)| {
+                    let Some(corners) = lod
+                        .faces
+                        .get(*index)
+                        .and_then(|face| face_points(lod, face))
+                    else {
+                        return false;
+                    };
+                    if corners.len() < 3 {
+                        return false;
+                    }
+                    let normal = cross(sub(corners[1], corners[0]), sub(corners[2], corners[0]));
+                    let normal_length = length(normal);
+                    if normal_length <= f32::EPSILON {
+                        return false;
+                    }
+                    let (mut front, mut back) = (false, false);
+                    for point in &points {
+                        let distance = dot(normal, sub(*point, corners[0])) / normal_length;
+                        front |= distance > TOLERANCE;
+                        back |= distance < -TOLERANCE;
+                    }
+                    front && back
+                });
+                if let Some((face, _)) = concave {
+                    codes.push(Arc::new(CodeM04ComponentNotConvex::new(
+                        path.as_str().to_string(),
+                        resolution::name(lod.resolution),
+                        name.to_string(),
+                        face,
+                        config.severity(),
+                    )));
+                }
+            }
+        }
+        codes
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeM04ComponentNotConvex {
+    path: String,
+    lod: String,
+    component: String,
+    face: usize,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeM04ComponentNotConvex {
+    fn ident(&self) -> &'static str {
+        "L-M04"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/models.html#component_not_convex")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` in LOD `{}` of `{}` is not convex at face {}",
+            self.component, self.lod, self.path, self.face
+        )
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("points of the component are on both sides of the face".to_string())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("split the component into convex components, or use `Structure > Convexity > Component Convex Hull` in Object Builder".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeM04ComponentNotConvex {
+    #[must_use]
+    pub fn new(
+        path: String,
+        lod: String,
+        component: String,
+        face: usize,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            path,
+            lod,
+            component,
+            face,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,186 @@
+use std::sync::Arc;
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Severity},
+};
+
+use crate::{
+    analyze::{cross, face_points, length, sub, ModelData, ModelLintData},
+    resolution, Face, LOD,
+};
+
+crate::analyze::lint!(LintM05DegenerateFace);
+
+/// The smallest area of a face, in square meters
+const MIN_AREA: f32 = 1e-8;
+
+impl Lint<ModelLintData> for LintM05DegenerateFace {
+    fn ident(&self) -> &str {
+        "degenerate_face"
+    }
+
+    fn sort(&self) -> u32 {
+        50
+    }
+
+    fn description(&self) -> &str {
+        "Checks for faces with repeated or missing points, or without area"
+    }
+
+    fn documentation(&self) -> &str {
+        r"### Example
+
+**Incorrect**
+
+A triangle where two of the vertices are the same point, or where all three points are on a line.
+
+**Correct**
+
+A triangle with three separate points that are not on a line.
+
+### Explanation
+
+Degenerate faces have no area, so they can not be seen, but they can break normals, shadows and collisions. They are usually left over from merging points or collapsing edges.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<ModelLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<ModelLintData> for Runner {
+    type Target = ModelData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &ModelData,
+        _data: &ModelLintData,
+    ) -> Codes {
+        let (p3d, path) = target;
+        let mut codes: Codes = Vec::new();
+        for lod in &p3d.lods {
+            let degenerate = lod
+                .faces
+                .iter()
+                .enumerate()
+                .filter(|(_, face)| is_degenerate(lod, face))
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+            if let Some(face) = degenerate.first() {
+                codes.push(Arc::new(CodeM05DegenerateFace::new(
+                    path.as_str().to_string(),
+                    resolution::name(lod.resolution),
+                    *face,
+                    degenerate.len(),
+                    config.severity(),
+                )));
+            }
+        }
+        codes
+    }
+}
+
+fn is_degenerate(lod: &LOD, face: &Face) -> bool {
+    let indices = face
+        .vertices
+        .iter()
+        .map(|vertex| vertex.point_index)
+        .collect::<Vec<_>>();
+    if indices
+        .iter()
+        .enumerate()
+        .any(|(i, index)| indices[i + 1..].contains(index))
+    {
+        return true;
+    }
+    let Some(points) = face_points(lod, face) else {
+        return true;
+    };
+    // the area of the triangles of the face, without halving
+    let area = (2..points.len())
+        .map(|i| {
+            length(cross(
+                sub(points[i - 1], points[0]),
+                sub(points[i], points[0]),
+            ))
+        })
+        .sum::<f32>();
+    area <= MIN_AREA * 2.0
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeM05DegenerateFace {
+    path: String,
+    lod: String,
+    face: usize,
+    count: usize,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeM05DegenerateFace {
+    fn ident(&self) -> &'static str {
+        "L-M05"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/models.html#degenerate_face")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "face {} in LOD `{}` of `{}` is degenerate",
+            self.face, self.lod, self.path
+        )
+    }
+
+    fn note(&self) -> Option<String> {
+        if self.count > 1 {
+            Some(format!("{} faces in this LOD are degenerate", self.count))
+        } else {
+            None
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("remove the face, or merge its points in Object Builder".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeM05DegenerateFace {
+    #[must_use]
+    pub fn new(path: String, lod: String, face: usize, count: usize, severity: Severity) -> Self {
+        Self {
+            path,
+            lod,
+            face,
+            count,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
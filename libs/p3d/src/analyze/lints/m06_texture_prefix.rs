@@ -0,0 +1,204 @@
+use std::{collections::HashSet, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Severity},
+};
+
+use crate::{
+    analyze::{ModelData, ModelLintData},
+    resolution,
+};
+
+crate::analyze::lint!(LintM06TexturePrefix);
+
+impl Lint<ModelLintData> for LintM06TexturePrefix {
+    fn ident(&self) -> &str {
+        "texture_prefix"
+    }
+
+    fn sort(&self) -> u32 {
+        60
+    }
+
+    fn description(&self) -> &str {
+        "Checks for texture and material paths that do not match the project prefix"
+    }
+
+    fn documentation(&self) -> &str {
+        r"### Example
+
+With `mainprefix = 'z'` and `prefix = 'abe'`
+
+**Incorrect**
+
+```
+P:\z\abe\addons\main\data\box_co.paa
+abe\addons\main\data\box_co.paa
+```
+
+**Correct**
+
+```
+z\abe\addons\main\data\box_co.paa
+```
+
+### Explanation
+
+Textures and materials are loaded by their path in the game, not from the disk. A path that includes a drive, or that refers to the project without its full prefix, works in Object Builder but will not be found in game.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::error()
+    }
+
+    fn minimum_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<ModelLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<ModelLintData> for Runner {
+    type Target = ModelData;
+    fn run(
+        &self,
+        project: Option<&ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &ModelData,
+        _data: &ModelLintData,
+    ) -> Codes {
+        let (p3d, path) = target;
+        let prefix = project.map(|project| {
+            project.mainprefix().map_or_else(
+                || project.prefix().clone(),
+                |main| format!("{main}\\{}", project.prefix()),
+            )
+        });
+        let mut seen = HashSet::new();
+        let mut codes: Codes = Vec::new();
+        for lod in &p3d.lods {
+            for (index, face) in lod.faces.iter().enumerate() {
+                for texture in [&face.texture, &face.material] {
+                    if seen.contains(texture) {
+                        continue;
+                    }
+                    let Some(problem) = problem(texture, project, prefix.as_deref()) else {
+                        continue;
+                    };
+                    seen.insert(texture.clone());
+                    codes.push(Arc::new(CodeM06TexturePrefix::new(
+                        path.as_str().to_string(),
+                        resolution::name(lod.resolution),
+                        index,
+                        texture.clone(),
+                        problem,
+                        config.severity(),
+                    )));
+                }
+            }
+        }
+        codes
+    }
+}
+
+/// Why the path of a texture or material will not be found in game
+fn problem(texture: &str, project: Option<&ProjectConfig>, prefix: Option<&str>) -> Option<String> {
+    if texture.is_empty() || texture.starts_with('#') {
+        return None;
+    }
+    if texture.as_bytes().get(1) == Some(&b':') {
+        return Some("the path includes a drive".to_string());
+    }
+    let (project, prefix) = (project?, prefix?);
+    let texture = texture.trim_start_matches('\\').to_lowercase();
+    let expected = format!("{}\\", prefix.to_lowercase());
+    let in_project = texture
+        .split('\\')
+        .any(|part| part.eq_ignore_ascii_case(project.prefix()));
+    if in_project && !texture.starts_with(&expected) {
+        return Some(format!("the path does not start with `{prefix}`"));
+    }
+    None
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeM06TexturePrefix {
+    path: String,
+    lod: String,
+    face: usize,
+    texture: String,
+    problem: String,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeM06TexturePrefix {
+    fn ident(&self) -> &'static str {
+        "L-M06"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/models.html#texture_prefix")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` on face {} in LOD `{}` of `{}` will not be found",
+            self.texture, self.face, self.lod, self.path
+        )
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(self.problem.clone())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(
+            "use the path of the file inside the game, starting with the project prefix"
+                .to_string(),
+        )
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeM06TexturePrefix {
+    #[must_use]
+    pub fn new(
+        path: String,
+        lod: String,
+        face: usize,
+        texture: String,
+        problem: String,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            path,
+            lod,
+            face,
+            texture,
+            problem,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,83 @@
+use hemtt_common::config::ProjectConfig;
+use hemtt_workspace::{lint::LintManager, lint_manager, reporting::Codes, WorkspacePath};
+
+use crate::{resolution, Face, LOD, P3D};
+
+pub mod lints {
+    automod::dir!(pub "src/analyze/lints");
+}
+
+lint_manager!(model, vec![], ModelLintData);
+
+pub struct ModelLintData {}
+
+/// A model and the path it was read from
+pub type ModelData = (P3D, WorkspacePath);
+
+/// The LODs that are used for collisions, and must be made of closed convex components
+const GEOMETRY_LODS: [f32; 3] = [
+    resolution::GEOMETRY,
+    resolution::FIRE_GEOMETRY,
+    resolution::VIEW_GEOMETRY,
+];
+
+pub fn lint_one(model: &ModelData, project: Option<&ProjectConfig>) -> Codes {
+    let mut manager = LintManager::new(
+        project.map_or_else(Default::default, |project| project.lints().models().clone()),
+    );
+    if let Err(e) = manager.extend(
+        MODEL_LINTS
+            .iter()
+            .map(|l| (**l).clone())
+            .collect::<Vec<_>>(),
+    ) {
+        return e;
+    }
+    manager.run(&ModelLintData {}, project, None, model)
+}
+
+/// The LODs of the model that are used for collisions
+pub fn geometry_lods(p3d: &P3D) -> impl Iterator<Item = &LOD> {
+    p3d.lods.iter().filter(|lod| {
+        GEOMETRY_LODS
+            .iter()
+            .any(|special| resolution::is(lod.resolution, *special))
+    })
+}
+
+#[must_use]
+/// The coordinates of the points of a face, `None` if a point does not exist
+pub fn face_points(lod: &LOD, face: &Face) -> Option<Vec<[f32; 3]>> {
+    face.vertices
+        .iter()
+        .map(|vertex| {
+            lod.points
+                .get(vertex.point_index as usize)
+                .map(|point| [point.coords.0, point.coords.1, point.coords.2])
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+#[must_use]
+pub fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1].mul_add(b[2], -(a[2] * b[1])),
+        a[2].mul_add(b[0], -(a[0] * b[2])),
+        a[0].mul_add(b[1], -(a[1] * b[0])),
+    ]
+}
+
+#[must_use]
+pub fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0].mul_add(b[0], a[1].mul_add(b[1], a[2] * b[2]))
+}
+
+#[must_use]
+pub fn length(a: [f32; 3]) -> f32 {
+    dot(a, a).sqrt()
+}
@@ -14,6 +14,13 @@ pub enum Error {
     /// Unsupported lod type
     UnsupportedLODType(String),
 
+    #[error("Unsupported odol version: {0}")]
+    /// Unsupported odol version
+    UnsupportedODOLVersion(u32),
+    #[error("Invalid compressed data: {0}")]
+    /// Invalid compressed data in an odol
+    InvalidCompressedData(String),
+
     #[error("Unexpected bytes where `TAGG` expected: {0}")]
     /// Unexpected bytes where `TAGG` expected
     UnexpectedBytesTagg(String),
@@ -3,7 +3,10 @@ use std::io::{Read, Write};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use hemtt_common::io::{ReadExt, WriteExt};
 
-use crate::{Error, Vertex};
+use crate::{lod::read_fixed_string, Error, Vertex};
+
+/// Length of the null padded texture of a face in an `SP3X` LOD
+const LEGACY_TEXTURE_LENGTH: usize = 32;
 
 #[derive(Debug, Default)]
 pub struct Face {
@@ -55,6 +58,36 @@ impl Face {
         })
     }
 
+    /// Reads a Face from an `SP3X` LOD, which has a fixed length texture and no material.
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if an IO error occurs.
+    pub fn read_legacy<I: Read>(input: &mut I) -> Result<Self, Error> {
+        let texture = read_fixed_string(input, LEGACY_TEXTURE_LENGTH)?;
+        let num_verts = input.read_u32::<LittleEndian>()?;
+        if num_verts != 3 && num_verts != 4 {
+            return Err(Error::InvalidFaceVertexCount(num_verts));
+        }
+
+        let mut vertices: Vec<Vertex> = Vec::with_capacity(num_verts as usize);
+        for _i in 0..num_verts {
+            vertices.push(Vertex::read(input)?);
+        }
+
+        if num_verts == 3 {
+            Vertex::read(input)?;
+        }
+
+        let flags = input.read_u32::<LittleEndian>()?;
+
+        Ok(Self {
+            vertices,
+            flags,
+            texture,
+            material: String::new(),
+        })
+    }
+
     /// Writes the Face to a given output stream.
     ///
     /// # Errors
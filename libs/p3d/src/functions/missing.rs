@@ -76,20 +76,26 @@ impl P3D {
             } else if workspace.locate(&texture)?.is_none() {
                 #[allow(clippy::case_sensitive_file_extension_comparisons)]
                 // working on lowercase paths
-                let (replaced, ext) = if texture.ends_with(".paa") {
-                    (texture.replace(".paa", ".tga"), "tga")
+                // a `.paa` may be converted from a `.tga` or `.png` during the build
+                let replacements: &[(&str, &str)] = if texture.ends_with(".paa") {
+                    &[(".paa", ".tga"), (".paa", ".png")]
                 } else if texture.ends_with(".tga") {
-                    (texture.replace(".tga", ".paa"), "paa")
+                    &[(".tga", ".paa")]
                 } else if texture.ends_with(".png") {
-                    (texture.replace(".png", ".paa"), "paa")
+                    &[(".png", ".paa")]
                 } else {
-                    (texture.clone(), "")
+                    &[]
                 };
-                if ext.is_empty() || workspace.locate(&replaced)?.is_none() {
-                    cache.insert(texture.clone(), false);
+                let mut found = false;
+                for (from, to) in replacements {
+                    if workspace.locate(&texture.replace(from, to))?.is_some() {
+                        found = true;
+                        break;
+                    }
+                }
+                cache.insert(texture.clone(), found);
+                if !found {
                     missing_textures.push(texture);
-                } else {
-                    cache.insert(texture.clone(), true);
                 }
             } else {
                 cache.insert(texture.clone(), true);
@@ -1,4 +1,6 @@
 //! HEMTT - Arma 3 P3D Reader
+//!
+//! Reads and writes MLOD models, and reads binarized ODOL models
 
 // Parts of the following code is derivative work of the code from the armake2 project by KoffeinFlummi,
 // which is licensed GPLv2. This code therefore is also licensed under the terms
@@ -11,18 +13,24 @@ use std::io::{BufReader, BufWriter, Read, Seek, Write};
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
+pub mod analyze;
 mod error;
 mod face;
 mod functions;
 mod lod;
+pub mod odol;
 mod point;
+pub mod resolution;
+mod tagg;
 mod vertex;
 
 pub use error::Error;
 pub use face::Face;
 pub use functions::*;
 pub use lod::LOD;
+pub use odol::ODOL;
 pub use point::Point;
+pub use tagg::{Selection, Tagg};
 pub use vertex::Vertex;
 
 #[derive(Debug)]
@@ -58,6 +66,25 @@ impl P3D {
         Ok(Self { version, lods })
     }
 
+    /// Replaces the prefix of the texture and material paths of every face
+    ///
+    /// Prefixes are matched ignoring case and leading backslashes, and only on whole folders.
+    /// Returns the number of paths that were changed.
+    pub fn retexture(&mut self, from: &str, to: &str) -> usize {
+        let from = from.trim_matches('\\');
+        let to = to.trim_matches('\\');
+        let mut changed = 0;
+        for face in self.lods.iter_mut().flat_map(|lod| lod.faces.iter_mut()) {
+            for path in [&mut face.texture, &mut face.material] {
+                if let Some(new) = replace_prefix(path, from, to) {
+                    *path = new;
+                    changed += 1;
+                }
+            }
+        }
+        changed
+    }
+
     /// Writes the P3D to a given output stream.
     ///
     /// # Errors
@@ -77,6 +104,23 @@ impl P3D {
             lod.write(&mut writer)?;
         }
 
+        writer.flush()?;
         Ok(())
     }
 }
+
+/// Replace the prefix of a path, keeping a leading backslash
+fn replace_prefix(path: &str, from: &str, to: &str) -> Option<String> {
+    let (leading, rest) = path
+        .strip_prefix('\\')
+        .map_or(("", path), |rest| ("\\", rest));
+    let start = rest.get(..from.len())?;
+    let after = &rest[from.len()..];
+    if from.is_empty()
+        || !start.eq_ignore_ascii_case(from)
+        || !(after.is_empty() || after.starts_with('\\'))
+    {
+        return None;
+    }
+    Some(format!("{leading}{to}{after}"))
+}
@@ -2,9 +2,11 @@ use std::io::{Read, Seek, Write};
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use hemtt_common::io::{ReadExt, WriteExt};
-use indexmap::IndexMap;
 
-use crate::{Error, Face, Point};
+use crate::{Error, Face, Point, Selection, Tagg};
+
+/// Length of the null padded name of a tagg in an `SP3X` LOD
+const LEGACY_TAGG_NAME_LENGTH: usize = 64;
 
 #[derive(Debug)]
 pub struct LOD {
@@ -14,25 +16,109 @@ pub struct LOD {
     pub points: Vec<Point>,
     pub face_normals: Vec<(f32, f32, f32)>,
     pub faces: Vec<Face>,
-    pub taggs: IndexMap<String, Box<[u8]>>,
+    pub taggs: Vec<Tagg>,
 }
 
 impl LOD {
+    /// The named selections of the LOD
+    pub fn selections(&self) -> impl Iterator<Item = (&str, &Selection)> {
+        self.taggs.iter().filter_map(|tagg| match tagg {
+            Tagg::Selection { name, selection } => Some((name.as_str(), selection)),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// Find a named selection, ignoring case
+    pub fn selection(&self, name: &str) -> Option<&Selection> {
+        self.selections()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, s)| s)
+    }
+
+    /// The components of a geometry LOD, the selections named `ComponentXX`
+    pub fn components(&self) -> impl Iterator<Item = (&str, &Selection)> {
+        self.selections().filter(|(name, _)| {
+            name.get(..9)
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case("component"))
+        })
+    }
+
+    /// The named properties of the LOD
+    pub fn properties(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.taggs.iter().filter_map(|tagg| match tagg {
+            Tagg::Property { name, value } => Some((name.as_str(), value.as_str())),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// Find the value of a named property, ignoring case
+    pub fn property(&self, name: &str) -> Option<&str> {
+        self.properties()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v)
+    }
+
+    #[must_use]
+    /// The mass of each point
+    pub fn mass(&self) -> Option<&[f32]> {
+        self.taggs.iter().find_map(|tagg| match tagg {
+            Tagg::Mass(mass) => Some(mass.as_slice()),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// The pairs of point indices that form sharp edges
+    pub fn sharp_edges(&self) -> Option<&[(u32, u32)]> {
+        self.taggs.iter().find_map(|tagg| match tagg {
+            Tagg::SharpEdges(edges) => Some(edges.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// The UV sets of the LOD, by stage
+    pub fn uv_sets(&self) -> impl Iterator<Item = (u32, &[(f32, f32)])> {
+        self.taggs.iter().filter_map(|tagg| match tagg {
+            Tagg::UvSet { stage, uvs } => Some((*stage, uvs.as_slice())),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// The points and faces that are locked
+    pub fn lock(&self) -> Option<&Selection> {
+        self.taggs.iter().find_map(|tagg| match tagg {
+            Tagg::Lock(lock) => Some(lock),
+            _ => None,
+        })
+    }
+
     /// Reads a LOD from a given input stream.
     ///
+    /// Legacy `SP3X` LODs are read into the same model as `P3DM` LODs,
+    /// and are written as `P3DM`
+    ///
     /// # Errors
     /// [`std::io::Error`] if an IO error occurs.
     pub fn read<I: Read + Seek>(input: &mut I) -> Result<Self, Error> {
         let mut buffer = [0; 4];
         input.read_exact(&mut buffer)?;
-        if &buffer != b"P3DM" {
-            return Err(Error::UnsupportedLODType(
-                String::from_utf8_lossy(&buffer).to_string(),
-            ));
-        }
+        let legacy = match &buffer {
+            b"P3DM" => false,
+            b"SP3X" => true,
+            _ => {
+                return Err(Error::UnsupportedLODType(
+                    String::from_utf8_lossy(&buffer).to_string(),
+                ));
+            }
+        };
 
         let version_major = input.read_u32::<LittleEndian>()?;
         let version_minor = input.read_u32::<LittleEndian>()?;
+        // the version of the P3DM layout the LOD is converted to
+        let version_minor = if legacy { 0x100 } else { version_minor };
 
         let num_points = input.read_u32::<LittleEndian>()?;
         let num_face_normals = input.read_u32::<LittleEndian>()?;
@@ -57,7 +143,11 @@ impl LOD {
         }
 
         for _i in 0..num_faces {
-            faces.push(Face::read(input)?);
+            faces.push(if legacy {
+                Face::read_legacy(input)?
+            } else {
+                Face::read(input)?
+            });
         }
 
         input.read_exact(&mut buffer)?;
@@ -67,21 +157,29 @@ impl LOD {
             ));
         }
 
-        let mut taggs: IndexMap<String, Box<[u8]>> = IndexMap::new();
+        let mut taggs: Vec<Tagg> = Vec::new();
 
         loop {
-            input.bytes().next();
-
-            let name = input.read_cstring()?;
+            let name = if legacy {
+                read_fixed_string(input, LEGACY_TAGG_NAME_LENGTH)?
+            } else {
+                input.bytes().next();
+                input.read_cstring()?
+            };
             let size = input.read_u32::<LittleEndian>()?;
-            let mut buffer = vec![0; size as usize].into_boxed_slice();
+            let mut buffer = vec![0; size as usize];
             input.read_exact(&mut buffer)?;
 
             if name == "#EndOfFile#" {
                 break;
             }
 
-            taggs.insert(name, buffer);
+            taggs.push(Tagg::from_raw(
+                name,
+                buffer,
+                num_points as usize,
+                num_faces as usize,
+            ));
         }
 
         let resolution = input.read_f32::<LittleEndian>()?;
@@ -133,13 +231,8 @@ impl LOD {
 
         output.write_all(b"TAGG")?;
 
-        for (name, buffer) in &self.taggs {
-            let buffer_len = u32::try_from(buffer.len())
-                .map_err(|_| Error::ExceededTaggLength(buffer.len() as u64))?;
-            output.write_all(&[1])?;
-            output.write_cstring(name)?;
-            output.write_u32::<LittleEndian>(buffer_len)?;
-            output.write_all(buffer)?;
+        for tagg in &self.taggs {
+            tagg.write(output)?;
         }
 
         output.write_cstring("\x01#EndOfFile#")?;
@@ -150,3 +243,11 @@ impl LOD {
         Ok(())
     }
 }
+
+/// Read a null padded string of a fixed length, used by `SP3X` LODs
+pub fn read_fixed_string<I: Read>(input: &mut I, length: usize) -> Result<String, Error> {
+    let mut buffer = vec![0; length];
+    input.read_exact(&mut buffer)?;
+    let end = buffer.iter().position(|b| *b == 0).unwrap_or(length);
+    Ok(String::from_utf8_lossy(&buffer[..end]).to_string())
+}
@@ -0,0 +1,365 @@
+//! Read only support for binarized (ODOL) models
+//!
+//! Only the parts of the model that are useful for inspection are kept,
+//! the geometry and render data of each LOD is skipped
+//!
+//! The reader has only been tested against synthetic models, it is not used by
+//! `hemtt utils inspect` until it is checked against models from binarize
+
+use std::io::Read;
+
+use crate::Error;
+
+use self::reader::Reader;
+
+mod reader;
+
+/// The oldest version that can be read, used by Arma 3
+pub const MIN_VERSION: u32 = 68;
+/// The newest version that can be read
+pub const MAX_VERSION: u32 = 75;
+
+/// Animation types that have no axis in the animations to bones data
+const ANIM_DIRECT: u32 = 8;
+const ANIM_HIDE: u32 = 9;
+
+#[derive(Debug)]
+pub struct ODOL {
+    pub version: u32,
+    /// The prefix of the model, from `$PBOPREFIX$`
+    pub prefix: String,
+    pub skeleton: Option<Skeleton>,
+    /// The names of the animation classes, from `model.cfg`
+    pub animations: Vec<String>,
+    /// `class` named property of the geometry LOD
+    pub property_class: String,
+    /// `damage` named property of the geometry LOD
+    pub property_damage: String,
+    pub lods: Vec<Lod>,
+}
+
+#[derive(Debug, Default)]
+/// The skeleton of the model, from `model.cfg`
+pub struct Skeleton {
+    pub name: String,
+    pub inherited: bool,
+    /// Each bone and its parent, the parent is empty for root bones
+    pub bones: Vec<(String, String)>,
+}
+
+#[derive(Debug, Default)]
+pub struct Lod {
+    pub resolution: f32,
+    /// Models of the proxies in the LOD
+    pub proxies: Vec<String>,
+    pub textures: Vec<String>,
+    pub materials: Vec<String>,
+    pub selections: Vec<String>,
+    pub properties: Vec<(String, String)>,
+    pub faces: usize,
+}
+
+impl ODOL {
+    /// Reads an ODOL from a given input stream.
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if an IO error occurs.
+    /// [`Error::UnsupportedP3DType`] if the input is not an ODOL.
+    /// [`Error::UnsupportedODOLVersion`] if the version can not be read.
+    /// [`Error::InvalidCompressedData`] if a compressed array is invalid.
+    pub fn read<I: Read>(input: &mut I) -> Result<Self, Error> {
+        let mut data = Vec::new();
+        input.read_to_end(&mut data)?;
+        let mut reader = Reader::new(&data);
+
+        let signature = reader.bytes(4)?;
+        if signature != b"ODOL" {
+            return Err(Error::UnsupportedP3DType(
+                String::from_utf8_lossy(signature).to_string(),
+            ));
+        }
+        let version = reader.u32()?;
+        if !(MIN_VERSION..=MAX_VERSION).contains(&version) {
+            return Err(Error::UnsupportedODOLVersion(version));
+        }
+        reader.set_version(version);
+
+        reader.skip(4)?; // app id
+        let prefix = reader.asciiz()?;
+        let num_lods = reader.count()?;
+        let mut resolutions = Vec::new();
+        for _ in 0..num_lods {
+            resolutions.push(reader.f32()?);
+        }
+
+        let info = ModelInfo::read(&mut reader, num_lods)?;
+
+        let animations = if reader.bool()? {
+            read_animations(&mut reader)?
+        } else {
+            Vec::new()
+        };
+
+        let mut starts = Vec::new();
+        for _ in 0..num_lods {
+            starts.push(reader.u32()? as usize);
+        }
+
+        let mut lods = Vec::new();
+        for (resolution, start) in resolutions.into_iter().zip(starts) {
+            reader.seek(start)?;
+            let mut lod = Lod::read(&mut reader)?;
+            lod.resolution = resolution;
+            lods.push(lod);
+        }
+
+        Ok(Self {
+            version,
+            prefix,
+            skeleton: info.skeleton,
+            animations,
+            property_class: info.property_class,
+            property_damage: info.property_damage,
+            lods,
+        })
+    }
+}
+
+/// The parts of the model info that are kept
+struct ModelInfo {
+    skeleton: Option<Skeleton>,
+    property_class: String,
+    property_damage: String,
+}
+
+impl ModelInfo {
+    fn read(reader: &mut Reader, num_lods: usize) -> Result<Self, Error> {
+        // special flags, lod sphere, geometry sphere, remarks, and hints, or hints
+        reader.skip(4 * 6)?;
+        reader.skip(12)?; // aiming center
+        reader.skip(4 * 2)?; // map icon color, map selected color
+        reader.skip(4)?; // view density
+        reader.skip(12 * 2)?; // bounding box
+        if reader.version() >= 70 {
+            reader.skip(4)?; // lod density coefficient
+        }
+        if reader.version() >= 71 {
+            reader.skip(4)?; // draw importance
+        }
+        reader.skip(12 * 2)?; // visual bounding box
+        reader.skip(12 * 3)?; // bounding center, geometry center, center of mass
+        reader.skip(12 * 3)?; // inverse inertia
+        reader.skip(4)?; // auto center, lock auto center, can occlude, can be occluded
+        if reader.version() >= 73 {
+            reader.skip(1)?; // ai covers
+        }
+        reader.skip(4 * 6)?; // armor and thermal values
+        reader.skip(1)?; // force not alpha
+        reader.skip(4 + 1)?; // shadow buffer source, prefer shadow volume
+        reader.skip(4)?; // shadow offset
+        reader.skip(1)?; // animated
+        let skeleton = read_skeleton(reader)?;
+        reader.skip(1)?; // map type
+        reader.compressed_array(4)?; // mass of each point
+        reader.skip(4 * 4)?; // mass, inverse mass, armor, inverse armor
+        if reader.version() >= 72 {
+            reader.skip(4)?; // explosion shielding
+        }
+        // indices of the special lods, geometry simple and physx, memory, geometry,
+        // fire geometry, view geometry, view pilot, view gunner, view commander,
+        // view cargo, land contact, roadway, paths, hitpoints
+        reader.skip(14)?;
+        reader.skip(4)?; // min shadow
+        reader.skip(1)?; // can blend
+        let property_class = reader.asciiz()?;
+        let property_damage = reader.asciiz()?;
+        reader.skip(1)?; // property frequent
+        reader.skip(4)?;
+        // preferred shadow volume, shadow buffer and visible shadow buffer lods
+        reader.skip(num_lods * 4 * 3)?;
+        Ok(Self {
+            skeleton,
+            property_class,
+            property_damage,
+        })
+    }
+}
+
+fn read_skeleton(reader: &mut Reader) -> Result<Option<Skeleton>, Error> {
+    let name = reader.asciiz()?;
+    if name.is_empty() {
+        return Ok(None);
+    }
+    let inherited = reader.bool()?;
+    let num_bones = reader.count()?;
+    let mut bones = Vec::new();
+    for _ in 0..num_bones {
+        bones.push((reader.asciiz()?, reader.asciiz()?));
+    }
+    reader.asciiz()?; // pivots model, no longer used
+    Ok(Some(Skeleton {
+        name,
+        inherited,
+        bones,
+    }))
+}
+
+/// Read the animations, returning the names of the animation classes
+fn read_animations(reader: &mut Reader) -> Result<Vec<String>, Error> {
+    let num_classes = reader.count()?;
+    let mut names = Vec::new();
+    let mut types = Vec::new();
+    for _ in 0..num_classes {
+        let kind = reader.u32()?;
+        names.push(reader.asciiz()?);
+        reader.asciiz()?; // source
+        reader.skip(4 * 7)?; // min and max phase and value, period, initial phase, source address
+        match kind {
+            // rotations, translations, and hide
+            0..=7 | ANIM_HIDE => reader.skip(4 * 2)?,
+            ANIM_DIRECT => reader.skip(12 * 2 + 4 * 2)?,
+            _ => {}
+        }
+        types.push(kind);
+    }
+    let num_lods = reader.count()?;
+    // the animations of each bone
+    for _ in 0..num_lods {
+        for _ in 0..reader.count()? {
+            let num_anims = reader.count()?;
+            reader.skip(num_anims * 4)?;
+        }
+    }
+    // the bone of each animation, and its axis
+    for _ in 0..num_lods {
+        for kind in &types {
+            let bone = reader.i32()?;
+            if bone != -1 && *kind != ANIM_DIRECT && *kind != ANIM_HIDE {
+                reader.skip(12 * 2)?;
+            }
+        }
+    }
+    Ok(names)
+}
+
+impl Lod {
+    fn read(reader: &mut Reader) -> Result<Self, Error> {
+        let mut lod = Self::default();
+        for _ in 0..reader.count()? {
+            lod.proxies.push(reader.asciiz()?);
+            reader.skip(4 * 12)?; // transform
+            reader.skip(4 * 4)?; // sequence id, named selection, bone and section indices
+        }
+        // sub skeleton to skeleton
+        let count = reader.count()?;
+        reader.skip(count * 4)?;
+        // skeleton to sub skeleton
+        for _ in 0..reader.count()? {
+            let count = reader.count()?;
+            reader.skip(count * 4)?;
+        }
+        reader.skip(4)?; // vertex count
+        reader.skip(4)?; // face area
+        reader.skip(4 * 2)?; // or hints, and hints
+        reader.skip(12 * 3 + 4)?; // bounding box, center and radius
+        for _ in 0..reader.count()? {
+            lod.textures.push(reader.asciiz()?);
+        }
+        for _ in 0..reader.count()? {
+            lod.materials.push(read_material(reader)?);
+        }
+        let index_size = reader.vertex_index_size();
+        reader.compressed_array(index_size)?; // point to vertex
+        reader.compressed_array(index_size)?; // vertex to point
+        lod.faces = reader.count()?;
+        reader.skip(4 + 2)?; // allocated size
+        for _ in 0..lod.faces {
+            let vertices = reader.u8()? as usize;
+            reader.skip(vertices * index_size)?;
+        }
+        for _ in 0..reader.count()? {
+            read_section(reader)?;
+        }
+        for _ in 0..reader.count()? {
+            lod.selections.push(read_selection(reader)?);
+        }
+        for _ in 0..reader.count()? {
+            lod.properties.push((reader.asciiz()?, reader.asciiz()?));
+        }
+        Ok(lod)
+    }
+}
+
+/// Read a material embedded in a LOD, returning its path
+fn read_material(reader: &mut Reader) -> Result<String, Error> {
+    let name = reader.asciiz()?;
+    let version = reader.u32()?;
+    // emissive, ambient, diffuse, forced diffuse, specular, specular 2
+    reader.skip(16 * 6)?;
+    // specular power, pixel shader, vertex shader, main light, fog mode
+    reader.skip(4 * 5)?;
+    if version == 3 {
+        reader.skip(1)?;
+    }
+    if version >= 6 {
+        reader.asciiz()?; // surface
+    }
+    if version >= 4 {
+        reader.skip(4 * 2)?; // render flags
+    }
+    let stages = if version > 6 { reader.count()? } else { 0 };
+    let tex_gens = if version > 8 { reader.count()? } else { 0 };
+    for _ in 0..stages {
+        read_stage_texture(reader, version)?;
+    }
+    // uv source and transform
+    reader.skip(tex_gens * (4 + 4 * 12))?;
+    if version >= 10 {
+        read_stage_texture(reader, version)?; // ti
+    }
+    Ok(name)
+}
+
+fn read_stage_texture(reader: &mut Reader, version: u32) -> Result<(), Error> {
+    if version >= 5 {
+        reader.skip(4)?; // filter
+    }
+    reader.asciiz()?;
+    if version >= 8 {
+        reader.skip(4)?; // stage
+    }
+    if version >= 11 {
+        reader.skip(1)?; // use world environment map
+    }
+    Ok(())
+}
+
+fn read_section(reader: &mut Reader) -> Result<(), Error> {
+    // face range, min bone index, bone count, common point user value
+    reader.skip(4 * 5)?;
+    reader.skip(2)?; // texture index
+    reader.skip(4)?; // face flags
+    if reader.i32()? == -1 {
+        reader.asciiz()?; // material
+    }
+    let stages = reader.count()?;
+    reader.skip(stages * 4)?; // area over texture
+    if reader.i32()? >= 1 {
+        reader.skip(4 * 11)?;
+    }
+    Ok(())
+}
+
+/// Read a named selection, returning its name
+fn read_selection(reader: &mut Reader) -> Result<String, Error> {
+    let name = reader.asciiz()?;
+    let index_size = reader.vertex_index_size();
+    reader.compressed_array(index_size)?; // faces
+    reader.skip(4)?;
+    reader.skip(1)?; // sectional
+    reader.compressed_array(4)?; // sections
+    reader.compressed_array(index_size)?; // vertices
+    let weights = reader.count()?;
+    reader.compressed(weights)?;
+    Ok(name)
+}
@@ -0,0 +1,126 @@
+use std::io::{Error as IoError, ErrorKind};
+
+use crate::Error;
+
+/// Reads the values of an ODOL from memory
+///
+/// Compressed arrays are followed by more data, so the whole file is kept in memory
+/// to know how much of it each array used
+pub struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    version: u32,
+}
+
+impl<'a> Reader<'a> {
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            version: 0,
+        }
+    }
+
+    pub const fn set_version(&mut self, version: u32) {
+        self.version = version;
+    }
+
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn seek(&mut self, pos: usize) -> Result<(), Error> {
+        if pos > self.data.len() {
+            return Err(eof());
+        }
+        self.pos = pos;
+        Ok(())
+    }
+
+    pub fn bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let bytes = self.data.get(self.pos..self.pos + len).ok_or_else(eof)?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    pub fn skip(&mut self, len: usize) -> Result<(), Error> {
+        self.bytes(len).map(|_| ())
+    }
+
+    pub fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub fn bool(&mut self) -> Result<bool, Error> {
+        Ok(self.u8()? != 0)
+    }
+
+    pub fn u32(&mut self) -> Result<u32, Error> {
+        let b = self.bytes(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    pub fn i32(&mut self) -> Result<i32, Error> {
+        let b = self.bytes(4)?;
+        Ok(i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    pub fn f32(&mut self) -> Result<f32, Error> {
+        let b = self.bytes(4)?;
+        Ok(f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    /// A count, followed by that many items
+    pub fn count(&mut self) -> Result<usize, Error> {
+        Ok(self.u32()? as usize)
+    }
+
+    pub fn asciiz(&mut self) -> Result<String, Error> {
+        let rest = self.data.get(self.pos..).ok_or_else(eof)?;
+        let end = rest.iter().position(|b| *b == 0).ok_or_else(eof)?;
+        self.pos += end + 1;
+        Ok(String::from_utf8_lossy(&rest[..end]).to_string())
+    }
+
+    /// The size of a vertex index, which became 32 bits in version 69
+    pub const fn vertex_index_size(&self) -> usize {
+        if self.version >= 69 {
+            4
+        } else {
+            2
+        }
+    }
+
+    /// Data of a known size, that may be LZO compressed
+    pub fn compressed(&mut self, size: usize) -> Result<Vec<u8>, Error> {
+        if size == 0 {
+            return Ok(Vec::new());
+        }
+        let compressed = if self.version >= 64 {
+            self.bool()?
+        } else {
+            size >= 1024
+        };
+        if !compressed {
+            return Ok(self.bytes(size)?.to_vec());
+        }
+        let mut output = vec![0; size];
+        let used = hemtt_lzo::decompress_stream(&self.data[self.pos..], &mut output)
+            .map_err(|e| Error::InvalidCompressedData(e.to_string()))?;
+        self.pos += used;
+        Ok(output)
+    }
+
+    /// A count, followed by that many items of `size` bytes, that may be LZO compressed
+    pub fn compressed_array(&mut self, size: usize) -> Result<Vec<u8>, Error> {
+        let count = self.count()?;
+        self.compressed(count * size)
+    }
+}
+
+fn eof() -> Error {
+    Error::Io(IoError::new(
+        ErrorKind::UnexpectedEof,
+        "unexpected end of odol data",
+    ))
+}
@@ -0,0 +1,71 @@
+//! Resolutions of the special LODs
+
+pub const GEOMETRY: f32 = 1e13;
+pub const GEOMETRY_BUOYANCY: f32 = 2e13;
+pub const GEOMETRY_PHYSX: f32 = 4e13;
+pub const MEMORY: f32 = 1e15;
+pub const LAND_CONTACT: f32 = 2e15;
+pub const ROADWAY: f32 = 3e15;
+pub const PATHS: f32 = 4e15;
+pub const HITPOINTS: f32 = 5e15;
+pub const VIEW_GEOMETRY: f32 = 6e15;
+pub const FIRE_GEOMETRY: f32 = 7e15;
+
+/// Names of the special LODs
+const SPECIAL: [(f32, &str); 29] = [
+    (1000.0, "View Gunner"),
+    (1100.0, "View Pilot"),
+    (1200.0, "View Cargo"),
+    (GEOMETRY, "Geometry"),
+    (GEOMETRY_BUOYANCY, "Geometry Buoyancy"),
+    (3e13, "Geometry PhysX (Old)"),
+    (GEOMETRY_PHYSX, "Geometry PhysX"),
+    (MEMORY, "Memory"),
+    (LAND_CONTACT, "Land Contact"),
+    (ROADWAY, "Roadway"),
+    (PATHS, "Paths"),
+    (HITPOINTS, "Hit-points"),
+    (VIEW_GEOMETRY, "View Geometry"),
+    (FIRE_GEOMETRY, "Fire Geometry"),
+    (8e15, "View Cargo Geometry"),
+    (9e15, "View Cargo Fire Geometry"),
+    (1e16, "View Commander"),
+    (1.1e16, "View Commander Geometry"),
+    (1.2e16, "View Commander Fire Geometry"),
+    (1.3e16, "View Pilot"),
+    (1.4e16, "View Pilot Geometry"),
+    (1.5e16, "View Pilot Fire Geometry"),
+    (1.6e16, "View Gunner"),
+    (1.7e16, "View Gunner Geometry"),
+    (1.8e16, "View Gunner Fire Geometry"),
+    (1.9e16, "Sub Parts"),
+    (2e16, "Shadow Volume - View Cargo"),
+    (2.1e16, "Shadow Volume - View Pilot"),
+    (2.2e16, "Shadow Volume - View Gunner"),
+];
+
+#[must_use]
+/// Is the resolution the given special LOD
+pub fn is(resolution: f32, special: f32) -> bool {
+    (resolution - special).abs() <= special * 1e-6
+}
+
+#[must_use]
+/// A readable name for a LOD resolution, as shown in Object Builder
+pub fn name(resolution: f32) -> String {
+    if let Some((_, name)) = SPECIAL.iter().find(|(value, _)| is(resolution, *value)) {
+        return (*name).to_string();
+    }
+    if (2.3e16..2.4e16).contains(&resolution) {
+        return "Wreck".to_string();
+    }
+    for (start, end, name) in [
+        (10000.0, 20000.0, "Shadow Volume"),
+        (20000.0, 30000.0, "Edit"),
+    ] {
+        if (start..end).contains(&resolution) {
+            return format!("{name} {}", resolution - start);
+        }
+    }
+    resolution.to_string()
+}
@@ -0,0 +1,239 @@
+use std::io::Write;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use hemtt_common::io::WriteExt;
+
+use crate::Error;
+
+/// Length of the name and value of a `#Property#` tagg
+const PROPERTY_LENGTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+/// A tagg at the end of a LOD
+pub enum Tagg {
+    /// A named selection, any tagg that does not start with `#`
+    Selection { name: String, selection: Selection },
+    /// `#Mass#`, the mass of each point
+    Mass(Vec<f32>),
+    /// `#Property#`, a named property of the LOD
+    Property { name: String, value: String },
+    /// `#SharpEdges#`, pairs of point indices
+    SharpEdges(Vec<(u32, u32)>),
+    /// `#UVSet#`, an additional set of coordinates for every vertex of every face
+    UvSet { stage: u32, uvs: Vec<(f32, f32)> },
+    /// `#Lock#`, the points and faces that are locked in Object Builder
+    Lock(Selection),
+    /// Any other tagg, or a tagg that could not be parsed
+    Unknown { name: String, data: Vec<u8> },
+}
+
+impl Tagg {
+    #[must_use]
+    /// Parse a tagg from its name and data
+    ///
+    /// Taggs that can not be written back to the same bytes are kept as [`Tagg::Unknown`]
+    pub fn from_raw(name: String, data: Vec<u8>, points: usize, faces: usize) -> Self {
+        let tagg = match name.as_str() {
+            "#Mass#" if data.len() == points * 4 => Some(Self::Mass(
+                data.chunks_exact(4)
+                    .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+                    .collect(),
+            )),
+            "#Property#" if data.len() == PROPERTY_LENGTH * 2 => Some(Self::Property {
+                name: padded(&data[..PROPERTY_LENGTH]),
+                value: padded(&data[PROPERTY_LENGTH..]),
+            }),
+            "#SharpEdges#" if data.len().is_multiple_of(8) => Some(Self::SharpEdges(
+                data.chunks_exact(8)
+                    .map(|c| {
+                        (
+                            u32::from_le_bytes([c[0], c[1], c[2], c[3]]),
+                            u32::from_le_bytes([c[4], c[5], c[6], c[7]]),
+                        )
+                    })
+                    .collect(),
+            )),
+            "#UVSet#" if data.len() >= 4 && (data.len() - 4).is_multiple_of(8) => {
+                Some(Self::UvSet {
+                    stage: u32::from_le_bytes([data[0], data[1], data[2], data[3]]),
+                    uvs: data[4..]
+                        .chunks_exact(8)
+                        .map(|c| {
+                            (
+                                f32::from_le_bytes([c[0], c[1], c[2], c[3]]),
+                                f32::from_le_bytes([c[4], c[5], c[6], c[7]]),
+                            )
+                        })
+                        .collect(),
+                })
+            }
+            "#Lock#" if data.len() == points + faces => Some(Self::Lock(Selection::new(
+                data[..points].to_vec(),
+                data[points..].to_vec(),
+            ))),
+            _ if !name.starts_with('#') && data.len() == points + faces => Some(Self::Selection {
+                name: name.clone(),
+                selection: Selection::new(data[..points].to_vec(), data[points..].to_vec()),
+            }),
+            _ => None,
+        };
+        match tagg {
+            Some(tagg) if tagg.data() == data => tagg,
+            _ => Self::Unknown { name, data },
+        }
+    }
+
+    #[must_use]
+    /// The name of the tagg
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Selection { name, .. } | Self::Unknown { name, .. } => name,
+            Self::Mass(_) => "#Mass#",
+            Self::Property { .. } => "#Property#",
+            Self::SharpEdges(_) => "#SharpEdges#",
+            Self::UvSet { .. } => "#UVSet#",
+            Self::Lock(_) => "#Lock#",
+        }
+    }
+
+    #[must_use]
+    /// The data of the tagg, as it is written in the file
+    pub fn data(&self) -> Vec<u8> {
+        match self {
+            Self::Selection { selection, .. } | Self::Lock(selection) => {
+                let mut data = selection.points.clone();
+                data.extend(&selection.faces);
+                data
+            }
+            Self::Mass(masses) => masses.iter().flat_map(|m| m.to_le_bytes()).collect(),
+            Self::Property { name, value } => {
+                let mut data = vec![0; PROPERTY_LENGTH * 2];
+                let name = &name.as_bytes()[..name.len().min(PROPERTY_LENGTH - 1)];
+                let value = &value.as_bytes()[..value.len().min(PROPERTY_LENGTH - 1)];
+                data[..name.len()].copy_from_slice(name);
+                data[PROPERTY_LENGTH..PROPERTY_LENGTH + value.len()].copy_from_slice(value);
+                data
+            }
+            Self::SharpEdges(edges) => edges
+                .iter()
+                .flat_map(|(a, b)| [a.to_le_bytes(), b.to_le_bytes()])
+                .flatten()
+                .collect(),
+            Self::UvSet { stage, uvs } => stage
+                .to_le_bytes()
+                .into_iter()
+                .chain(
+                    uvs.iter()
+                        .flat_map(|(u, v)| [u.to_le_bytes(), v.to_le_bytes()])
+                        .flatten(),
+                )
+                .collect(),
+            Self::Unknown { data, .. } => data.clone(),
+        }
+    }
+
+    /// Writes the tagg to a given output stream.
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if an IO error occurs.
+    /// [`Error::ExceededTaggLength`] if the data is too long.
+    pub fn write<O: Write>(&self, output: &mut O) -> Result<(), Error> {
+        let data = self.data();
+        let data_len =
+            u32::try_from(data.len()).map_err(|_| Error::ExceededTaggLength(data.len() as u64))?;
+        output.write_all(&[1])?;
+        output.write_cstring(self.name())?;
+        output.write_u32::<LittleEndian>(data_len)?;
+        output.write_all(&data)?;
+        Ok(())
+    }
+}
+
+/// A null padded string
+fn padded(data: &[u8]) -> String {
+    let end = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+    String::from_utf8_lossy(&data[..end]).to_string()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// The weight of every point and face in a selection
+///
+/// Weights are stored as a byte, `0` is not selected, `1` is fully selected,
+/// and any other value is a weight of `(256 - value) / 255`
+pub struct Selection {
+    pub points: Vec<u8>,
+    pub faces: Vec<u8>,
+}
+
+impl Selection {
+    #[must_use]
+    pub const fn new(points: Vec<u8>, faces: Vec<u8>) -> Self {
+        Self { points, faces }
+    }
+
+    #[must_use]
+    /// The weight of a point, 0.0 if it is not selected
+    pub fn point_weight(&self, index: usize) -> f32 {
+        self.points.get(index).copied().map_or(0.0, weight)
+    }
+
+    #[must_use]
+    /// The weight of a face, 0.0 if it is not selected
+    pub fn face_weight(&self, index: usize) -> f32 {
+        self.faces.get(index).copied().map_or(0.0, weight)
+    }
+
+    /// Set the weight of a point, clamped to 0.0..=1.0
+    pub fn set_point_weight(&mut self, index: usize, value: f32) {
+        if self.points.len() <= index {
+            self.points.resize(index + 1, 0);
+        }
+        self.points[index] = byte(value);
+    }
+
+    /// Set the weight of a face, clamped to 0.0..=1.0
+    pub fn set_face_weight(&mut self, index: usize, value: f32) {
+        if self.faces.len() <= index {
+            self.faces.resize(index + 1, 0);
+        }
+        self.faces[index] = byte(value);
+    }
+
+    /// The indices and weights of the selected points
+    pub fn selected_points(&self) -> impl Iterator<Item = (usize, f32)> + '_ {
+        selected(&self.points)
+    }
+
+    /// The indices and weights of the selected faces
+    pub fn selected_faces(&self) -> impl Iterator<Item = (usize, f32)> + '_ {
+        selected(&self.faces)
+    }
+}
+
+fn selected(weights: &[u8]) -> impl Iterator<Item = (usize, f32)> + '_ {
+    weights
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| **b != 0)
+        .map(|(i, b)| (i, weight(*b)))
+}
+
+fn weight(byte: u8) -> f32 {
+    match byte {
+        0 => 0.0,
+        1 => 1.0,
+        _ => f32::from(256 - u16::from(byte)) / 255.0,
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn byte(weight: f32) -> u8 {
+    if weight <= 0.0 {
+        0
+    } else if weight >= 1.0 {
+        1
+    } else {
+        // 2..=255, so a small weight is never stored as unselected
+        (256 - (weight * 255.0).round() as u16).clamp(2, 255) as u8
+    }
+}
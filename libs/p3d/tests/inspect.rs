@@ -18,11 +18,58 @@ fn ace_gunbag() {
 
 #[test]
 fn kat_iv() {
+    let p3d = P3D::read(&mut std::fs::File::open("tests/kat_iv.p3d").unwrap()).unwrap();
+    assert_eq!(p3d.lods.len(), 1);
+    let lod = &p3d.lods[0];
+    assert_eq!(lod.points.len(), 328);
+    assert_eq!(lod.faces.len(), 592);
+    assert!(lod.faces.iter().all(|f| f.texture.is_empty()));
     assert_eq!(
-        format!(
-            "{:?}",
-            P3D::read(&mut std::fs::File::open("tests/kat_iv.p3d").unwrap())
-        ),
-        "Err(UnsupportedLODType(\"SP3X\"))"
+        lod.selections().map(|(name, _)| name).collect::<Vec<_>>(),
+        vec!["0"]
     );
+    assert_eq!(lod.uv_sets().count(), 1);
+
+    // SP3X LODs are written as P3DM
+    let mut buffer = Vec::new();
+    p3d.write(&mut buffer).unwrap();
+    assert_eq!(&buffer[12..16], b"P3DM");
+    let written = P3D::read(&mut std::io::Cursor::new(buffer)).unwrap();
+    assert_eq!(written.lods[0].points.len(), 328);
+    assert_eq!(written.lods[0].faces.len(), 592);
+    assert_eq!(written.lods[0].taggs, lod.taggs);
+}
+
+#[test]
+fn resolution_names() {
+    let p3d = P3D::read(&mut std::fs::File::open("tests/ace_gunbag.p3d").unwrap()).unwrap();
+    let names = p3d
+        .lods
+        .iter()
+        .map(|lod| hemtt_p3d::resolution::name(lod.resolution))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            "1",
+            "3",
+            "6",
+            "10",
+            "View Pilot",
+            "Shadow Volume 1",
+            "Shadow Volume 1000",
+            "Geometry",
+            "Memory",
+            "Land Contact",
+            "View Geometry",
+            "Fire Geometry"
+        ]
+    );
+}
+
+#[test]
+fn view_resolution_names() {
+    assert_eq!(hemtt_p3d::resolution::name(1000.0), "View Gunner");
+    assert_eq!(hemtt_p3d::resolution::name(1100.0), "View Pilot");
+    assert_eq!(hemtt_p3d::resolution::name(1200.0), "View Cargo");
 }
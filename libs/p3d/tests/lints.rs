@@ -0,0 +1,194 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_common::config::{PDriveOption, ProjectConfig};
+use hemtt_p3d::{analyze::lint_one, resolution, Face, Point, Selection, Tagg, Vertex, LOD, P3D};
+
+/// The corners of a 2m box
+const CORNERS: [(f32, f32, f32); 8] = [
+    (-1.0, -1.0, -1.0),
+    (1.0, -1.0, -1.0),
+    (1.0, 1.0, -1.0),
+    (-1.0, 1.0, -1.0),
+    (-1.0, -1.0, 1.0),
+    (1.0, -1.0, 1.0),
+    (1.0, 1.0, 1.0),
+    (-1.0, 1.0, 1.0),
+];
+
+/// The faces of the box, by corner
+const SIDES: [[u32; 4]; 6] = [
+    [0, 3, 2, 1],
+    [4, 5, 6, 7],
+    [0, 1, 5, 4],
+    [1, 2, 6, 5],
+    [2, 3, 7, 6],
+    [3, 0, 4, 7],
+];
+
+fn face(points: &[u32], texture: &str) -> Face {
+    let mut face = Face::new();
+    face.vertices = points
+        .iter()
+        .map(|point| Vertex {
+            point_index: *point,
+            ..Default::default()
+        })
+        .collect();
+    face.texture = texture.to_string();
+    face
+}
+
+fn lod(resolution: f32, texture: &str) -> LOD {
+    let points = CORNERS
+        .iter()
+        .map(|coords| Point {
+            coords: *coords,
+            flags: 0,
+        })
+        .collect::<Vec<_>>();
+    let faces = SIDES
+        .iter()
+        .map(|side| face(side, texture))
+        .collect::<Vec<_>>();
+    let mut taggs = Vec::new();
+    if resolution >= resolution::GEOMETRY {
+        taggs.push(Tagg::Mass(vec![10.0; points.len()]));
+        taggs.push(Tagg::Selection {
+            name: "Component01".to_string(),
+            selection: Selection::new(vec![1; points.len()], vec![1; faces.len()]),
+        });
+    }
+    LOD {
+        version_major: 28,
+        version_minor: 0x100,
+        resolution,
+        points,
+        face_normals: Vec::new(),
+        faces,
+        taggs,
+    }
+}
+
+/// A box with a resolution LOD and all geometry LODs
+fn model() -> P3D {
+    P3D {
+        version: 257,
+        lods: vec![
+            lod(1.0, "z\\abe\\addons\\main\\data\\box_co.paa"),
+            lod(resolution::GEOMETRY, ""),
+            lod(resolution::FIRE_GEOMETRY, ""),
+            lod(resolution::VIEW_GEOMETRY, ""),
+        ],
+    }
+}
+
+fn geometry(p3d: &mut P3D) -> &mut LOD {
+    &mut p3d.lods[1]
+}
+
+fn lint(p3d: P3D, project: Option<&ProjectConfig>) -> Vec<String> {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .memory()
+        .finish(None, false, &PDriveOption::Disallow)
+        .unwrap();
+    let path = workspace.join("box.p3d").unwrap();
+    lint_one(&(p3d, path), project)
+        .iter()
+        .map(|c| format!("{} {}", c.ident(), c.message()))
+        .collect()
+}
+
+fn project() -> ProjectConfig {
+    ProjectConfig::from_file(std::path::Path::new("tests/project.toml")).unwrap()
+}
+
+#[test]
+fn clean() {
+    assert!(lint(model(), Some(&project())).is_empty());
+}
+
+#[test]
+fn fixtures() {
+    let p3d = P3D::read(&mut std::fs::File::open("tests/kat_iv.p3d").unwrap()).unwrap();
+    assert_eq!(
+        lint(p3d, None),
+        vec!["L-M01 model `/box.p3d` has no Geometry, Fire Geometry, View Geometry LOD"]
+    );
+    let p3d = P3D::read(&mut std::fs::File::open("tests/ace_gunbag.p3d").unwrap()).unwrap();
+    assert_eq!(
+        lint(p3d, None)[0],
+        "L-M05 face 2552 in LOD `1` of `/box.p3d` is degenerate"
+    );
+}
+
+#[test]
+fn missing_geometry_lods() {
+    let mut p3d = model();
+    p3d.lods.truncate(2);
+    assert_eq!(
+        lint(p3d, None),
+        vec!["L-M01 model `/box.p3d` has no Fire Geometry, View Geometry LOD"]
+    );
+}
+
+#[test]
+fn geometry_zero_mass() {
+    let mut p3d = model();
+    geometry(&mut p3d)
+        .taggs
+        .retain(|t| !matches!(t, Tagg::Mass(_)));
+    assert_eq!(
+        lint(p3d, None),
+        vec!["L-M02 model `/box.p3d` has no mass in LOD `Geometry`"]
+    );
+}
+
+#[test]
+fn component_not_closed() {
+    let mut p3d = model();
+    geometry(&mut p3d).faces[1] = face(&[4, 5, 6], "");
+    assert_eq!(
+        lint(p3d, None),
+        vec!["L-M03 `Component01` in LOD `Geometry` of `/box.p3d` is not closed at face 1"]
+    );
+}
+
+#[test]
+fn component_not_convex() {
+    let mut p3d = model();
+    // push a corner into the box
+    geometry(&mut p3d).points[6].coords = (0.2, 0.2, 0.2);
+    let codes = lint(p3d, None);
+    assert_eq!(codes.len(), 1);
+    assert!(codes[0]
+        .starts_with("L-M04 `Component01` in LOD `Geometry` of `/box.p3d` is not convex at face"));
+}
+
+#[test]
+fn degenerate_face() {
+    let mut p3d = model();
+    p3d.lods[0].faces.push(face(&[0, 1, 1], ""));
+    p3d.lods[0].faces.push(face(&[0, 1, 2], ""));
+    p3d.lods[0].points[2].coords = (3.0, -1.0, -1.0);
+    let codes = lint(p3d, None);
+    assert_eq!(
+        codes,
+        vec!["L-M05 face 6 in LOD `1` of `/box.p3d` is degenerate"]
+    );
+}
+
+#[test]
+fn texture_prefix() {
+    let mut p3d = model();
+    p3d.lods[0].faces[2].texture = "abe\\addons\\main\\data\\box_co.paa".to_string();
+    p3d.lods[0].faces[3].texture = "P:\\z\\abe\\addons\\main\\data\\box_co.paa".to_string();
+    p3d.lods[0].faces[4].texture = "a3\\data_f\\white_co.paa".to_string();
+    p3d.lods[0].faces[5].texture = "#(argb,8,8,3)color(1,1,1,1)".to_string();
+    assert_eq!(
+        lint(p3d, Some(&project())),
+        vec![
+            "L-M06 `abe\\addons\\main\\data\\box_co.paa` on face 2 in LOD `1` of `/box.p3d` will not be found",
+            "L-M06 `P:\\z\\abe\\addons\\main\\data\\box_co.paa` on face 3 in LOD `1` of `/box.p3d` will not be found",
+        ]
+    );
+}
@@ -0,0 +1,49 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_common::config::PDriveOption;
+use hemtt_p3d::{Face, SearchCache, LOD, P3D};
+
+fn model(textures: &[&str]) -> P3D {
+    P3D {
+        version: 257,
+        lods: vec![LOD {
+            version_major: 28,
+            version_minor: 0x100,
+            resolution: 1.0,
+            points: Vec::new(),
+            face_normals: Vec::new(),
+            faces: textures
+                .iter()
+                .map(|texture| {
+                    let mut face = Face::new();
+                    face.texture = (*texture).to_string();
+                    face
+                })
+                .collect(),
+            taggs: Vec::new(),
+        }],
+    }
+}
+
+#[test]
+fn converted_textures() {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .memory()
+        .finish(None, false, &PDriveOption::Disallow)
+        .unwrap();
+    let data = workspace.join("z/abe/addons/main/data").unwrap();
+    data.vfs().create_dir_all().unwrap();
+    for file in ["box_co.png", "wheel_co.tga", "door_co.paa"] {
+        data.join(file).unwrap().create_file().unwrap();
+    }
+    let (textures, materials) = model(&[
+        "z\\abe\\addons\\main\\data\\box_co.paa",
+        "z\\abe\\addons\\main\\data\\wheel_co.paa",
+        "z\\abe\\addons\\main\\data\\door_co.paa",
+        "z\\abe\\addons\\main\\data\\hood_co.paa",
+    ])
+    .missing(&workspace, &SearchCache::new())
+    .unwrap();
+    assert_eq!(textures, vec!["\\z\\abe\\addons\\main\\data\\hood_co.paa"]);
+    assert!(materials.is_empty());
+}
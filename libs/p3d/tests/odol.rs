@@ -0,0 +1,219 @@
+#![allow(clippy::unwrap_used)]
+
+//! Builds a small ODOL v73 model, to check the layout that is read
+
+use hemtt_p3d::{Error, ODOL};
+
+#[derive(Default)]
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn u8(&mut self, value: u8) -> &mut Self {
+        self.0.push(value);
+        self
+    }
+
+    fn u32(&mut self, value: u32) -> &mut Self {
+        self.0.extend(value.to_le_bytes());
+        self
+    }
+
+    fn i32(&mut self, value: i32) -> &mut Self {
+        self.0.extend(value.to_le_bytes());
+        self
+    }
+
+    fn f32(&mut self, value: f32) -> &mut Self {
+        self.0.extend(value.to_le_bytes());
+        self
+    }
+
+    fn asciiz(&mut self, value: &str) -> &mut Self {
+        self.0.extend(value.as_bytes());
+        self.0.push(0);
+        self
+    }
+
+    fn zeros(&mut self, len: usize) -> &mut Self {
+        self.0.extend(vec![0; len]);
+        self
+    }
+
+    fn array(&mut self, count: u32, data: &[u8], compress: bool) -> &mut Self {
+        self.u32(count);
+        if data.is_empty() {
+            return self;
+        }
+        self.u8(u8::from(compress));
+        if compress {
+            let mut compressed = Vec::with_capacity(hemtt_lzo::worst_compress(data.len()));
+            hemtt_lzo::compress(data, &mut compressed).unwrap();
+            self.0.extend(compressed);
+        } else {
+            self.0.extend(data);
+        }
+        self
+    }
+}
+
+fn model() -> Vec<u8> {
+    let mut w = Writer::default();
+    w.0.extend(b"ODOL");
+    w.u32(73).u32(0).asciiz("z\\abe\\addons\\main");
+    w.u32(2).f32(1.0).f32(1e13);
+
+    // model info
+    w.zeros(216);
+    w.asciiz("OFP2_ManSkeleton")
+        .u8(0)
+        .u32(2)
+        .asciiz("pelvis")
+        .asciiz("")
+        .asciiz("spine")
+        .asciiz("pelvis")
+        .asciiz("");
+    w.u8(0);
+    let mass = (0..300u16)
+        .flat_map(|i| (f32::from(i) / 10.0).to_le_bytes())
+        .collect::<Vec<_>>();
+    w.array(300, &mass, true);
+    w.zeros(16 + 4 + 14 + 4 + 1);
+    w.asciiz("house").asciiz("tree");
+    w.zeros(1 + 4 + 2 * 12);
+
+    // animations
+    w.u8(1).u32(2);
+    w.u32(0)
+        .asciiz("door")
+        .asciiz("door_source")
+        .zeros(28)
+        .zeros(8);
+    w.u32(9).asciiz("hide").asciiz("damage").zeros(28).zeros(8);
+    w.u32(2);
+    w.u32(1).u32(2).u32(0).u32(1);
+    w.u32(0);
+    w.i32(0).zeros(24).i32(0);
+    w.i32(-1).i32(-1);
+
+    let starts = w.0.len();
+    w.zeros(4 * 2 * 2);
+    // use default face data
+    w.u8(1).u8(1);
+
+    let first = w.0.len();
+    // proxies
+    w.u32(1)
+        .asciiz("\\a3\\data_f\\proxies\\gunner.p3d")
+        .zeros(48 + 16);
+    w.u32(0).u32(0);
+    w.zeros(4 + 4 + 8 + 40);
+    w.u32(2)
+        .asciiz("z\\abe\\addons\\main\\data\\body_co.paa")
+        .asciiz("#(argb,8,8,3)color(1,0,0,1)");
+    // material
+    w.u32(1)
+        .asciiz("z\\abe\\addons\\main\\data\\body.rvmat")
+        .u32(11)
+        .zeros(96 + 20)
+        .asciiz("")
+        .zeros(8)
+        .u32(1)
+        .u32(1);
+    w.u32(0)
+        .asciiz("z\\abe\\addons\\main\\data\\body_nohq.paa")
+        .u32(0)
+        .u8(0);
+    w.zeros(4 + 48);
+    w.u32(0).asciiz("").u32(0).u8(0);
+    // point to vertex, vertex to point
+    w.array(3, &[0; 12], false).array(0, &[], false);
+    // polygons
+    w.u32(1).zeros(6).u8(3).u32(0).u32(1).u32(2);
+    // sections
+    w.u32(1)
+        .zeros(26)
+        .i32(-1)
+        .asciiz("body.rvmat")
+        .u32(1)
+        .f32(1.0)
+        .i32(0);
+    // named selections
+    w.u32(1).asciiz("camo");
+    w.array(1, &[0; 4], false).u32(0).u8(0).array(0, &[], false);
+    w.array(0, &[], false).u32(0);
+    // named properties
+    w.u32(1).asciiz("class").asciiz("house");
+    w.zeros(64);
+
+    let second = w.0.len();
+    w.u32(0).u32(0).u32(0).zeros(4 + 4 + 8 + 40).u32(0).u32(0);
+    w.array(0, &[], false).array(0, &[], false);
+    w.u32(0).zeros(6);
+    w.u32(0).u32(0).u32(0);
+
+    let end = w.0.len();
+    for (i, value) in [first, second, second, end].into_iter().enumerate() {
+        let value = u32::try_from(value).unwrap().to_le_bytes();
+        w.0[starts + i * 4..starts + i * 4 + 4].copy_from_slice(&value);
+    }
+    w.0
+}
+
+#[test]
+fn read() {
+    let odol = ODOL::read(&mut std::io::Cursor::new(model())).unwrap();
+    assert_eq!(odol.version, 73);
+    assert_eq!(odol.prefix, "z\\abe\\addons\\main");
+    let skeleton = odol.skeleton.unwrap();
+    assert_eq!(skeleton.name, "OFP2_ManSkeleton");
+    assert_eq!(
+        skeleton.bones,
+        vec![
+            ("pelvis".to_string(), String::new()),
+            ("spine".to_string(), "pelvis".to_string())
+        ]
+    );
+    assert_eq!(odol.animations, vec!["door", "hide"]);
+    assert_eq!(odol.property_class, "house");
+    assert_eq!(odol.property_damage, "tree");
+
+    assert_eq!(odol.lods.len(), 2);
+    let lod = &odol.lods[0];
+    assert!((lod.resolution - 1.0).abs() < f32::EPSILON);
+    assert_eq!(lod.proxies, vec!["\\a3\\data_f\\proxies\\gunner.p3d"]);
+    assert_eq!(
+        lod.textures,
+        vec![
+            "z\\abe\\addons\\main\\data\\body_co.paa",
+            "#(argb,8,8,3)color(1,0,0,1)"
+        ]
+    );
+    assert_eq!(
+        lod.materials,
+        vec!["z\\abe\\addons\\main\\data\\body.rvmat"]
+    );
+    assert_eq!(lod.faces, 1);
+    assert_eq!(lod.selections, vec!["camo"]);
+    assert_eq!(
+        lod.properties,
+        vec![("class".to_string(), "house".to_string())]
+    );
+    assert!(odol.lods[1].textures.is_empty());
+}
+
+#[test]
+fn unsupported() {
+    let mut data = model();
+    data[4] = 50;
+    assert!(matches!(
+        ODOL::read(&mut std::io::Cursor::new(&data)),
+        Err(Error::UnsupportedODOLVersion(50))
+    ));
+    assert!(matches!(
+        ODOL::read(&mut std::fs::File::open("tests/ace_gunbag.p3d").unwrap()),
+        Err(Error::UnsupportedP3DType(_))
+    ));
+    // truncated data is an error, not a panic
+    let data = model();
+    assert!(ODOL::read(&mut std::io::Cursor::new(&data[..data.len() / 2])).is_err());
+}
@@ -0,0 +1,41 @@
+#![allow(clippy::unwrap_used)]
+
+use std::collections::BTreeSet;
+
+use hemtt_p3d::P3D;
+
+fn paths(p3d: &P3D) -> BTreeSet<String> {
+    p3d.lods
+        .iter()
+        .flat_map(|lod| &lod.faces)
+        .flat_map(|face| [face.texture.clone(), face.material.clone()])
+        .filter(|path| !path.is_empty())
+        .collect()
+}
+
+#[test]
+fn ace_gunbag() {
+    let mut p3d = P3D::read(&mut std::fs::File::open("tests/ace_gunbag.p3d").unwrap()).unwrap();
+    assert_eq!(
+        p3d.retexture("z\\ace\\addons\\gun", "z\\abe\\addons\\bag"),
+        0
+    );
+    let changed = p3d.retexture("\\Z\\ACE\\addons\\gunbag\\", "z\\abe\\addons\\bag");
+    assert!(changed > 0);
+    let mut written = Vec::new();
+    p3d.write(&mut written).unwrap();
+    let p3d = P3D::read(&mut std::io::Cursor::new(written)).unwrap();
+    assert_eq!(
+        paths(&p3d),
+        [
+            "#(argb,8,8,3)color(0,0,0,0)",
+            "#(argb,8,8,3)color(0,0,0,1.0,co)",
+            "a3\\data_f\\penetration\\cloth.rvmat",
+            "z\\abe\\addons\\bag\\data\\gunbag.rvmat",
+            "z\\abe\\addons\\bag\\data\\gunbag_co.paa",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect()
+    );
+}
@@ -0,0 +1,112 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_p3d::{Selection, Tagg, P3D};
+
+#[test]
+fn ace_gunbag_round_trip() {
+    let original = std::fs::read("tests/ace_gunbag.p3d").unwrap();
+    let p3d = P3D::read(&mut std::io::Cursor::new(&original)).unwrap();
+    let mut written = Vec::new();
+    p3d.write(&mut written).unwrap();
+    assert_eq!(original, written);
+}
+
+#[test]
+fn ace_gunbag_taggs() {
+    let p3d = P3D::read(&mut std::fs::File::open("tests/ace_gunbag.p3d").unwrap()).unwrap();
+    assert!(p3d
+        .lods
+        .iter()
+        .flat_map(|lod| &lod.taggs)
+        .all(|t| !matches!(t, Tagg::Unknown { .. })));
+
+    let lod = &p3d.lods[0];
+    assert_eq!(
+        lod.properties().collect::<Vec<_>>(),
+        vec![("autocenter", "0"), ("lodnoshadow", "1")]
+    );
+    assert_eq!(lod.property("LODNoShadow"), Some("1"));
+    assert_eq!(
+        lod.selections().map(|(name, _)| name).collect::<Vec<_>>(),
+        vec!["Spine2", "insignia", "Camo", "Camo1"]
+    );
+    let camo = lod.selection("camo").unwrap();
+    assert_eq!(camo.points.len(), lod.points.len());
+    assert_eq!(camo.faces.len(), lod.faces.len());
+    assert!(camo.selected_faces().count() > 0);
+    assert!(lod.sharp_edges().is_some());
+    let (_, uvs) = lod.uv_sets().next().unwrap();
+    assert_eq!(
+        uvs.len(),
+        lod.faces.iter().map(|f| f.vertices.len()).sum::<usize>()
+    );
+
+    let geometry = p3d
+        .lods
+        .iter()
+        .find(|lod| lod.resolution > 9e12 && lod.resolution < 2e13)
+        .unwrap();
+    assert_eq!(geometry.mass().unwrap().len(), geometry.points.len());
+}
+
+#[test]
+fn property() {
+    let tagg = Tagg::Property {
+        name: "class".to_string(),
+        value: "house".to_string(),
+    };
+    let data = tagg.data();
+    assert_eq!(data.len(), 128);
+    assert_eq!(
+        Tagg::from_raw("#Property#".to_string(), data.clone(), 0, 0),
+        tagg
+    );
+    // bytes after the null can not be written again, so they are kept as is
+    let mut dirty = data;
+    dirty[100] = 1;
+    assert!(matches!(
+        Tagg::from_raw("#Property#".to_string(), dirty, 0, 0),
+        Tagg::Unknown { .. }
+    ));
+}
+
+#[test]
+fn selection() {
+    let tagg = Tagg::from_raw("gear".to_string(), vec![0, 1, 128, 1, 0], 3, 2);
+    let Tagg::Selection { name, selection } = tagg else {
+        panic!("expected a selection");
+    };
+    assert_eq!(name, "gear");
+    assert_eq!(
+        selection
+            .selected_points()
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert!((selection.point_weight(2) - 128.0 / 255.0).abs() < f32::EPSILON);
+    assert!((selection.face_weight(0) - 1.0).abs() < f32::EPSILON);
+    assert!(selection.face_weight(1).abs() < f32::EPSILON);
+    // the wrong length is not a selection
+    assert!(matches!(
+        Tagg::from_raw("gear".to_string(), vec![0, 1], 3, 2),
+        Tagg::Unknown { .. }
+    ));
+}
+
+#[test]
+fn selection_weights() {
+    let mut selection = Selection::default();
+    selection.set_point_weight(3, 0.5);
+    selection.set_point_weight(1, 1.0);
+    selection.set_face_weight(0, 0.001);
+    assert_eq!(selection.points, vec![0, 1, 0, 128]);
+    // a small weight is still selected
+    assert_eq!(selection.faces, vec![255]);
+    for byte in 2..=255 {
+        let mut other = Selection::new(vec![byte], vec![]);
+        let weight = other.point_weight(0);
+        other.set_point_weight(0, weight);
+        assert_eq!(other.points[0], byte);
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Severity},
+};
+
+use crate::analyze::{TextureData, TextureLintData};
+
+crate::analyze::lint!(LintT01NotPowerOfTwo);
+
+impl Lint<TextureLintData> for LintT01NotPowerOfTwo {
+    fn ident(&self) -> &str {
+        "not_power_of_two"
+    }
+
+    fn sort(&self) -> u32 {
+        10
+    }
+
+    fn description(&self) -> &str {
+        "Checks for textures that are not a power of two in size"
+    }
+
+    fn documentation(&self) -> &str {
+        r"### Example
+
+**Incorrect**
+
+A texture that is 1000x1000 pixels.
+
+**Correct**
+
+A texture that is 1024x1024 pixels.
+
+### Explanation
+
+The engine requires both sides of a texture to be a power of two, such as 256, 512 or 1024. Other sizes may fail to load or display incorrectly.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::error()
+    }
+
+    fn minimum_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<TextureLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<TextureLintData> for Runner {
+    type Target = TextureData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &TextureData,
+        _data: &TextureLintData,
+    ) -> Codes {
+        let (paa, path) = target;
+        let Some(map) = paa.maps().first() else {
+            return vec![];
+        };
+        if map.width().is_power_of_two() && map.height().is_power_of_two() {
+            return vec![];
+        }
+        vec![Arc::new(CodeT01NotPowerOfTwo::new(
+            path.as_str().to_string(),
+            (map.width(), map.height()),
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeT01NotPowerOfTwo {
+    path: String,
+    size: (u16, u16),
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeT01NotPowerOfTwo {
+    fn ident(&self) -> &'static str {
+        "L-T01"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/textures.html#not_power_of_two")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "texture `{}` is {}x{}, which is not a power of two",
+            self.path, self.size.0, self.size.1
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("resize the texture so both sides are a power of two, such as 512 or 1024".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeT01NotPowerOfTwo {
+    #[must_use]
+    pub fn new(path: String, size: (u16, u16), severity: Severity) -> Self {
+        Self {
+            path,
+            size,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
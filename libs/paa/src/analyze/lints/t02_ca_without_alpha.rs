@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Severity},
+};
+
+use crate::{
+    analyze::{TextureData, TextureLintData},
+    pax::texture_suffix,
+    PaXType,
+};
+
+crate::analyze::lint!(LintT02CaWithoutAlpha);
+
+impl Lint<TextureLintData> for LintT02CaWithoutAlpha {
+    fn ident(&self) -> &str {
+        "ca_without_alpha"
+    }
+
+    fn sort(&self) -> u32 {
+        20
+    }
+
+    fn description(&self) -> &str {
+        "Checks for `_ca` textures that are stored without an alpha channel"
+    }
+
+    fn documentation(&self) -> &str {
+        r"### Example
+
+**Incorrect**
+
+`flag_ca.paa` stored as DXT1, or without an alpha flag.
+
+**Correct**
+
+`flag_ca.paa` stored as DXT5, with an alpha flag.
+
+### Explanation
+
+The `_ca` suffix tells the engine the texture has transparency. DXT1 can not store blended alpha, and a texture without an alpha flag will be drawn as opaque. Converting the texture again from an image with transparency will fix both.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<TextureLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<TextureLintData> for Runner {
+    type Target = TextureData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &TextureData,
+        _data: &TextureLintData,
+    ) -> Codes {
+        let (paa, path) = target;
+        if texture_suffix(&path.filename()) != "ca" {
+            return vec![];
+        }
+        let reason = if *paa.format() == PaXType::DXT1 {
+            Reason::Dxt1
+        } else if paa.flag().is_none() {
+            Reason::NoFlag
+        } else {
+            return vec![];
+        };
+        vec![Arc::new(CodeT02CaWithoutAlpha::new(
+            path.as_str().to_string(),
+            reason,
+            config.severity(),
+        ))]
+    }
+}
+
+pub enum Reason {
+    Dxt1,
+    NoFlag,
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeT02CaWithoutAlpha {
+    path: String,
+    reason: Reason,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeT02CaWithoutAlpha {
+    fn ident(&self) -> &'static str {
+        "L-T02"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/textures.html#ca_without_alpha")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        match self.reason {
+            Reason::Dxt1 => format!("texture `{}` is `_ca` but stored as DXT1", self.path),
+            Reason::NoFlag => format!("texture `{}` is `_ca` but has no alpha flag", self.path),
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(
+            "convert the texture again from an image with transparency, it will be stored as DXT5"
+                .to_string(),
+        )
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeT02CaWithoutAlpha {
+    #[must_use]
+    pub fn new(path: String, reason: Reason, severity: Severity) -> Self {
+        Self {
+            path,
+            reason,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
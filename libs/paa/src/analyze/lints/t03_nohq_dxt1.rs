@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Severity},
+};
+
+use crate::{
+    analyze::{TextureData, TextureLintData},
+    pax::texture_suffix,
+    PaXType,
+};
+
+crate::analyze::lint!(LintT03NohqDxt1);
+
+impl Lint<TextureLintData> for LintT03NohqDxt1 {
+    fn ident(&self) -> &str {
+        "nohq_dxt1"
+    }
+
+    fn sort(&self) -> u32 {
+        30
+    }
+
+    fn description(&self) -> &str {
+        "Checks for `_nohq` normal maps that are stored as DXT1"
+    }
+
+    fn documentation(&self) -> &str {
+        r"### Example
+
+**Incorrect**
+
+`body_nohq.paa` stored as DXT1.
+
+**Correct**
+
+`body_nohq.paa` stored as DXT5.
+
+### Explanation
+
+Normal maps need more precision than DXT1 can provide, which causes visible banding in the lighting. The engine expects `_nohq` textures to be stored as DXT5.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<TextureLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<TextureLintData> for Runner {
+    type Target = TextureData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &TextureData,
+        _data: &TextureLintData,
+    ) -> Codes {
+        let (paa, path) = target;
+        if texture_suffix(&path.filename()) != "nohq" || *paa.format() != PaXType::DXT1 {
+            return vec![];
+        }
+        vec![Arc::new(CodeT03NohqDxt1::new(
+            path.as_str().to_string(),
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeT03NohqDxt1 {
+    path: String,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeT03NohqDxt1 {
+    fn ident(&self) -> &'static str {
+        "L-T03"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/textures.html#nohq_dxt1")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("normal map `{}` is stored as DXT1", self.path)
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("convert the texture again as DXT5".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeT03NohqDxt1 {
+    #[must_use]
+    pub fn new(path: String, severity: Severity) -> Self {
+        Self {
+            path,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Severity},
+};
+
+use crate::{
+    analyze::{TextureData, TextureLintData},
+    Problem,
+};
+
+crate::analyze::lint!(LintT04MissingMipmaps);
+
+impl Lint<TextureLintData> for LintT04MissingMipmaps {
+    fn ident(&self) -> &str {
+        "missing_mipmaps"
+    }
+
+    fn sort(&self) -> u32 {
+        40
+    }
+
+    fn description(&self) -> &str {
+        "Checks for textures with missing mipmaps"
+    }
+
+    fn documentation(&self) -> &str {
+        r"### Example
+
+**Incorrect**
+
+A 1024x1024 texture with only a single mipmap.
+
+**Correct**
+
+A 1024x1024 texture with mipmaps of 512x512, 256x256 and so on, until a side is 4 pixels.
+
+### Explanation
+
+The engine uses smaller mipmaps for textures that are far away. Without them, the full texture is sampled at every distance, which looks noisy and uses more memory. Textures converted by HEMTT always have a complete chain.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<TextureLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<TextureLintData> for Runner {
+    type Target = TextureData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &TextureData,
+        _data: &TextureLintData,
+    ) -> Codes {
+        let (paa, path) = target;
+        let Some(problem) = paa.validate().into_iter().find(|p| {
+            matches!(
+                p,
+                Problem::MissingMipLevel { .. } | Problem::IncompleteMipChain { .. }
+            )
+        }) else {
+            return vec![];
+        };
+        vec![Arc::new(CodeT04MissingMipmaps::new(
+            path.as_str().to_string(),
+            problem,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeT04MissingMipmaps {
+    path: String,
+    problem: Problem,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeT04MissingMipmaps {
+    fn ident(&self) -> &'static str {
+        "L-T04"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/textures.html#missing_mipmaps")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("texture `{}` is missing mipmaps", self.path)
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(self.problem.to_string())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("convert the texture again to generate the mipmaps".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeT04MissingMipmaps {
+    #[must_use]
+    pub fn new(path: String, problem: Problem, severity: Severity) -> Self {
+        Self {
+            path,
+            problem,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
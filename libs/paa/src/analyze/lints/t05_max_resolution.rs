@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Severity},
+};
+
+use crate::analyze::{TextureData, TextureLintData};
+
+crate::analyze::lint!(LintT05MaxResolution);
+
+impl Lint<TextureLintData> for LintT05MaxResolution {
+    fn ident(&self) -> &str {
+        "max_resolution"
+    }
+
+    fn sort(&self) -> u32 {
+        50
+    }
+
+    fn description(&self) -> &str {
+        "Checks for textures larger than a maximum resolution"
+    }
+
+    fn documentation(&self) -> &str {
+        r"### Configuration
+
+- **max**: the largest allowed width or height, defaults to 4096
+
+```toml
+[lints.textures.max_resolution]
+options.max = 2048
+```
+
+### Example
+
+**Incorrect**
+
+A texture that is 8192x8192 pixels.
+
+**Correct**
+
+A texture that is 4096x4096 pixels or smaller.
+
+### Explanation
+
+Large textures increase the size of the mod and the memory used in game, often without a visible difference.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<TextureLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<TextureLintData> for Runner {
+    type Target = TextureData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &TextureData,
+        _data: &TextureLintData,
+    ) -> Codes {
+        let (paa, path) = target;
+        let max = if let Some(toml::Value::Integer(max)) = config.option("max") {
+            u64::try_from(*max).unwrap_or_default()
+        } else {
+            4096
+        };
+        let Some(map) = paa.maps().first() else {
+            return vec![];
+        };
+        if u64::from(map.width()) <= max && u64::from(map.height()) <= max {
+            return vec![];
+        }
+        vec![Arc::new(CodeT05MaxResolution::new(
+            path.as_str().to_string(),
+            (map.width(), map.height()),
+            max,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeT05MaxResolution {
+    path: String,
+    size: (u16, u16),
+    max: u64,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeT05MaxResolution {
+    fn ident(&self) -> &'static str {
+        "L-T05"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/textures.html#max_resolution")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "texture `{}` is {}x{}, larger than the maximum of {}",
+            self.path, self.size.0, self.size.1, self.max
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "resize the texture so neither side is larger than {}",
+            self.max
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeT05MaxResolution {
+    #[must_use]
+    pub fn new(path: String, size: (u16, u16), max: u64, severity: Severity) -> Self {
+        Self {
+            path,
+            size,
+            max,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
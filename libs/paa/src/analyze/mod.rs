@@ -0,0 +1,30 @@
+use hemtt_common::config::ProjectConfig;
+use hemtt_workspace::{lint::LintManager, lint_manager, reporting::Codes, WorkspacePath};
+
+use crate::Paa;
+
+pub mod lints {
+    automod::dir!(pub "src/analyze/lints");
+}
+
+lint_manager!(texture, vec![], TextureLintData);
+
+pub struct TextureLintData {}
+
+/// A texture and the path it was read from
+pub type TextureData = (Paa, WorkspacePath);
+
+pub fn lint_one(texture: &TextureData, project: Option<&ProjectConfig>) -> Codes {
+    let mut manager = LintManager::new(project.map_or_else(Default::default, |project| {
+        project.lints().textures().clone()
+    }));
+    if let Err(e) = manager.extend(
+        TEXTURE_LINTS
+            .iter()
+            .map(|l| (**l).clone())
+            .collect::<Vec<_>>(),
+    ) {
+        return e;
+    }
+    manager.run(&TextureLintData {}, project, None, texture)
+}
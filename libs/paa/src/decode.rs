@@ -0,0 +1,61 @@
+use image::RgbaImage;
+use texpresso::Format;
+
+use crate::PaXType;
+
+/// Decode the uncompressed data of a single mipmap
+///
+/// Returns `None` if the data is too short for the dimensions
+pub fn decode(format: &PaXType, width: u16, height: u16, data: &[u8]) -> Option<RgbaImage> {
+    if data.len() < format.data_size(width, height) {
+        return None;
+    }
+    let pixels = usize::from(width) * usize::from(height);
+    let rgba = match format {
+        PaXType::DXT1 | PaXType::DXT2 | PaXType::DXT3 | PaXType::DXT4 | PaXType::DXT5 => {
+            let algo = Format::try_from(format.clone()).ok()?;
+            // decode whole blocks and crop, texpresso writes out of bounds
+            // when the last row of blocks is only partially in the image
+            let (width, height) = (usize::from(width), usize::from(height));
+            let block_width = width.next_multiple_of(4);
+            let mut buffer = vec![0u8; block_width * height.next_multiple_of(4) * 4];
+            algo.decompress(data, block_width, height.next_multiple_of(4), &mut buffer);
+            buffer
+                .chunks_exact(block_width * 4)
+                .take(height)
+                .flat_map(|row| &row[..width * 4])
+                .copied()
+                .collect()
+        }
+        PaXType::RGBA8 => data[..pixels * 4]
+            .chunks_exact(4)
+            .flat_map(|p| [p[2], p[1], p[0], p[3]])
+            .collect(),
+        PaXType::RGBA4 => words(data, pixels)
+            .flat_map(|v| {
+                // 4 bit channels are scaled by 17 to cover 0..=255
+                [v >> 8, v >> 4, v, v >> 12].map(|c| (c & 0xF) as u8 * 17)
+            })
+            .collect(),
+        PaXType::RGBA5 => words(data, pixels)
+            .flat_map(|v| {
+                let [r, g, b] = [v >> 10, v >> 5, v].map(|c| {
+                    let c = (c & 0x1F) as u8;
+                    c << 3 | c >> 2
+                });
+                [r, g, b, if v & 0x8000 == 0 { 0 } else { 255 }]
+            })
+            .collect(),
+        PaXType::GRAYA => data[..pixels * 2]
+            .chunks_exact(2)
+            .flat_map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+    };
+    RgbaImage::from_raw(u32::from(width), u32::from(height), rgba)
+}
+
+fn words(data: &[u8], pixels: usize) -> impl Iterator<Item = u16> + '_ {
+    data[..pixels * 2]
+        .chunks_exact(2)
+        .map(|p| u16::from_le_bytes([p[0], p[1]]))
+}
@@ -0,0 +1,63 @@
+use image::{EncodableLayout, RgbaImage};
+use texpresso::{Format, Params};
+
+use crate::PaXType;
+
+/// Encode an image as the data of a single mipmap
+///
+/// Returns `None` if the format can not be written
+pub fn encode(format: &PaXType, image: &RgbaImage) -> Option<Vec<u8>> {
+    match format {
+        PaXType::DXT1 | PaXType::DXT3 | PaXType::DXT5 => {
+            let algo = Format::try_from(format.clone()).ok()?;
+            let (width, height) = (image.width() as usize, image.height() as usize);
+            let mut buffer = vec![0; algo.compressed_size(width, height)];
+            algo.compress(
+                image.as_bytes(),
+                width,
+                height,
+                Params::default(),
+                &mut buffer,
+            );
+            Some(buffer)
+        }
+        PaXType::RGBA8 => Some(
+            image
+                .pixels()
+                .flat_map(|p| [p.0[2], p.0[1], p.0[0], p.0[3]])
+                .collect(),
+        ),
+        PaXType::RGBA4 => Some(
+            image
+                .pixels()
+                .flat_map(|p| {
+                    let [r, g, b, a] = p.0.map(|c| u16::from(c >> 4));
+                    (a << 12 | r << 8 | g << 4 | b).to_le_bytes()
+                })
+                .collect(),
+        ),
+        PaXType::RGBA5 => Some(
+            image
+                .pixels()
+                .flat_map(|p| {
+                    let [r, g, b] = [p.0[0], p.0[1], p.0[2]].map(|c| u16::from(c >> 3));
+                    let a = u16::from(p.0[3] >= 128);
+                    (a << 15 | r << 10 | g << 5 | b).to_le_bytes()
+                })
+                .collect(),
+        ),
+        PaXType::GRAYA => Some(
+            image
+                .pixels()
+                .flat_map(|p| {
+                    let [r, g, b, a] = p.0;
+                    // ITU-R BT.601 luma
+                    let luma =
+                        (u32::from(r) * 299 + u32::from(g) * 587 + u32::from(b) * 114) / 1000;
+                    [u8::try_from(luma).unwrap_or(u8::MAX), a]
+                })
+                .collect(),
+        ),
+        PaXType::DXT2 | PaXType::DXT4 => None,
+    }
+}
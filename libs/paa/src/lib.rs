@@ -1,10 +1,19 @@
+#[cfg(not(target_arch = "wasm32"))]
+pub mod analyze;
+mod decode;
+mod encode;
+mod lzss;
 mod mipmap;
 mod paa;
 mod pax;
+mod tagg;
+mod validate;
 
 pub use self::mipmap::MipMap;
 pub use self::paa::Paa;
 pub use self::pax::PaXType;
+pub use self::tagg::{AlphaFlag, Color, Swizzle, Tagg};
+pub use self::validate::Problem;
 
 #[cfg(feature = "wasm")]
 mod wasm;
@@ -0,0 +1,209 @@
+//! LZSS compression used by the engine for the mipmaps of non-DXT formats
+//!
+//! Data is stored in blocks of 8 items, preceded by a flag byte. A set bit is a
+//! literal byte, a clear bit is a 2 byte back reference of up to 4095 bytes
+//! with a length of 3 to 18. The data is followed by a 32 bit checksum of the
+//! uncompressed bytes.
+
+use std::io::{Error, ErrorKind};
+
+const WINDOW: usize = 4095;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 18;
+
+/// Decompress `input` into exactly `size` bytes
+///
+/// # Errors
+/// [`std::io::Error`] if the data is truncated, or the checksum does not match
+pub fn decompress(input: &[u8], size: usize) -> Result<Vec<u8>, Error> {
+    let truncated = || Error::new(ErrorKind::UnexpectedEof, "lzss data is truncated");
+    let mut output = Vec::with_capacity(size);
+    let mut pos = 0;
+    while output.len() < size {
+        let flags = *input.get(pos).ok_or_else(truncated)?;
+        pos += 1;
+        for bit in 0..8 {
+            if output.len() >= size {
+                break;
+            }
+            if flags & (1 << bit) != 0 {
+                output.push(*input.get(pos).ok_or_else(truncated)?);
+                pos += 1;
+            } else {
+                let [low, high] = [
+                    *input.get(pos).ok_or_else(truncated)?,
+                    *input.get(pos + 1).ok_or_else(truncated)?,
+                ];
+                pos += 2;
+                let distance = usize::from(low) | (usize::from(high & 0xF0) << 4);
+                let length = usize::from(high & 0x0F) + MIN_MATCH;
+                for _ in 0..length.min(size - output.len()) {
+                    // references before the start of the data are spaces
+                    let byte = output
+                        .len()
+                        .checked_sub(distance)
+                        .map_or(b' ', |from| output[from]);
+                    output.push(byte);
+                }
+            }
+        }
+    }
+    let checksum = input
+        .get(pos..pos + 4)
+        .ok_or_else(truncated)
+        .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))?;
+    if checksum != signed_checksum(&output) && checksum != unsigned_checksum(&output) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "lzss checksum does not match",
+        ));
+    }
+    Ok(output)
+}
+
+/// Compress `input`, followed by its checksum
+pub fn compress(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(input.len() + input.len() / 8 + 5);
+    let mut chains = Chains::new(input.len());
+    let mut pos = 0;
+    while pos < input.len() {
+        let flag_pos = output.len();
+        output.push(0);
+        for bit in 0..8 {
+            if pos >= input.len() {
+                break;
+            }
+            let (distance, mut length) = chains.longest_match(input, pos);
+            if length >= MIN_MATCH {
+                #[allow(clippy::cast_possible_truncation)]
+                output.extend([
+                    distance as u8,
+                    ((distance >> 4) & 0xF0) as u8 | (length - MIN_MATCH) as u8,
+                ]);
+            } else {
+                output[flag_pos] |= 1 << bit;
+                output.push(input[pos]);
+                length = 1;
+            }
+            for _ in 0..length {
+                chains.insert(input, pos);
+                pos += 1;
+            }
+        }
+    }
+    output.extend(signed_checksum(input).to_le_bytes());
+    output
+}
+
+/// Hash chains of the positions of every 3 byte sequence
+struct Chains {
+    head: Vec<usize>,
+    prev: Vec<usize>,
+}
+
+impl Chains {
+    const BITS: usize = 14;
+    const DEPTH: usize = 64;
+    const NONE: usize = usize::MAX;
+
+    fn new(size: usize) -> Self {
+        Self {
+            head: vec![Self::NONE; 1 << Self::BITS],
+            prev: vec![Self::NONE; size],
+        }
+    }
+
+    fn hash(input: &[u8], pos: usize) -> Option<usize> {
+        let bytes = input.get(pos..pos + MIN_MATCH)?;
+        let value =
+            (usize::from(bytes[0]) << 16) | (usize::from(bytes[1]) << 8) | usize::from(bytes[2]);
+        Some((value.wrapping_mul(2_654_435_761) >> 8) & ((1 << Self::BITS) - 1))
+    }
+
+    fn insert(&mut self, input: &[u8], pos: usize) {
+        if let Some(hash) = Self::hash(input, pos) {
+            self.prev[pos] = self.head[hash];
+            self.head[hash] = pos;
+        }
+    }
+
+    /// Find the longest earlier occurrence of the data at `pos`
+    fn longest_match(&self, input: &[u8], pos: usize) -> (usize, usize) {
+        let Some(hash) = Self::hash(input, pos) else {
+            return (0, 0);
+        };
+        let max = MAX_MATCH.min(input.len() - pos);
+        let mut best = (0, 0);
+        let mut candidate = self.head[hash];
+        for _ in 0..Self::DEPTH {
+            if candidate == Self::NONE || pos - candidate > WINDOW {
+                break;
+            }
+            let length = (0..max)
+                .take_while(|i| input[candidate + i] == input[pos + i])
+                .count();
+            if length > best.1 {
+                best = (pos - candidate, length);
+                if length == max {
+                    break;
+                }
+            }
+            candidate = self.prev[candidate];
+        }
+        best
+    }
+}
+
+/// Textures use a checksum of the bytes as signed values
+fn signed_checksum(data: &[u8]) -> u32 {
+    data.iter().fold(0u32, |sum, b| {
+        sum.wrapping_add_signed(i32::from(i8::from_ne_bytes([*b])))
+    })
+}
+
+fn unsigned_checksum(data: &[u8]) -> u32 {
+    data.iter()
+        .fold(0u32, |sum, b| sum.wrapping_add(u32::from(*b)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompress_reference() {
+        // 3 literals, then a reference 3 bytes back of 6 bytes
+        let mut data = vec![0b0000_0111, b'A', b'B', b'C', 3, 3];
+        data.extend(594u32.to_le_bytes());
+        assert_eq!(decompress(&data, 9).expect("valid data"), b"ABCABCABC");
+
+        // references before the start of the data are spaces
+        let mut data = vec![0b0000_0010, 5, 0, b'!'];
+        data.extend((3 * 32 + 33u32).to_le_bytes());
+        assert_eq!(decompress(&data, 4).expect("valid data"), b"   !");
+
+        data[4] = 0;
+        assert!(decompress(&data, 4).is_err());
+        assert!(decompress(&data[..3], 4).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn compress_round_trip() {
+        let data = (0..20_000u32)
+            .map(|i| (i * 7 % 251) as u8 ^ (i / 300) as u8)
+            .collect::<Vec<_>>();
+        let compressed = compress(&data);
+        assert!(compressed.len() < data.len());
+        assert_eq!(
+            decompress(&compressed, data.len()).expect("valid data"),
+            data
+        );
+        // bytes above 127 are negative in the checksum
+        let compressed = compress(&[200, 201]);
+        assert_eq!(
+            compressed[compressed.len() - 4..],
+            (-56i32 - 55).to_le_bytes()
+        );
+    }
+}
@@ -1,14 +1,18 @@
-use std::io::Read;
+use std::{
+    borrow::Cow,
+    io::{Error, ErrorKind, Read},
+};
 
 use byteorder::{LittleEndian, ReadBytesExt};
-use texpresso::Format;
+
+use crate::{decode::decode, lzss, PaXType};
 
 #[derive(Debug)]
 pub struct MipMap {
     width: u16,
     height: u16,
     data: Vec<u8>,
-    format: Format,
+    format: PaXType,
 }
 
 impl MipMap {
@@ -16,7 +20,7 @@ impl MipMap {
     ///
     /// # Errors
     /// [`std::io::Error`] if the input is not readable, or the `MipMap` is invalid
-    pub fn from_stream<I: Read>(format: Format, stream: &mut I) -> Result<Self, std::io::Error> {
+    pub fn from_stream<I: Read>(format: PaXType, stream: &mut I) -> Result<Self, Error> {
         let width = stream.read_u16::<LittleEndian>()?;
         let height = stream.read_u16::<LittleEndian>()?;
         let length = stream.read_u24::<LittleEndian>()?;
@@ -60,49 +64,37 @@ impl MipMap {
 
     #[must_use]
     /// Get the format of the `MipMap`
-    pub const fn format(&self) -> &Format {
+    pub const fn format(&self) -> &PaXType {
         &self.format
     }
 
-    #[must_use]
     /// Get the image from the `MipMap`
     ///
-    /// # Panics
-    /// Panics if the `MipMap` is invalid
-    pub fn get_image(&self) -> image::DynamicImage {
-        let data = &*self.data;
-        let mut width_2 = self.width;
-        let compress = if width_2 % 32768 == width_2 {
-            false
-        } else {
-            width_2 -= 32768;
-            true
-        };
-        let mut img_size: u32 = u32::from(width_2) * u32::from(self.height);
-        if self.format == Format::Bc1 {
-            img_size /= 2;
-        }
-        let mut buffer: Box<[u8]> = vec![0; img_size as usize].into_boxed_slice();
-        let mut out_buffer = vec![0u8; 4 * (width_2 as usize) * (self.height as usize)];
-        if compress {
-            let _ = hemtt_lzo::decompress_to_slice(data, &mut buffer);
-            self.format.decompress(
-                &buffer,
-                usize::from(width_2),
-                usize::from(self.height),
-                &mut out_buffer,
-            );
+    /// DXT data is decompressed with LZO when the `MipMap` is compressed,
+    /// other formats are decompressed with LZSS unless the data is raw pixels
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the data can not be decompressed, or is too short for the `MipMap`
+    pub fn get_image(&self) -> Result<image::DynamicImage, Error> {
+        let width = self.width();
+        let size = self.format.data_size(width, self.height);
+        let data = if self.is_compressed() {
+            let mut buffer = vec![0; size];
+            hemtt_lzo::decompress_to_slice(&self.data, &mut buffer)
+                .map_err(|e| Error::new(ErrorKind::InvalidData, format!("lzo error: {e:?}")))?;
+            Cow::Owned(buffer)
+        } else if self.format.is_dxt() || self.data.len() == size {
+            Cow::Borrowed(&self.data)
         } else {
-            self.format.decompress(
-                data,
-                usize::from(width_2),
-                usize::from(self.height),
-                &mut out_buffer,
-            );
+            Cow::Owned(lzss::decompress(&self.data, size)?)
         };
-        image::DynamicImage::ImageRgba8(
-            image::RgbaImage::from_raw(u32::from(width_2), u32::from(self.height), out_buffer)
-                .expect("paa should contain valid image data"),
-        )
+        decode(&self.format, width, self.height, &data)
+            .map(image::DynamicImage::ImageRgba8)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    "mipmap data is too short for its dimensions",
+                )
+            })
     }
 }
@@ -6,26 +6,28 @@ use std::{
 };
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
-use image::{EncodableLayout, RgbaImage};
-use texpresso::{Format, Params};
+use image::RgbaImage;
 
-use crate::{MipMap, PaXType};
-
-use std::collections::HashMap;
+use crate::{
+    encode::encode,
+    lzss,
+    tagg::{AlphaFlag, Color, Swizzle, Tagg},
+    MipMap, PaXType,
+};
 
 #[derive(Debug)]
 pub struct Paa {
     format: PaXType,
-    taggs: HashMap<String, Vec<u8>>,
+    taggs: Vec<Tagg>,
     maps: Vec<MipMap>,
 }
 
 impl Paa {
     #[must_use]
-    pub fn new(format: PaXType) -> Self {
+    pub const fn new(format: PaXType) -> Self {
         Self {
             format,
-            taggs: HashMap::new(),
+            taggs: Vec::new(),
             maps: Vec::new(),
         }
     }
@@ -38,10 +40,64 @@ impl Paa {
 
     #[must_use]
     /// Get the taggs of the Paa
-    pub const fn taggs(&self) -> &HashMap<String, Vec<u8>> {
+    pub fn taggs(&self) -> &[Tagg] {
         &self.taggs
     }
 
+    #[must_use]
+    /// Get the average color, from the `AVGC` tagg
+    pub fn average_color(&self) -> Option<Color> {
+        self.taggs.iter().find_map(|t| match t {
+            Tagg::AverageColor(color) => Some(*color),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// Get the maximum color, from the `MAXC` tagg
+    pub fn max_color(&self) -> Option<Color> {
+        self.taggs.iter().find_map(|t| match t {
+            Tagg::MaxColor(color) => Some(*color),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// Get how the alpha channel is used, from the `FLAG` tagg
+    pub fn flag(&self) -> Option<AlphaFlag> {
+        self.taggs.iter().find_map(|t| match t {
+            Tagg::Flag(flag) => Some(*flag),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// Get the channel swizzle, from the `SWIZ` tagg
+    pub fn swizzle(&self) -> Option<Swizzle> {
+        self.taggs.iter().find_map(|t| match t {
+            Tagg::Swizzle(swizzle) => Some(*swizzle),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// Get the offsets of the mipmaps, from the `OFFS` tagg
+    pub fn offsets(&self) -> Option<&[u32]> {
+        self.taggs.iter().find_map(|t| match t {
+            Tagg::Offsets(offsets) => Some(offsets.as_slice()),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// Get the expression of a procedural texture, from the `PROC` tagg
+    pub fn procedural(&self) -> Option<&str> {
+        self.taggs.iter().find_map(|t| match t {
+            Tagg::Procedural(expression) => Some(expression.as_str()),
+            _ => None,
+        })
+    }
+
     #[must_use]
     /// Get the maps of the Paa
     pub const fn maps(&self) -> &Vec<MipMap> {
@@ -61,37 +117,19 @@ impl Paa {
                 input.read_exact(&mut tagg_sig)?;
                 std::str::from_utf8(&tagg_sig).map_or(false, |ts| ts == "GGAT")
             } {
-                let name = {
-                    let mut bytes = [0; 4];
-                    input.read_exact(&mut bytes)?;
-                    std::str::from_utf8(&bytes)
-                        .map_err(|_| {
-                            std::io::Error::new(
-                                std::io::ErrorKind::InvalidData,
-                                "invalid tagg name",
-                            )
-                        })?
-                        .to_string()
-                };
-                paa.taggs.insert(name, {
-                    let mut buffer: Box<[u8]> =
-                        vec![0; (input.read_u32::<LittleEndian>()?) as usize].into_boxed_slice();
-                    input.read_exact(&mut buffer)?;
-                    buffer.to_vec()
-                });
+                let mut name = [0; 4];
+                input.read_exact(&mut name)?;
+                let mut data: Box<[u8]> =
+                    vec![0; (input.read_u32::<LittleEndian>()?) as usize].into_boxed_slice();
+                input.read_exact(&mut data)?;
+                paa.taggs.push(Tagg::from_raw(name, data.to_vec())?);
             }
             // Read MipMaps
-            if let Some(offs) = paa.taggs.get("SFFO") {
-                for i in 0..(offs.len() / 4) {
-                    let mut seek: [u8; 4] = [0; 4];
-                    let p = i * 4;
-                    seek.clone_from_slice(&offs[p..p + 4]);
-                    let seek = u32::from_le_bytes(seek);
-                    if seek != 0 {
-                        input.seek(SeekFrom::Start(u64::from(seek)))?;
-                        paa.maps
-                            .push(MipMap::from_stream(paa.format.clone().into(), &mut input)?);
-                    }
+            if let Some(offsets) = paa.offsets().map(<[u32]>::to_vec) {
+                for offset in offsets.into_iter().filter(|o| *o != 0) {
+                    input.seek(SeekFrom::Start(u64::from(offset)))?;
+                    paa.maps
+                        .push(MipMap::from_stream(paa.format.clone(), &mut input)?);
                 }
             }
             Ok(paa)
@@ -103,81 +141,147 @@ impl Paa {
         }
     }
 
-    /// Write the Paa to the given output
+    /// Write an image to the given output as DXT5
     ///
     /// # Errors
-    /// [`std::io::Error`] if the output is not writable
+    /// [`std::io::Error`] if the output is not writable, or the image can not be written
     pub fn write(image: &RgbaImage, output: &mut impl Write) -> Result<(), Error> {
-        let algo: Format = PaXType::DXT5.clone().into();
-        output.write_all(&PaXType::DXT5.as_bytes())?; // 2
-
-        // Average Color
-        output.write_all(b"GGATAVGC")?; // 8
-        output.write_u32::<LittleEndian>(size_of::<u32>() as u32)?; // 4
-        let avg_color = image
-            .pixels()
-            .map(|p| {
-                [
-                    u32::from(p.0[0]),
-                    u32::from(p.0[1]),
-                    u32::from(p.0[2]),
-                    u32::from(p.0[3]),
-                ]
-            })
-            .fold([0, 0, 0, 0], |mut acc, p| {
-                acc[0] += p[0];
-                acc[1] += p[1];
-                acc[2] += p[2];
-                acc[3] += p[3];
-                acc
-            });
-        output.write_u32::<LittleEndian>(u32::from_le_bytes([
-            (avg_color[0] / (image.width() * image.height())) as u8,
-            (avg_color[1] / (image.width() * image.height())) as u8,
-            (avg_color[2] / (image.width() * image.height())) as u8,
-            (avg_color[3] / (image.width() * image.height())) as u8,
-        ]))?; // 4
-
-        // Max Color
-        output.write_all(b"GGATCXAM")?; // 8
-        output.write_u32::<LittleEndian>(size_of::<u32>() as u32)?; // 4
-        let max_color = image
-            .pixels()
-            .map(|p| p.0)
-            .fold([0, 0, 0, 0], |mut acc, p| {
-                acc[0] = acc[0].max(p[0]);
-                acc[1] = acc[1].max(p[1]);
-                acc[2] = acc[2].max(p[2]);
-                acc[3] = acc[3].max(p[3]);
-                acc
-            });
-        output.write_u32::<LittleEndian>(u32::from_le_bytes(max_color))?; // 4
-
-        // Offset Table
-        output.write_all(b"GGATSFFO")?; // 8
-        output.write_u32::<LittleEndian>(16 * size_of::<u32>() as u32)?; // 4
-        let pos = 2 + 8 + 4 + 4 + 8 + 4 + 4 + 8 + 4 + (16 * 4);
-        output.write_u32::<LittleEndian>(pos)?; // 4
-        for _ in 0..15 {
-            // 15 * 4
-            output.write_u32::<LittleEndian>(0)?;
+        Self::write_as(image, &PaXType::DXT5, output)
+    }
+
+    /// Write an image to the given output in the given format,
+    /// with a chain of mipmaps down to 4x4
+    ///
+    /// DXT mipmaps are LZO compressed when it makes them smaller,
+    /// mipmaps of other formats are always LZSS compressed
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the output is not writable, the format can not be written,
+    /// or the image's dimensions are not powers of two
+    pub fn write_as(
+        image: &RgbaImage,
+        format: &PaXType,
+        output: &mut impl Write,
+    ) -> Result<(), Error> {
+        let (width, height) = image.dimensions();
+        if !width.is_power_of_two() || !height.is_power_of_two() || width < 4 || height < 4 {
+            return Err(Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("dimensions must be powers of two, and at least 4x4, not {width}x{height}"),
+            ));
+        }
+        if width >= 0x8000 || height >= 0x8000 {
+            return Err(Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("dimensions must be less than 32768, not {width}x{height}"),
+            ));
         }
 
-        // Write main image
-        output.write_u16::<LittleEndian>(image.width() as u16)?;
-        output.write_u16::<LittleEndian>(image.height() as u16)?;
-        let size = algo.compressed_size(image.width() as usize, image.height() as usize);
-        output.write_u24::<LittleEndian>(size as u32)?;
-        let mut buffer = vec![0; size];
-        algo.compress(
-            image.as_bytes(),
-            image.width() as usize,
-            image.height() as usize,
-            Params::default(),
-            &mut buffer,
-        );
-        output.write_all(&buffer)?;
+        let mut maps = Vec::new();
+        for level in mipmaps(image) {
+            let Some(data) = encode(format, &level) else {
+                return Err(Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    format!("writing {format} is not supported"),
+                ));
+            };
+            let mut width = level.width() as u16;
+            let data = if format.is_dxt() {
+                let mut compressed = Vec::with_capacity(hemtt_lzo::worst_compress(data.len()));
+                hemtt_lzo::compress(&data, &mut compressed)
+                    .map_err(|e| Error::other(e.to_string()))?;
+                if compressed.len() < data.len() {
+                    width |= 0x8000;
+                    compressed
+                } else {
+                    data
+                }
+            } else {
+                lzss::compress(&data)
+            };
+            if data.len() > 0xFF_FFFF {
+                return Err(Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "mipmap is too large for the PAA format",
+                ));
+            }
+            maps.push((width, level.height() as u16, data));
+        }
+
+        let mut taggs = vec![
+            Tagg::AverageColor(Color::new(average_color(image))),
+            Tagg::MaxColor(Color::new(max_color(image))),
+        ];
+        if image.pixels().any(|p| p.0[3] != u8::MAX) {
+            taggs.push(Tagg::Flag(AlphaFlag::Interpolated));
+        }
+
+        // type, taggs, offset tagg, palette
+        let header_size =
+            2 + taggs.iter().map(Tagg::size).sum::<usize>() + 12 + 16 * size_of::<u32>() + 2;
+        let mut offsets = vec![0u32; 16];
+        let mut pos = header_size;
+        for (offset, (_, _, data)) in offsets.iter_mut().zip(&maps) {
+            *offset = pos as u32;
+            pos += 2 + 2 + 3 + data.len();
+        }
+
+        output.write_all(&format.as_bytes())?;
+        taggs.push(Tagg::Offsets(offsets));
+        for tagg in &taggs {
+            tagg.write(output)?;
+        }
+        // Palette
+        output.write_u16::<LittleEndian>(0)?;
+
+        for (width, height, data) in &maps {
+            output.write_u16::<LittleEndian>(*width)?;
+            output.write_u16::<LittleEndian>(*height)?;
+            output.write_u24::<LittleEndian>(data.len() as u32)?;
+            output.write_all(data)?;
+        }
+        // Terminator
+        output.write_all(&[0; 6])?;
 
         Ok(())
     }
 }
+
+/// The image and its smaller versions, halving until a side is 4 pixels
+fn mipmaps(image: &RgbaImage) -> Vec<RgbaImage> {
+    let mut maps = vec![image.clone()];
+    loop {
+        let last = maps.last().expect("at least the original image");
+        if last.width() <= 4 || last.height() <= 4 {
+            break;
+        }
+        let next = image::imageops::resize(
+            last,
+            last.width() / 2,
+            last.height() / 2,
+            image::imageops::FilterType::Triangle,
+        );
+        maps.push(next);
+    }
+    maps
+}
+
+fn average_color(image: &RgbaImage) -> [u8; 4] {
+    let sum = image.pixels().fold([0u64; 4], |mut acc, p| {
+        for (acc, c) in acc.iter_mut().zip(p.0) {
+            *acc += u64::from(c);
+        }
+        acc
+    });
+    let count = u64::from(image.width()) * u64::from(image.height());
+    sum.map(|c| (c / count) as u8)
+}
+
+fn max_color(image: &RgbaImage) -> [u8; 4] {
+    image.pixels().fold([0; 4], |mut acc, p| {
+        for (acc, c) in acc.iter_mut().zip(p.0) {
+            *acc = (*acc).max(c);
+        }
+        acc
+    })
+}
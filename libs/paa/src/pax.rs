@@ -1,4 +1,4 @@
-use std::{fmt::Display, io::Read};
+use std::{fmt::Display, io::Read, str::FromStr};
 
 use texpresso::Format;
 
@@ -34,7 +34,7 @@ impl PaXType {
             [4, 255] => Some(Self::DXT4),    // 0x04FF
             [5, 255] => Some(Self::DXT5),    // 0x05FF
             [68, 68] => Some(Self::RGBA4),   // 0x4444
-            [21, 85] => Some(Self::RGBA5),   // 0x1555
+            [85, 21] => Some(Self::RGBA5),   // 0x5515
             [136, 136] => Some(Self::RGBA8), // 0x8888
             [128, 128] => Some(Self::GRAYA), // 0x8080
             _ => None,
@@ -50,20 +50,92 @@ impl PaXType {
             Self::DXT4 => [4, 255],
             Self::DXT5 => [5, 255],
             Self::RGBA4 => [68, 68],
-            Self::RGBA5 => [21, 85],
+            Self::RGBA5 => [85, 21],
             Self::RGBA8 => [136, 136],
             Self::GRAYA => [128, 128],
         }
     }
 }
 
-impl From<PaXType> for Format {
-    fn from(pax: PaXType) -> Self {
+impl PaXType {
+    #[must_use]
+    /// Is the format block compressed
+    pub const fn is_dxt(&self) -> bool {
+        matches!(
+            self,
+            Self::DXT1 | Self::DXT2 | Self::DXT3 | Self::DXT4 | Self::DXT5
+        )
+    }
+
+    #[must_use]
+    /// The size of the uncompressed data of a mipmap
+    ///
+    /// DXT formats are stored in whole 4x4 blocks, so mipmaps smaller than a block still use a full block
+    pub const fn data_size(&self, width: u16, height: u16) -> usize {
+        let pixels = width as usize * height as usize;
+        let blocks = (width as usize).div_ceil(4) * (height as usize).div_ceil(4);
+        match self {
+            Self::DXT1 => blocks * 8,
+            Self::DXT2 | Self::DXT3 | Self::DXT4 | Self::DXT5 => blocks * 16,
+            Self::RGBA4 | Self::RGBA5 | Self::GRAYA => pixels * 2,
+            Self::RGBA8 => pixels * 4,
+        }
+    }
+
+    #[must_use]
+    /// The format to use for a texture, based on the suffix of its file name
+    ///
+    /// - `_ca`, `_nohq` and `_smdi` use DXT5
+    /// - `_co` and any other texture use DXT1, or DXT5 if the image has transparency
+    pub fn suggested(filename: &str, has_alpha: bool) -> Self {
+        match texture_suffix(filename).as_str() {
+            "ca" | "nohq" | "smdi" => Self::DXT5,
+            _ if has_alpha => Self::DXT5,
+            _ => Self::DXT1,
+        }
+    }
+}
+
+/// The lowercase suffix of a texture's file name, `co` for `wood_co.paa`
+pub fn texture_suffix(filename: &str) -> String {
+    std::path::Path::new(filename)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_lowercase())
+        .and_then(|stem| stem.rsplit_once('_').map(|(_, s)| s.to_string()))
+        .unwrap_or_default()
+}
+
+impl FromStr for PaXType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "DXT1" => Ok(Self::DXT1),
+            "DXT2" => Ok(Self::DXT2),
+            "DXT3" => Ok(Self::DXT3),
+            "DXT4" => Ok(Self::DXT4),
+            "DXT5" => Ok(Self::DXT5),
+            "RGBA4" | "ARGB4444" => Ok(Self::RGBA4),
+            "RGBA5" | "ARGB1555" => Ok(Self::RGBA5),
+            "RGBA8" | "ARGB8888" => Ok(Self::RGBA8),
+            "GRAYA" | "AI88" => Ok(Self::GRAYA),
+            _ => Err(format!("unknown PAA format `{s}`")),
+        }
+    }
+}
+
+impl TryFrom<PaXType> for Format {
+    type Error = PaXType;
+
+    /// The block compression of a DXT format
+    ///
+    /// The premultiplied DXT2 and DXT4 use the same blocks as DXT3 and DXT5
+    fn try_from(pax: PaXType) -> Result<Self, Self::Error> {
         match pax {
-            PaXType::DXT1 => Self::Bc1,
-            PaXType::DXT3 => Self::Bc2,
-            PaXType::DXT5 => Self::Bc3,
-            _ => unimplemented!(),
+            PaXType::DXT1 => Ok(Self::Bc1),
+            PaXType::DXT2 | PaXType::DXT3 => Ok(Self::Bc2),
+            PaXType::DXT4 | PaXType::DXT5 => Ok(Self::Bc3),
+            _ => Err(pax),
         }
     }
 }
@@ -0,0 +1,253 @@
+use std::{
+    fmt::Display,
+    io::{Error, ErrorKind, Write},
+};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+/// A tagg in the header of a PAA
+///
+/// Names are stored reversed in the file, `AVGC` is written as `CGVA`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tagg {
+    /// `AVGC`, the average color of the texture
+    AverageColor(Color),
+    /// `MAXC`, the maximum of each channel of the texture
+    MaxColor(Color),
+    /// `FLAG`, how the alpha channel is used
+    Flag(AlphaFlag),
+    /// `SWIZ`, where each channel is sourced from
+    Swizzle(Swizzle),
+    /// `OFFS`, the offsets of the mipmaps in the file
+    Offsets(Vec<u32>),
+    /// `PROC`, the expression of a procedural texture
+    Procedural(String),
+    /// Any other tagg
+    Unknown { name: String, data: Vec<u8> },
+}
+
+impl Tagg {
+    /// Parse a tagg from its name, as stored in the file, and its data
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the data is not valid for the tagg
+    pub fn from_raw(stored_name: [u8; 4], data: Vec<u8>) -> Result<Self, Error> {
+        let name = stored_name
+            .iter()
+            .rev()
+            .map(|c| char::from(*c))
+            .collect::<String>();
+        let len = data.len();
+        let invalid = |expected: &str| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("tagg {name} should be {expected}, not {len} bytes"),
+            )
+        };
+        Ok(match name.as_str() {
+            "AVGC" => Self::AverageColor(Color::from_bytes(
+                data.try_into().map_err(|_| invalid("4 bytes"))?,
+            )),
+            "MAXC" => Self::MaxColor(Color::from_bytes(
+                data.try_into().map_err(|_| invalid("4 bytes"))?,
+            )),
+            "FLAG" => Self::Flag(AlphaFlag::from(u32::from_le_bytes(
+                data.try_into().map_err(|_| invalid("4 bytes"))?,
+            ))),
+            "SWIZ" => Self::Swizzle(Swizzle(data.try_into().map_err(|_| invalid("4 bytes"))?)),
+            "OFFS" => {
+                if !data.len().is_multiple_of(4) {
+                    return Err(invalid("a multiple of 4 bytes"));
+                }
+                Self::Offsets(
+                    data.chunks_exact(4)
+                        .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+                        .collect(),
+                )
+            }
+            "PROC" => Self::Procedural(
+                String::from_utf8_lossy(&data)
+                    .trim_end_matches('\0')
+                    .to_string(),
+            ),
+            _ => Self::Unknown { name, data },
+        })
+    }
+
+    #[must_use]
+    /// The name of the tagg, as it is written in documentation
+    pub fn name(&self) -> &str {
+        match self {
+            Self::AverageColor(_) => "AVGC",
+            Self::MaxColor(_) => "MAXC",
+            Self::Flag(_) => "FLAG",
+            Self::Swizzle(_) => "SWIZ",
+            Self::Offsets(_) => "OFFS",
+            Self::Procedural(_) => "PROC",
+            Self::Unknown { name, .. } => name,
+        }
+    }
+
+    #[must_use]
+    /// The data of the tagg, as it is written in the file
+    pub fn data(&self) -> Vec<u8> {
+        match self {
+            Self::AverageColor(color) | Self::MaxColor(color) => color.to_bytes().to_vec(),
+            Self::Flag(flag) => u32::from(*flag).to_le_bytes().to_vec(),
+            Self::Swizzle(swizzle) => swizzle.0.to_vec(),
+            Self::Offsets(offsets) => offsets.iter().flat_map(|o| o.to_le_bytes()).collect(),
+            Self::Procedural(expression) => expression.as_bytes().to_vec(),
+            Self::Unknown { data, .. } => data.clone(),
+        }
+    }
+
+    /// The size of the tagg in the file, including its header
+    pub(crate) fn size(&self) -> usize {
+        12 + self.data().len()
+    }
+
+    /// Write the tagg to the output
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the output is not writable, or the name is not 4 characters
+    pub fn write(&self, output: &mut impl Write) -> Result<(), Error> {
+        let name = self.name().as_bytes();
+        if name.len() != 4 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("tagg name `{}` should be 4 characters", self.name()),
+            ));
+        }
+        let data = self.data();
+        output.write_all(b"GGAT")?;
+        output.write_all(&[name[3], name[2], name[1], name[0]])?;
+        output.write_u32::<LittleEndian>(
+            u32::try_from(data.len()).map_err(|e| Error::new(ErrorKind::InvalidInput, e))?,
+        )?;
+        output.write_all(&data)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A color in a tagg, stored as ARGB in a little endian u32
+pub struct Color([u8; 4]);
+
+impl Color {
+    #[must_use]
+    /// Create a color from its RGBA channels
+    pub const fn new(rgba: [u8; 4]) -> Self {
+        Self(rgba)
+    }
+
+    #[must_use]
+    /// Read a color from the bytes of a tagg
+    pub const fn from_bytes(bytes: [u8; 4]) -> Self {
+        let [b, g, r, a] = bytes;
+        Self([r, g, b, a])
+    }
+
+    #[must_use]
+    /// The bytes of the color in a tagg
+    pub const fn to_bytes(self) -> [u8; 4] {
+        let [r, g, b, a] = self.0;
+        [b, g, r, a]
+    }
+
+    #[must_use]
+    /// The RGBA channels of the color
+    pub const fn rgba(self) -> [u8; 4] {
+        self.0
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [red, green, blue, alpha] = self.0;
+        write!(f, "#{red:02X}{green:02X}{blue:02X}{alpha:02X}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How the alpha channel of the texture is used
+pub enum AlphaFlag {
+    /// The alpha channel is blended
+    Interpolated,
+    /// The alpha channel is a transparency key, without blending
+    Key,
+    Other(u32),
+}
+
+impl From<u32> for AlphaFlag {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Interpolated,
+            2 => Self::Key,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<AlphaFlag> for u32 {
+    fn from(flag: AlphaFlag) -> Self {
+        match flag {
+            AlphaFlag::Interpolated => 1,
+            AlphaFlag::Key => 2,
+            AlphaFlag::Other(value) => value,
+        }
+    }
+}
+
+impl Display for AlphaFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Interpolated => write!(f, "Interpolated"),
+            Self::Key => write!(f, "Key"),
+            Self::Other(value) => write!(f, "Unknown ({value})"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The source of the alpha, red, green and blue channels
+///
+/// Each source is `0..=3` for the A, R, G or B channel, `4..=7` for the inverted
+/// channel, or `8` for a constant of one
+pub struct Swizzle([u8; 4]);
+
+impl Swizzle {
+    #[must_use]
+    /// Create a swizzle from the sources of the alpha, red, green and blue channels
+    pub const fn new(sources: [u8; 4]) -> Self {
+        Self(sources)
+    }
+
+    #[must_use]
+    /// The sources of the alpha, red, green and blue channels
+    pub const fn sources(self) -> [u8; 4] {
+        self.0
+    }
+
+    #[must_use]
+    /// Does every channel use its own source
+    pub fn is_identity(self) -> bool {
+        self.0 == [0, 1, 2, 3]
+    }
+}
+
+impl Display for Swizzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const CHANNELS: [&str; 4] = ["A", "R", "G", "B"];
+        let sources = self.0.iter().map(|source| match source {
+            0..=3 => CHANNELS[*source as usize].to_string(),
+            4..=7 => format!("1-{}", CHANNELS[*source as usize - 4]),
+            8 => "1".to_string(),
+            _ => format!("?{source}"),
+        });
+        let parts = CHANNELS
+            .iter()
+            .zip(sources)
+            .map(|(channel, source)| format!("{channel}={source}"))
+            .collect::<Vec<_>>();
+        write!(f, "{}", parts.join(" "))
+    }
+}
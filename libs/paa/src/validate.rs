@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+use crate::Paa;
+
+/// Size of the width, height and length that precede the data of a mipmap
+const MIPMAP_HEADER: u32 = 2 + 2 + 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A problem found by [`Paa::validate`]
+pub enum Problem {
+    /// There is no `OFFS` tagg, so the engine can not find the mipmaps
+    MissingOffsets,
+    /// A mipmap does not start where the previous mipmap ends
+    InconsistentOffset {
+        index: usize,
+        expected: u32,
+        actual: u32,
+    },
+    /// A mipmap is not a power of two in size
+    NotPowerOfTwo {
+        index: usize,
+        width: u16,
+        height: u16,
+    },
+    /// A mipmap is not half the size of the previous mipmap
+    MissingMipLevel {
+        index: usize,
+        expected: (u16, u16),
+        actual: (u16, u16),
+    },
+    /// The smallest mipmap is larger than 4 pixels on both sides
+    IncompleteMipChain { width: u16, height: u16 },
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingOffsets => write!(f, "missing OFFS tagg"),
+            Self::InconsistentOffset {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "mipmap {index} is at offset {actual}, but the previous mipmap ends at {expected}"
+            ),
+            Self::NotPowerOfTwo {
+                index,
+                width,
+                height,
+            } => write!(
+                f,
+                "mipmap {index} is {width}x{height}, which is not a power of two"
+            ),
+            Self::MissingMipLevel {
+                index,
+                expected: (expected_width, expected_height),
+                actual: (width, height),
+            } => write!(
+                f,
+                "mipmap {index} is {width}x{height}, expected {expected_width}x{expected_height}"
+            ),
+            Self::IncompleteMipChain { width, height } => write!(
+                f,
+                "the smallest mipmap is {width}x{height}, the chain should continue until a side is 4"
+            ),
+        }
+    }
+}
+
+impl Paa {
+    #[must_use]
+    /// Check the mipmaps and their offsets for problems
+    pub fn validate(&self) -> Vec<Problem> {
+        let mut problems = Vec::new();
+        match self.offsets() {
+            None => problems.push(Problem::MissingOffsets),
+            Some(offsets) => {
+                let offsets = offsets.iter().filter(|o| **o != 0).collect::<Vec<_>>();
+                for (index, (pair, previous)) in offsets
+                    .windows(2)
+                    .zip(self.maps())
+                    .enumerate()
+                    .map(|(i, p)| (i + 1, p))
+                {
+                    let expected = previous
+                        .data()
+                        .len()
+                        .try_into()
+                        .map_or(u32::MAX, |len: u32| {
+                            pair[0].saturating_add(MIPMAP_HEADER + len)
+                        });
+                    if *pair[1] != expected {
+                        problems.push(Problem::InconsistentOffset {
+                            index,
+                            expected,
+                            actual: *pair[1],
+                        });
+                    }
+                }
+            }
+        }
+        for (index, map) in self.maps().iter().enumerate() {
+            let (width, height) = (map.width(), map.height());
+            if !width.is_power_of_two() || !height.is_power_of_two() {
+                problems.push(Problem::NotPowerOfTwo {
+                    index,
+                    width,
+                    height,
+                });
+            }
+            if let Some(previous) = index.checked_sub(1).map(|i| &self.maps()[i]) {
+                let expected = (
+                    (previous.width() / 2).max(1),
+                    (previous.height() / 2).max(1),
+                );
+                if (width, height) != expected {
+                    problems.push(Problem::MissingMipLevel {
+                        index,
+                        expected,
+                        actual: (width, height),
+                    });
+                }
+            }
+        }
+        if let Some(last) = self.maps().last() {
+            if last.width() > 4 && last.height() > 4 {
+                problems.push(Problem::IncompleteMipChain {
+                    width: last.width(),
+                    height: last.height(),
+                });
+            }
+        }
+        problems
+    }
+}
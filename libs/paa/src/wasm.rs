@@ -35,6 +35,7 @@ impl ImageResult {
         let mut buffer = Cursor::new(Vec::new());
         paa.maps()[0]
             .get_image()
+            .expect("Failed to decode PAA")
             .write_to(&mut buffer, image::ImageFormat::Png)
             .expect("Failed to write PNG");
 
@@ -0,0 +1,82 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+
+use hemtt_common::config::{PDriveOption, ProjectConfig};
+use hemtt_paa::{analyze::lint_one, PaXType, Paa};
+use hemtt_workspace::reporting::Codes;
+use image::{Rgba, RgbaImage};
+
+fn paa(width: u32, height: u32, format: &PaXType) -> Vec<u8> {
+    let image = RgbaImage::from_pixel(width, height, Rgba([64, 128, 192, 128]));
+    let mut buffer = Vec::new();
+    Paa::write_as(&image, format, &mut buffer).unwrap();
+    buffer
+}
+
+/// The position of the offsets in the `OFFS` tagg
+fn offsets(buffer: &[u8]) -> usize {
+    buffer.windows(4).position(|w| w == b"SFFO").unwrap() + 8
+}
+
+fn lint(name: &str, buffer: Vec<u8>, project: Option<&ProjectConfig>) -> Vec<String> {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .memory()
+        .finish(None, false, &PDriveOption::Disallow)
+        .unwrap();
+    let path = workspace.join(name).unwrap();
+    let paa = Paa::read(Cursor::new(buffer)).unwrap();
+    let codes: Codes = lint_one(&(paa, path), project);
+    codes.iter().map(|c| c.ident().to_string()).collect()
+}
+
+#[test]
+fn clean() {
+    assert!(lint("wood_co.paa", paa(64, 64, &PaXType::DXT1), None).is_empty());
+    assert!(lint("flag_ca.paa", paa(64, 64, &PaXType::DXT5), None).is_empty());
+    assert!(lint("body_nohq.paa", paa(64, 64, &PaXType::DXT5), None).is_empty());
+}
+
+#[test]
+fn not_power_of_two() {
+    let mut buffer = paa(64, 64, &PaXType::RGBA8);
+    let first = offsets(&buffer);
+    let first = u32::from_le_bytes(buffer[first..first + 4].try_into().unwrap()) as usize;
+    buffer[first..first + 2].copy_from_slice(&60u16.to_le_bytes());
+    assert!(lint("wood_co.paa", buffer, None).contains(&"L-T01".to_string()));
+}
+
+#[test]
+fn ca_without_alpha() {
+    assert_eq!(
+        lint("flag_ca.paa", paa(64, 64, &PaXType::DXT1), None),
+        vec!["L-T02"]
+    );
+}
+
+#[test]
+fn nohq_dxt1() {
+    assert_eq!(
+        lint("body_nohq.paa", paa(64, 64, &PaXType::DXT1), None),
+        vec!["L-T03"]
+    );
+}
+
+#[test]
+fn missing_mipmaps() {
+    let mut buffer = paa(64, 64, &PaXType::DXT1);
+    // keep only the first mipmap
+    let start = offsets(&buffer) + 4;
+    buffer[start..start + 15 * 4].fill(0);
+    assert_eq!(lint("wood_co.paa", buffer, None), vec!["L-T04"]);
+}
+
+#[test]
+fn max_resolution() {
+    let project = ProjectConfig::from_file(std::path::Path::new("tests/project.toml")).unwrap();
+    assert!(lint("wood_co.paa", paa(64, 64, &PaXType::DXT1), None).is_empty());
+    assert_eq!(
+        lint("wood_co.paa", paa(64, 64, &PaXType::DXT1), Some(&project)),
+        vec!["L-T05"]
+    );
+}
@@ -1,7 +1,6 @@
 #![allow(clippy::unwrap_used)]
 
 use hemtt_paa::PaXType;
-use texpresso::Format;
 
 #[test]
 fn read_dxt1() {
@@ -9,15 +8,16 @@ fn read_dxt1() {
     let paa = hemtt_paa::Paa::read(file).unwrap();
     assert_eq!(paa.format(), &PaXType::DXT1);
     assert_eq!(paa.taggs().len(), 3);
-    assert!(paa.taggs().contains_key(&"SFFO".to_string()));
-    assert!(paa.taggs().contains_key(&"CGVA".to_string()));
-    assert!(paa.taggs().contains_key(&"CXAM".to_string()));
+    assert!(paa.offsets().is_some());
+    assert!(paa.average_color().is_some());
+    assert!(paa.max_color().is_some());
+    assert!(paa.flag().is_none());
     let mipmap = &paa.maps()[0];
     assert_eq!(mipmap.width(), 512);
     assert!(mipmap.is_compressed());
-    assert_eq!(mipmap.format(), &Format::Bc1);
+    assert_eq!(mipmap.format(), &PaXType::DXT1);
     assert_eq!(mipmap.data().len(), 4716);
-    let _ = paa.maps()[0].get_image();
+    paa.maps()[0].get_image().unwrap();
 }
 
 #[test]
@@ -26,14 +26,123 @@ fn read_dxt5() {
     let paa = hemtt_paa::Paa::read(file).unwrap();
     assert_eq!(paa.format(), &PaXType::DXT5);
     assert_eq!(paa.taggs().len(), 4);
-    assert!(paa.taggs().contains_key(&"SFFO".to_string()));
-    assert!(paa.taggs().contains_key(&"CGVA".to_string()));
-    assert!(paa.taggs().contains_key(&"CXAM".to_string()));
-    assert!(paa.taggs().contains_key(&"GALF".to_string()));
+    assert!(paa.offsets().is_some());
+    assert!(paa.average_color().is_some());
+    assert!(paa.max_color().is_some());
+    assert!(paa.flag().is_some());
     let mipmap = &paa.maps()[0];
     assert_eq!(mipmap.width(), 64);
     assert!(!mipmap.is_compressed());
-    assert_eq!(mipmap.format(), &Format::Bc3);
+    assert_eq!(mipmap.format(), &PaXType::DXT5);
     assert_eq!(mipmap.data().len(), 4096);
-    let _ = paa.maps()[0].get_image();
+    paa.maps()[0].get_image().unwrap();
+}
+
+/// Read a PAA and compare every pixel of the first mipmap to the source image,
+/// allowing for the precision of the format
+///
+/// The uncompressed fixtures were not written by `Paa::write_as`, but by a standalone
+/// encoder following the community documentation of the format. They are not `ImageToPAA`
+/// or `TexView` output, and should be replaced by files from those tools when they are available
+fn read_uncompressed(file: &str, format: &PaXType, tolerance: u8, gray: bool) {
+    let paa = hemtt_paa::Paa::read(std::fs::File::open(file).unwrap()).unwrap();
+    assert_eq!(paa.format(), format);
+    assert_eq!(paa.maps().len(), 3);
+    let mipmap = &paa.maps()[0];
+    assert_eq!(mipmap.format(), format);
+    assert!(!mipmap.is_compressed());
+    // the data is LZSS compressed
+    assert_ne!(mipmap.data().len(), format.data_size(16, 16));
+
+    let source = image::open("tests/source.png").unwrap().to_rgba8();
+    let image = mipmap.get_image().unwrap().to_rgba8();
+    assert_eq!(image.dimensions(), (16, 16));
+    for (actual, expected) in image.pixels().zip(source.pixels()) {
+        let [r, g, b, a] = expected.0;
+        let expected = if gray {
+            let luma = (u32::from(r) * 299 + u32::from(g) * 587 + u32::from(b) * 114) / 1000;
+            let luma = u8::try_from(luma).unwrap();
+            [luma, luma, luma, a]
+        } else {
+            expected.0
+        };
+        for (actual, expected) in actual.0.iter().zip(expected) {
+            assert!(
+                actual.abs_diff(expected) <= tolerance,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+    for map in paa.maps() {
+        assert_eq!(map.get_image().unwrap().width(), u32::from(map.width()));
+    }
+}
+
+#[test]
+fn read_rgba8() {
+    read_uncompressed("tests/rgba8.paa", &PaXType::RGBA8, 0, false);
+}
+
+#[test]
+fn read_rgba4() {
+    read_uncompressed("tests/rgba4.paa", &PaXType::RGBA4, 17, false);
+}
+
+#[test]
+fn read_rgba5() {
+    // alpha is a single bit
+    let paa = hemtt_paa::Paa::read(std::fs::File::open("tests/rgba5.paa").unwrap()).unwrap();
+    assert_eq!(paa.format(), &PaXType::RGBA5);
+    let source = image::open("tests/source.png").unwrap().to_rgba8();
+    let image = paa.maps()[0].get_image().unwrap().to_rgba8();
+    for (actual, expected) in image.pixels().zip(source.pixels()) {
+        for i in 0..3 {
+            assert!(actual.0[i].abs_diff(expected.0[i]) <= 8);
+        }
+        assert_eq!(actual.0[3], if expected.0[3] >= 128 { 255 } else { 0 });
+    }
+}
+
+#[test]
+fn read_graya() {
+    read_uncompressed("tests/graya.paa", &PaXType::GRAYA, 0, true);
+}
+
+#[test]
+fn read_corrupt_lzss() {
+    let mut data = std::fs::read("tests/rgba8.paa").unwrap();
+    let paa = hemtt_paa::Paa::read(std::io::Cursor::new(data.clone())).unwrap();
+    // the last byte of the first mipmap is part of its checksum
+    let offset = paa.offsets().unwrap()[0] as usize;
+    let end = offset + 7 + paa.maps()[0].data().len();
+    data[end - 1] ^= 0xFF;
+    let paa = hemtt_paa::Paa::read(std::io::Cursor::new(data)).unwrap();
+    assert!(paa.maps()[0].get_image().is_err());
+}
+
+#[test]
+fn read_small_dxt_mipmaps() {
+    for (format, block) in [(PaXType::DXT1, 8), (PaXType::DXT5, 16)] {
+        for (width, height) in [(1u16, 1u16), (2, 2), (4, 2), (6, 6)] {
+            let blocks = usize::from(width.div_ceil(4)) * usize::from(height.div_ceil(4));
+            assert_eq!(format.data_size(width, height), blocks * block);
+            let data = vec![0u8; blocks * block];
+            let mut compressed = Vec::with_capacity(hemtt_lzo::worst_compress(data.len()));
+            hemtt_lzo::compress(&data, &mut compressed).unwrap();
+            for (flag, data) in [(0, &data), (0x8000, &compressed)] {
+                let mut stream = Vec::new();
+                stream.extend_from_slice(&(width | flag).to_le_bytes());
+                stream.extend_from_slice(&height.to_le_bytes());
+                stream.extend_from_slice(&u32::try_from(data.len()).unwrap().to_le_bytes()[..3]);
+                stream.extend_from_slice(data);
+                let mipmap =
+                    hemtt_paa::MipMap::from_stream(format.clone(), &mut stream.as_slice()).unwrap();
+                let image = mipmap.get_image().unwrap();
+                assert_eq!(
+                    (image.width(), image.height()),
+                    (u32::from(width), u32::from(height))
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,159 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+
+use hemtt_paa::{AlphaFlag, Color, PaXType, Paa, Problem, Tagg};
+use image::{Rgba, RgbaImage};
+
+fn gradient(width: u32, height: u32, alpha: u8) -> RgbaImage {
+    RgbaImage::from_fn(width, height, |x, y| {
+        Rgba([
+            u8::try_from(x * 255 / width).unwrap(),
+            u8::try_from(y * 255 / height).unwrap(),
+            128,
+            alpha,
+        ])
+    })
+}
+
+#[test]
+fn write_dxt1_mipmaps() {
+    let image = gradient(64, 32, 255);
+    let mut buffer = Vec::new();
+    Paa::write_as(&image, &PaXType::DXT1, &mut buffer).unwrap();
+
+    let paa = Paa::read(Cursor::new(buffer)).unwrap();
+    assert_eq!(paa.format(), &PaXType::DXT1);
+    assert!(paa.flag().is_none());
+    let sizes = paa
+        .maps()
+        .iter()
+        .map(|m| (m.width(), m.height()))
+        .collect::<Vec<_>>();
+    assert_eq!(sizes, vec![(64, 32), (32, 16), (16, 8), (8, 4)]);
+    for map in paa.maps() {
+        let decoded = map.get_image().unwrap();
+        assert_eq!(decoded.width(), u32::from(map.width()));
+    }
+    let decoded = paa.maps()[0].get_image().unwrap().to_rgba8();
+    let pixel = decoded.get_pixel(40, 20);
+    let expected = image.get_pixel(40, 20);
+    for (a, b) in pixel.0.iter().zip(expected.0) {
+        assert!(a.abs_diff(b) < 16, "{pixel:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn write_dxt5_compressed() {
+    // A solid image compresses well, so the mipmaps are stored with LZO
+    let image = RgbaImage::from_pixel(256, 256, Rgba([10, 20, 30, 40]));
+    let mut buffer = Vec::new();
+    Paa::write_as(&image, &PaXType::DXT5, &mut buffer).unwrap();
+
+    let paa = Paa::read(Cursor::new(buffer)).unwrap();
+    assert_eq!(paa.format(), &PaXType::DXT5);
+    assert_eq!(paa.flag(), Some(AlphaFlag::Interpolated));
+    assert_eq!(paa.maps().len(), 7);
+    assert!(paa.maps()[0].is_compressed());
+    assert_eq!(paa.average_color(), Some(Color::new([10, 20, 30, 40])));
+    assert_eq!(paa.max_color(), Some(Color::new([10, 20, 30, 40])));
+    assert!(paa.validate().is_empty());
+    let decoded = paa.maps()[0].get_image().unwrap().to_rgba8();
+    assert_eq!(decoded.dimensions(), (256, 256));
+    assert_eq!(decoded.get_pixel(128, 128).0[3], 40);
+}
+
+#[test]
+fn write_invalid_size() {
+    let mut buffer = Vec::new();
+    assert!(Paa::write_as(&gradient(100, 64, 255), &PaXType::DXT1, &mut buffer).is_err());
+    assert!(Paa::write_as(&gradient(2, 2, 255), &PaXType::DXT1, &mut buffer).is_err());
+    assert!(Paa::write_as(&gradient(4, 4, 255), &PaXType::DXT2, &mut buffer).is_err());
+}
+
+#[test]
+fn suggested_format() {
+    assert_eq!(PaXType::suggested("wood_co.png", false), PaXType::DXT1);
+    assert_eq!(PaXType::suggested("wood_co.png", true), PaXType::DXT5);
+    assert_eq!(PaXType::suggested("glass_ca.tga", false), PaXType::DXT5);
+    assert_eq!(PaXType::suggested("wood_NOHQ.png", false), PaXType::DXT5);
+    assert_eq!(
+        PaXType::suggested("data/wood_smdi.png", false),
+        PaXType::DXT5
+    );
+    assert_eq!(PaXType::suggested("icon.png", false), PaXType::DXT1);
+}
+
+#[test]
+fn write_uncompressed_round_trip() {
+    let image = gradient(64, 64, 90);
+    for format in [
+        PaXType::RGBA4,
+        PaXType::RGBA5,
+        PaXType::RGBA8,
+        PaXType::GRAYA,
+    ] {
+        let mut buffer = Vec::new();
+        Paa::write_as(&image, &format, &mut buffer).unwrap();
+        let paa = Paa::read(Cursor::new(buffer)).unwrap();
+        assert_eq!(paa.format(), &format);
+        assert_eq!(paa.maps().len(), 5);
+        let decoded = paa.maps()[0].get_image().unwrap().to_rgba8();
+        assert_eq!(decoded.dimensions(), (64, 64));
+        if format == PaXType::RGBA8 {
+            assert_eq!(decoded, image);
+        }
+    }
+}
+
+#[test]
+fn tagg_round_trip() {
+    let taggs = [
+        Tagg::AverageColor(Color::new([1, 2, 3, 4])),
+        Tagg::Flag(AlphaFlag::Key),
+        Tagg::Swizzle(hemtt_paa::Swizzle::new([0, 5, 2, 8])),
+        Tagg::Offsets(vec![100, 200, 0]),
+        Tagg::Procedural("color(1,1,1,1)".to_string()),
+    ];
+    for tagg in taggs {
+        let mut buffer = Vec::new();
+        tagg.write(&mut buffer).unwrap();
+        assert_eq!(&buffer[..4], b"GGAT");
+        let name = [buffer[4], buffer[5], buffer[6], buffer[7]];
+        let read = Tagg::from_raw(name, buffer[12..].to_vec()).unwrap();
+        assert_eq!(read, tagg);
+    }
+    // colors are stored as ARGB in a little endian u32
+    let tagg = Tagg::from_raw(*b"CGVA", vec![3, 2, 1, 4]).unwrap();
+    assert_eq!(tagg, Tagg::AverageColor(Color::new([1, 2, 3, 4])));
+    assert!(Tagg::from_raw(*b"CGVA", vec![0; 3]).is_err());
+    assert_eq!(
+        hemtt_paa::Swizzle::new([0, 5, 2, 8]).to_string(),
+        "A=A R=1-R G=G B=1"
+    );
+}
+
+#[test]
+fn validate_problems() {
+    let mut buffer = Vec::new();
+    Paa::write_as(&gradient(32, 32, 255), &PaXType::DXT1, &mut buffer).unwrap();
+    assert!(Paa::read(Cursor::new(buffer.clone()))
+        .unwrap()
+        .validate()
+        .is_empty());
+
+    // drop the 16x16 mipmap by removing its offset
+    let offs = buffer.windows(8).position(|w| w == b"GGATSFFO").unwrap() + 12;
+    let table = buffer[offs + 8..offs + 64].to_vec();
+    buffer[offs + 4..offs + 60].copy_from_slice(&table);
+    buffer[offs + 60..offs + 64].copy_from_slice(&[0; 4]);
+    let problems = Paa::read(Cursor::new(buffer)).unwrap().validate();
+    assert!(problems
+        .iter()
+        .any(|p| matches!(p, Problem::InconsistentOffset { index: 1, .. })));
+    assert!(problems.contains(&Problem::MissingMipLevel {
+        index: 1,
+        expected: (16, 16),
+        actual: (8, 8),
+    }));
+}
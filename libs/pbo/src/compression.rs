@@ -0,0 +1,63 @@
+use std::path::Path;
+
+use crate::error::Error;
+
+/// File extensions that are read directly by the engine and must be stored raw
+const NEVER_COMPRESS: [&str; 14] = [
+    "bin", "bisurf", "jpg", "lip", "ogg", "p3d", "paa", "pac", "png", "rtm", "tga", "wav", "wrp",
+    "wss",
+];
+
+#[must_use]
+/// Can a file be stored compressed in a PBO
+///
+/// Binary assets that the engine streams directly from the PBO are never compressed
+pub fn is_compressible(filename: &str) -> bool {
+    Path::new(&filename.replace('\\', "/"))
+        .extension()
+        .and_then(std::ffi::OsStr::to_str)
+        .is_none_or(|ext| !NEVER_COMPRESS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// Compress the data of an entry
+///
+/// Returns `None` if compressing would not make the entry smaller
+pub fn compress(data: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+    if data.is_empty() {
+        return Ok(None);
+    }
+    let mut output = Vec::with_capacity(hemtt_lzo::worst_compress(data.len()));
+    hemtt_lzo::compress(data, &mut output)?;
+    if output.len() >= data.len() {
+        return Ok(None);
+    }
+    Ok(Some(output))
+}
+
+/// Decompress the data of an entry
+pub fn decompress(data: &[u8], original: u32) -> Result<Vec<u8>, Error> {
+    let mut output = vec![0; original as usize];
+    let size = hemtt_lzo::decompress_to_slice(data, &mut output)?.len();
+    if size != original as usize {
+        return Err(Error::DecompressedSizeMismatch {
+            expected: original,
+            actual: size,
+        });
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_compressible;
+
+    #[test]
+    fn compressible() {
+        assert!(is_compressible("script.sqf"));
+        assert!(is_compressible("data\\mission.SQM"));
+        assert!(is_compressible("$PBOPREFIX$"));
+        assert!(!is_compressible("data\\texture_co.paa"));
+        assert!(!is_compressible("data/model.P3D"));
+        assert!(!is_compressible("config.bin"));
+    }
+}
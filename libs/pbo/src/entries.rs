@@ -0,0 +1,199 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::{Cursor, Write},
+};
+
+use hemtt_common::io::WriteExt;
+use indexmap::IndexMap;
+
+use crate::{compression, error::Error, model::Header, WritePbo};
+
+/// The files and properties of a PBO that is being built
+///
+/// Shared by [`crate::WritablePbo`] and [`crate::StreamingPbo`], which only differ
+/// in how the data of each file is provided when the PBO is written
+pub struct Entries<S> {
+    properties: IndexMap<String, String>,
+    files: HashMap<String, (S, Header)>,
+    compress: HashSet<String>,
+}
+
+/// Everything needed to write the file data of a PBO
+pub struct Layout {
+    /// The properties, the file headers, and the terminating header
+    pub headers: Vec<u8>,
+    /// The file headers, in the order their data is written
+    pub files: Vec<Header>,
+    /// The data of the files that were compressed, by filename
+    pub compressed: HashMap<String, Vec<u8>>,
+}
+
+impl<S> Default for Entries<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Entries<S> {
+    pub fn new() -> Self {
+        Self {
+            properties: IndexMap::new(),
+            files: HashMap::new(),
+            compress: HashSet::new(),
+        }
+    }
+
+    pub fn add_file(
+        &mut self,
+        name: &str,
+        source: S,
+        size: u64,
+    ) -> Result<Option<(S, Header)>, Error> {
+        let name = name.replace('/', "\\");
+        if size > u32::MAX as u64 {
+            return Err(Error::FileTooLarge);
+        }
+        self.compress.remove(&name);
+        Ok(self.files.insert(
+            name.clone(),
+            (source, Header::new_for_file(name, size as u32)),
+        ))
+    }
+
+    pub fn add_file_with_header(&mut self, header: Header, source: S) -> Option<(S, Header)> {
+        let name = header.filename().replace('/', "\\");
+        self.compress.remove(&name);
+        self.files.insert(name, (source, header))
+    }
+
+    pub fn add_file_compressed(
+        &mut self,
+        name: &str,
+        source: S,
+        size: u64,
+    ) -> Result<Option<(S, Header)>, Error> {
+        let name = name.replace('/', "\\");
+        let existing = self.add_file(&name, source, size)?;
+        self.compress.insert(name);
+        Ok(existing)
+    }
+
+    pub fn is_compressed(&self, name: &str) -> bool {
+        self.compress.contains(&name.replace('/', "\\"))
+    }
+
+    pub fn source(&self, name: &str) -> Option<&S> {
+        self.files
+            .get(&name.replace('/', "\\"))
+            .map(|(source, _)| source)
+    }
+
+    pub fn source_mut(&mut self, name: &str) -> Option<&mut S> {
+        self.files
+            .get_mut(&name.replace('/', "\\"))
+            .map(|(source, _)| source)
+    }
+
+    pub fn files(&self) -> Vec<Header> {
+        self.files.values().map(|(_, h)| h.clone()).collect()
+    }
+
+    pub fn files_sorted(&self) -> Vec<Header> {
+        let mut sorted = self.files();
+        sorted.sort_by(|a, b| {
+            a.filename()
+                .to_lowercase()
+                .cmp(&b.filename().to_lowercase())
+        });
+        sorted
+    }
+
+    pub fn add_property(&mut self, key: String, value: &str) -> Option<String> {
+        self.properties
+            .insert(key, value.trim_matches('\\').to_string())
+    }
+
+    pub fn remove_property(&mut self, key: &str) -> Option<String> {
+        self.properties.swap_remove(key)
+    }
+
+    pub fn property(&self, key: &str) -> Option<&String> {
+        self.properties.get(key)
+    }
+
+    pub const fn properties(&self) -> &IndexMap<String, String> {
+        &self.properties
+    }
+
+    /// Compress the files marked for compression and build the header block
+    ///
+    /// `read` is called with the source of each file that is marked for compression,
+    /// and must return all of its data
+    pub fn layout<F>(&mut self, properties: bool, mut read: F) -> Result<Layout, Error>
+    where
+        F: FnMut(&mut S, &Header) -> Result<Vec<u8>, Error>,
+    {
+        let mut compressed = HashMap::new();
+        let mut files = Vec::with_capacity(self.files.len());
+        for header in self.files_sorted() {
+            if !self.compress.contains(header.filename()) {
+                files.push(header);
+                continue;
+            }
+            let (source, _) = self
+                .files
+                .get_mut(header.filename())
+                .expect("file with header should exist");
+            let buffer = read(source, &header)?;
+            if let Some(data) = compression::compress(&buffer)? {
+                let header = header.into_compressed(data.len() as u32);
+                compressed.insert(header.filename().to_string(), data);
+                files.push(header);
+            } else {
+                files.push(header);
+            }
+        }
+
+        let headers = header_block(properties.then_some(&self.properties), &files)?;
+
+        Ok(Layout {
+            headers,
+            files,
+            compressed,
+        })
+    }
+}
+
+/// Build everything before the file data: the properties, the file headers, and the terminating header
+fn header_block(
+    properties: Option<&IndexMap<String, String>>,
+    files: &[Header],
+) -> Result<Vec<u8>, Error> {
+    let mut headers: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    if let Some(properties) = properties {
+        Header::property().write_pbo(&mut headers)?;
+
+        if let Some(prefix) = properties.get("prefix") {
+            headers.write_cstring(b"prefix")?;
+            headers.write_cstring(prefix)?;
+        }
+
+        for (key, value) in properties {
+            if key == "prefix" {
+                continue;
+            }
+            headers.write_cstring(key.as_bytes())?;
+            headers.write_cstring(value.as_bytes())?;
+        }
+
+        headers.write_all(&[0])?;
+    }
+
+    for header in files {
+        header.write_pbo(&mut headers)?;
+    }
+
+    Header::default().write_pbo(&mut headers)?;
+
+    Ok(headers.into_inner())
+}
@@ -6,10 +6,31 @@ pub enum Error {
     #[error("IO Error: {0}")]
     /// [`std::io::Error`]
     Io(#[from] std::io::Error),
+    #[error("LZO Error: {0}")]
+    /// [`hemtt_lzo::LzoError`]
+    Lzo(#[from] hemtt_lzo::LzoError),
 
     #[error("HEMTT does not support the encountered PBO Mime type: {0}")]
     /// HEMTT does not support the encountered PBO Mime type
     UnsupportedMime(u32),
+    #[error("Decompressed entry is {actual} bytes, expected {expected} bytes")]
+    /// A compressed entry did not decompress to its original size
+    DecompressedSizeMismatch {
+        /// The original size stored in the header
+        expected: u32,
+        /// The size after decompression
+        actual: usize,
+    },
+    #[error("`{file}` is {actual} bytes, expected {expected} bytes")]
+    /// A streamed file did not provide the size it was added with
+    StreamSizeMismatch {
+        /// The file name in the PBO
+        file: String,
+        /// The size the file was added with
+        expected: u32,
+        /// The number of bytes read
+        actual: u64,
+    },
     #[error("Unexpected data after PBO checksum")]
     /// Unexpected data after PBO checksum
     UnexpectedDataAfterChecksum,
@@ -1,33 +1,59 @@
 //! File abstraction for reading from a PBO file.
 
-use std::io::Read;
+use std::io::{Cursor, Read};
 
 use crate::model::Header;
 
 /// A file in a PBO
 pub struct File<'a, I: Read> {
-    size: u32,
-    read: u32,
-    input: &'a mut I,
+    source: Source<'a, I>,
+}
+
+enum Source<'a, I: Read> {
+    /// Streamed from the PBO as it is stored
+    Stored {
+        size: u32,
+        read: u32,
+        input: &'a mut I,
+    },
+    /// Decompressed into memory
+    Decompressed(Cursor<Vec<u8>>),
 }
 
 impl<'a, I: Read> File<'a, I> {
     /// Create a new file from a header and a reader
+    ///
+    /// The data is read as it is stored in the PBO
     pub fn new(header: &Header, input: &'a mut I) -> Self {
         Self {
-            size: header.size(),
-            read: 0,
-            input,
+            source: Source::Stored {
+                size: header.size(),
+                read: 0,
+                input,
+            },
+        }
+    }
+
+    #[must_use]
+    /// Create a new file from data that has already been decompressed
+    pub const fn decompressed(data: Vec<u8>) -> Self {
+        Self {
+            source: Source::Decompressed(Cursor::new(data)),
         }
     }
 }
 
 impl<I: Read> Read for File<'_, I> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        // read up to the size of the file
-        let size = std::cmp::min(self.size - self.read, buf.len() as u32);
-        let read = self.input.read(&mut buf[..size as usize])?;
-        self.read += read as u32;
-        Ok(read)
+        match &mut self.source {
+            Source::Stored { size, read, input } => {
+                // read up to the size of the file
+                let size = std::cmp::min(*size - *read, buf.len() as u32);
+                let count = input.read(&mut buf[..size as usize])?;
+                *read += count as u32;
+                Ok(count)
+            }
+            Source::Decompressed(data) => data.read(buf),
+        }
     }
 }
@@ -11,16 +11,21 @@
 
 use std::io::{Read, Write};
 
+mod compression;
+mod entries;
 mod error;
 pub mod file;
 mod model;
 mod read;
+mod stream;
 pub mod tests;
 mod write;
 
+pub use compression::is_compressible;
 pub use error::Error;
 pub use model::{Checksum, Header, Mime};
 pub use read::ReadablePbo;
+pub use stream::StreamingPbo;
 pub use write::WritablePbo;
 
 // Re-exported from common
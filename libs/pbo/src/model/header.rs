@@ -28,6 +28,19 @@ impl Header {
         }
     }
 
+    #[must_use]
+    /// Mark the header as compressed, storing `size` bytes
+    ///
+    /// The current size is kept as the original size
+    pub fn into_compressed(self, size: u32) -> Self {
+        Self {
+            mime: Mime::Cprs,
+            original: self.size,
+            size,
+            ..self
+        }
+    }
+
     #[must_use]
     /// Create a new header for an property
     pub fn property() -> Self {
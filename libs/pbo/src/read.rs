@@ -1,11 +1,17 @@
-use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    io::{Cursor, Read, Seek, SeekFrom, Write},
+};
 
 use byteorder::ReadBytesExt;
 use hemtt_common::io::{ReadExt, WriteExt};
 use indexmap::IndexMap;
+use memmap2::Mmap;
 use sha1::{Digest, Sha1};
 
 use crate::{
+    compression,
     error::Error,
     file::File,
     model::{Checksum, Header, Mime},
@@ -17,9 +23,10 @@ pub struct ReadablePbo<I: Seek + Read> {
     properties: IndexMap<String, String>,
     vers_header: Option<Header>,
     headers: Vec<Header>,
+    /// Lowercase filename => (offset, index into headers)
+    index: HashMap<String, (u64, usize)>,
     checksum: Checksum,
     input: I,
-    blob_start: u64,
 }
 
 impl<I: Seek + Read> ReadablePbo<I> {
@@ -54,11 +61,16 @@ impl<I: Seek + Read> ReadablePbo<I> {
             }
         }
 
-        for header in &headers {
-            input.seek(SeekFrom::Current(i64::from(header.size())))?;
+        let mut index = HashMap::with_capacity(headers.len());
+        let mut offset = blob_start;
+        for (i, header) in headers.iter().enumerate() {
+            index
+                .entry(header.filename().to_lowercase())
+                .or_insert((offset, i));
+            offset += u64::from(header.size());
         }
 
-        input.seek(SeekFrom::Current(1))?;
+        input.seek(SeekFrom::Start(offset + 1))?;
         let checksum = Checksum::read_pbo(&mut input)?.0;
         if input.read_u8().is_ok() {
             return Err(Error::UnexpectedDataAfterChecksum);
@@ -67,9 +79,9 @@ impl<I: Seek + Read> ReadablePbo<I> {
             properties,
             vers_header,
             headers,
+            index,
             checksum,
             input,
-            blob_start,
         })
     }
 
@@ -80,6 +92,12 @@ impl<I: Seek + Read> ReadablePbo<I> {
             .find(|h| h.filename() == name.replace('/', "\\"))
     }
 
+    fn header_case_insensitive(&self, name: &str) -> Option<&Header> {
+        self.index
+            .get(&index_key(name))
+            .map(|(_, i)| &self.headers[*i])
+    }
+
     /// Get the PBO's properties
     pub const fn properties(&self) -> &IndexMap<String, String> {
         &self.properties
@@ -108,18 +126,42 @@ impl<I: Seek + Read> ReadablePbo<I> {
 
     /// Read a file from the PBO
     ///
+    /// Compressed entries are decompressed, use [`ReadablePbo::file_raw`] to read the stored data
+    ///
     /// # Errors
-    /// if the file cannot be read
-    pub fn file(&mut self, name: &str) -> Result<Option<File<I>>, Error> {
-        self.input.seek(SeekFrom::Start(self.blob_start))?;
-        for header in &self.headers {
-            if header.filename().to_lowercase() == name.replace('/', "\\").to_lowercase() {
-                return Ok(Some(File::new(header, &mut self.input)));
-            }
-            self.input
-                .seek(SeekFrom::Current(i64::from(header.size())))?;
+    /// if the file cannot be read, or a compressed file cannot be decompressed
+    ///
+    /// # Panics
+    /// if a file does not exist, but a header for it does
+    pub fn file(&mut self, name: &str) -> Result<Option<File<'_, I>>, Error> {
+        let Some(header) = self.header_case_insensitive(name).cloned() else {
+            return Ok(None);
+        };
+        if header.mime() != &Mime::Cprs {
+            return self.file_raw(name);
         }
-        Ok(None)
+        let mut data = Vec::with_capacity(header.size() as usize);
+        self.file_raw(name)?
+            .expect("file with header should exist")
+            .read_to_end(&mut data)?;
+        Ok(Some(File::decompressed(compression::decompress(
+            &data,
+            header.original(),
+        )?)))
+    }
+
+    /// Read a file from the PBO as it is stored, without decompressing it
+    ///
+    /// This is the data that is used for checksums and signatures
+    ///
+    /// # Errors
+    /// if the file cannot be read
+    pub fn file_raw(&mut self, name: &str) -> Result<Option<File<'_, I>>, Error> {
+        let Some(&(offset, i)) = self.index.get(&index_key(name)) else {
+            return Ok(None);
+        };
+        self.input.seek(SeekFrom::Start(offset))?;
+        Ok(Some(File::new(&self.headers[i], &mut self.input)))
     }
 
     /// Find the offset of a file
@@ -127,14 +169,7 @@ impl<I: Seek + Read> ReadablePbo<I> {
     /// # Errors
     /// if the file cannot be read
     pub fn file_offset(&self, name: &str) -> Result<Option<u64>, Error> {
-        let mut offset = self.blob_start;
-        for header in &self.headers {
-            if header.filename().to_lowercase() == name.replace('/', "\\").to_lowercase() {
-                return Ok(Some(offset));
-            }
-            offset += header.size() as u64;
-        }
-        Ok(None)
+        Ok(self.index.get(&index_key(name)).map(|(offset, _)| *offset))
     }
 
     /// Check if the files are sorted correctly
@@ -206,7 +241,7 @@ impl<I: Seek + Read> ReadablePbo<I> {
 
         for header in &self.files_sorted() {
             let mut file = self
-                .file(header.filename())?
+                .file_raw(header.filename())?
                 .expect("file with header should exist");
             std::io::copy(&mut file, &mut hasher)?;
         }
@@ -228,7 +263,7 @@ impl<I: Seek + Read> ReadablePbo<I> {
 
         for header in &self.files_sorted() {
             // Skip empty files
-            let Some(mut file) = self.file(header.filename())? else {
+            let Some(mut file) = self.file_raw(header.filename())? else {
                 continue;
             };
             if file.read_u8().is_err() {
@@ -254,7 +289,7 @@ impl<I: Seek + Read> ReadablePbo<I> {
                 continue;
             }
             nothing = false;
-            let Some(mut file) = self.file(header.filename())? else {
+            let Some(mut file) = self.file_raw(header.filename())? else {
                 continue;
             };
             std::io::copy(&mut file, &mut hasher)?;
@@ -267,3 +302,64 @@ impl<I: Seek + Read> ReadablePbo<I> {
         Ok(hasher.finalize().to_vec().into())
     }
 }
+
+impl<T: AsRef<[u8]>> ReadablePbo<Cursor<T>> {
+    /// Get a file from an in-memory PBO as it is stored, without decompressing it
+    ///
+    /// Does not require mutable access, so many files can be read at once
+    pub fn file_raw_slice(&self, name: &str) -> Option<&[u8]> {
+        let &(offset, i) = self.index.get(&index_key(name))?;
+        let start = offset as usize;
+        self.input
+            .get_ref()
+            .as_ref()
+            .get(start..start + self.headers[i].size() as usize)
+    }
+
+    /// Get a file from an in-memory PBO
+    ///
+    /// Compressed entries are decompressed, other entries are borrowed from the PBO.
+    /// Does not require mutable access, so many files can be read at once
+    ///
+    /// # Errors
+    /// if a compressed file cannot be decompressed
+    ///
+    /// # Panics
+    /// if a file has data, but no header
+    pub fn file_slice(&self, name: &str) -> Result<Option<Cow<'_, [u8]>>, Error> {
+        let Some(data) = self.file_raw_slice(name) else {
+            return Ok(None);
+        };
+        let header = self
+            .header_case_insensitive(name)
+            .expect("file with data should have a header");
+        if header.mime() == &Mime::Cprs {
+            Ok(Some(Cow::Owned(compression::decompress(
+                data,
+                header.original(),
+            )?)))
+        } else {
+            Ok(Some(Cow::Borrowed(data)))
+        }
+    }
+}
+
+impl ReadablePbo<Cursor<Mmap>> {
+    /// Read a PBO by memory mapping a file
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated while the PBO is in use,
+    /// by this process or any other, as the mapped data would change underneath it
+    ///
+    /// # Errors
+    /// if the file cannot be mapped or read
+    pub unsafe fn mmap(file: &std::fs::File) -> Result<Self, Error> {
+        // SAFETY: the map is read-only, and the caller guarantees the file is not modified
+        let map = unsafe { Mmap::map(file)? };
+        Self::from(Cursor::new(map))
+    }
+}
+
+fn index_key(name: &str) -> String {
+    name.replace('/', "\\").to_lowercase()
+}
@@ -0,0 +1,195 @@
+use std::io::{BufWriter, Read, Write};
+
+use indexmap::IndexMap;
+use sha1::{Digest, Sha1};
+
+use crate::{entries::Entries, error::Error, model::Header, Checksum};
+
+/// A PBO that streams its files to the output when written
+///
+/// Unlike [`crate::WritablePbo`], files are only described by a source and a size when added.
+/// Each source is opened when the PBO is written, and its data is copied to the output
+/// while the checksum is updated, so only one file is open at a time
+/// and files are never held in memory.
+///
+/// Files marked for compression are the exception, they are compressed before
+/// the headers are written, as the compressed size must be known up front.
+pub struct StreamingPbo<S> {
+    entries: Entries<S>,
+}
+
+impl<S> Default for StreamingPbo<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> StreamingPbo<S> {
+    #[must_use]
+    /// Create a new PBO
+    pub fn new() -> Self {
+        Self {
+            entries: Entries::new(),
+        }
+    }
+
+    /// Add a file to the PBO
+    ///
+    /// The source must provide exactly `size` bytes when it is opened
+    ///
+    /// # Errors
+    /// if the file is too large for the PBO format
+    pub fn add_file<N: Into<String>>(
+        &mut self,
+        name: N,
+        source: S,
+        size: u64,
+    ) -> Result<Option<(S, Header)>, Error> {
+        self.entries.add_file(&name.into(), source, size)
+    }
+
+    /// Add a file that will be compressed when the PBO is written
+    ///
+    /// The file is stored raw if compression would not make it smaller
+    ///
+    /// # Errors
+    /// if the file is too large for the PBO format
+    pub fn add_file_compressed<N: Into<String>>(
+        &mut self,
+        name: N,
+        source: S,
+        size: u64,
+    ) -> Result<Option<(S, Header)>, Error> {
+        self.entries.add_file_compressed(&name.into(), source, size)
+    }
+
+    #[must_use]
+    /// Will the file be compressed when the PBO is written
+    pub fn is_compressed(&self, name: &str) -> bool {
+        self.entries.is_compressed(name)
+    }
+
+    /// Get a list of all files in the PBO
+    #[must_use]
+    pub fn files(&self) -> Vec<Header> {
+        self.entries.files()
+    }
+
+    /// Get a list of all files in the PBO sorted by name
+    #[must_use]
+    pub fn files_sorted(&self) -> Vec<Header> {
+        self.entries.files_sorted()
+    }
+
+    /// Add an property to the PBO
+    pub fn add_property<K: Into<String>, V: Into<String>>(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Option<String> {
+        self.entries.add_property(key.into(), &value.into())
+    }
+
+    /// Remove an property from the PBO
+    pub fn remove_property(&mut self, key: &str) -> Option<String> {
+        self.entries.remove_property(key)
+    }
+
+    #[must_use]
+    /// Get an property from the PBO
+    pub fn property(&self, key: &str) -> Option<&String> {
+        self.entries.property(key)
+    }
+
+    #[must_use]
+    /// Get all properties from the PBO
+    pub const fn properties(&self) -> &IndexMap<String, String> {
+        self.entries.properties()
+    }
+
+    /// Write the PBO, opening each source with `open` as its data is needed
+    ///
+    /// # Errors
+    /// if a source cannot be opened or read, if a source does not provide the size
+    /// it was added with, or if the output cannot be written
+    ///
+    /// # Panics
+    /// if a file does not exist but a header is present
+    pub fn write<O, R, F>(
+        &mut self,
+        output: &mut O,
+        properties: bool,
+        mut open: F,
+    ) -> Result<Checksum, Error>
+    where
+        O: Write,
+        R: Read,
+        F: FnMut(&S) -> std::io::Result<R>,
+    {
+        let mut layout = self.entries.layout(properties, |source, header| {
+            let mut buffer = Vec::with_capacity(header.size() as usize);
+            open(source)?.read_to_end(&mut buffer)?;
+            check_size(header, buffer.len() as u64)?;
+            Ok(buffer)
+        })?;
+
+        let mut output = HashingWriter {
+            inner: BufWriter::new(output),
+            hasher: Sha1::new(),
+        };
+        output.write_all(&layout.headers)?;
+
+        for header in &layout.files {
+            if let Some(data) = layout.compressed.remove(header.filename()) {
+                output.write_all(&data)?;
+                continue;
+            }
+            let source = self
+                .entries
+                .source(header.filename())
+                .expect("file with header should exist");
+            // read one byte past the expected size, to detect sources that grew
+            let mut input = open(source)?.take(u64::from(header.size()) + 1);
+            let written = std::io::copy(&mut input, &mut output)?;
+            check_size(header, written)?;
+        }
+
+        let HashingWriter { mut inner, hasher } = output;
+        let checksum = hasher.finalize();
+        inner.write_all(&[0])?;
+        inner.write_all(&checksum)?;
+        inner.flush()?;
+
+        Ok(checksum.to_vec().into())
+    }
+}
+
+fn check_size(header: &Header, actual: u64) -> Result<(), Error> {
+    if actual == u64::from(header.size()) {
+        Ok(())
+    } else {
+        Err(Error::StreamSizeMismatch {
+            file: header.filename().to_string(),
+            expected: header.size(),
+            actual,
+        })
+    }
+}
+
+/// Writes to the output while updating the checksum
+struct HashingWriter<W: Write> {
+    inner: W,
+    hasher: Sha1,
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -1,19 +1,14 @@
-use std::{
-    collections::HashMap,
-    io::{BufWriter, Cursor, Read, Seek, SeekFrom, Write},
-};
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
 
-use hemtt_common::io::WriteExt;
 use indexmap::IndexMap;
 use sha1::{Digest, Sha1};
 
-use crate::{error::Error, model::Header, Checksum, WritePbo};
+use crate::{entries::Entries, error::Error, model::Header, Checksum};
 
 #[derive(Default)]
 /// A PBO file that can be written to
 pub struct WritablePbo<I: Seek + Read> {
-    properties: IndexMap<String, String>,
-    files: HashMap<String, (I, Header)>,
+    entries: Entries<I>,
 }
 
 impl<I: Seek + Read> WritablePbo<I> {
@@ -21,8 +16,7 @@ impl<I: Seek + Read> WritablePbo<I> {
     /// Create a new PBO
     pub fn new() -> Self {
         Self {
-            properties: IndexMap::new(),
-            files: HashMap::new(),
+            entries: Entries::new(),
         }
     }
 
@@ -35,15 +29,8 @@ impl<I: Seek + Read> WritablePbo<I> {
         name: S,
         mut input: I,
     ) -> Result<Option<(I, Header)>, Error> {
-        let name = name.into().replace('/', "\\");
         let size = input.seek(SeekFrom::End(0))?;
-        if size > u32::MAX as u64 {
-            return Err(Error::FileTooLarge);
-        }
-        Ok(self.files.insert(
-            name.clone(),
-            (input, Header::new_for_file(name, size as u32)),
-        ))
+        self.entries.add_file(&name.into(), input, size)
     }
 
     /// Add a file with a custom header
@@ -55,8 +42,28 @@ impl<I: Seek + Read> WritablePbo<I> {
         header: Header,
         input: I,
     ) -> Result<Option<(I, Header)>, Error> {
-        let name = header.filename().replace('/', "\\");
-        Ok(self.files.insert(name, (input, header)))
+        Ok(self.entries.add_file_with_header(header, input))
+    }
+
+    /// Add a file that will be compressed when the PBO is written
+    ///
+    /// The file is stored raw if compression would not make it smaller
+    ///
+    /// # Errors
+    /// if the file cannot be read
+    pub fn add_file_compressed<S: Into<String>>(
+        &mut self,
+        name: S,
+        mut input: I,
+    ) -> Result<Option<(I, Header)>, Error> {
+        let size = input.seek(SeekFrom::End(0))?;
+        self.entries.add_file_compressed(&name.into(), input, size)
+    }
+
+    #[must_use]
+    /// Will the file be compressed when the PBO is written
+    pub fn is_compressed(&self, name: &str) -> bool {
+        self.entries.is_compressed(name)
     }
 
     /// Read a file from the PBO
@@ -64,8 +71,7 @@ impl<I: Seek + Read> WritablePbo<I> {
     /// # Errors
     /// if the file cannot be read
     pub fn file(&mut self, name: &str) -> Result<Option<&mut I>, Error> {
-        let name = name.replace('/', "\\");
-        if let Some((input, _)) = self.files.get_mut(&name) {
+        if let Some(input) = self.entries.source_mut(name) {
             input.rewind()?;
             Ok(Some(input))
         } else {
@@ -76,23 +82,13 @@ impl<I: Seek + Read> WritablePbo<I> {
     /// Get a list of all files in the PBO
     #[must_use]
     pub fn files(&self) -> Vec<Header> {
-        let mut filenames = Vec::new();
-        for (_, h) in self.files.values() {
-            filenames.push(h.clone());
-        }
-        filenames
+        self.entries.files()
     }
 
     /// Get a list of all files in the PBO sorted by name
     #[must_use]
     pub fn files_sorted(&self) -> Vec<Header> {
-        let mut sorted = self.files();
-        sorted.sort_by(|a, b| {
-            a.filename()
-                .to_lowercase()
-                .cmp(&b.filename().to_lowercase())
-        });
-        sorted
+        self.entries.files_sorted()
     }
 
     /// Add an property to the PBO
@@ -101,25 +97,24 @@ impl<I: Seek + Read> WritablePbo<I> {
         key: K,
         value: V,
     ) -> Option<String> {
-        self.properties
-            .insert(key.into(), value.into().trim_matches('\\').to_string())
+        self.entries.add_property(key.into(), &value.into())
     }
 
     /// Remove an property from the PBO
     pub fn remove_property(&mut self, key: &str) -> Option<String> {
-        self.properties.swap_remove(key)
+        self.entries.remove_property(key)
     }
 
     #[must_use]
     /// Get an property from the PBO
     pub fn property(&self, key: &str) -> Option<&String> {
-        self.properties.get(key)
+        self.entries.property(key)
     }
 
     #[must_use]
     /// Get all properties from the PBO
     pub const fn properties(&self) -> &IndexMap<String, String> {
-        &self.properties
+        self.entries.properties()
     }
 
     /// Write the PBO to a file
@@ -134,47 +129,31 @@ impl<I: Seek + Read> WritablePbo<I> {
         output: &mut O,
         properties: bool,
     ) -> Result<Checksum, Error> {
-        let mut headers: Cursor<Vec<u8>> = Cursor::new(Vec::new());
-        if properties {
-            Header::property().write_pbo(&mut headers)?;
-
-            if let Some(prefix) = self.property("prefix") {
-                headers.write_cstring(b"prefix")?;
-                headers.write_cstring(prefix)?;
-            }
-
-            for (key, value) in &self.properties {
-                if key == "prefix" {
-                    continue;
-                }
-                headers.write_cstring(key.as_bytes())?;
-                headers.write_cstring(value.as_bytes())?;
-            }
-
-            headers.write_all(&[0])?;
-        }
-
-        let files_sorted = self.files_sorted();
-
-        for header in &files_sorted {
-            header.write_pbo(&mut headers)?;
-        }
-
-        Header::default().write_pbo(&mut headers)?;
+        let mut layout = self.entries.layout(properties, |input, header| {
+            input.rewind()?;
+            let mut buffer = Vec::with_capacity(header.size() as usize);
+            input.read_to_end(&mut buffer)?;
+            Ok(buffer)
+        })?;
 
         let mut hasher = Sha1::new();
 
         let mut buffered_output = BufWriter::new(output);
 
-        buffered_output.write_all(headers.get_ref())?;
-        hasher.update(headers.get_ref());
+        buffered_output.write_all(&layout.headers)?;
+        hasher.update(&layout.headers);
 
-        for header in &files_sorted {
-            let file = self
-                .file(header.filename())?
-                .expect("file with header should exist");
-            let mut buffer = Vec::with_capacity(header.size() as usize);
-            file.read_to_end(&mut buffer)?;
+        for header in &layout.files {
+            let buffer = if let Some(data) = layout.compressed.remove(header.filename()) {
+                data
+            } else {
+                let file = self
+                    .file(header.filename())?
+                    .expect("file with header should exist");
+                let mut buffer = Vec::with_capacity(header.size() as usize);
+                file.read_to_end(&mut buffer)?;
+                buffer
+            };
 
             if header.size() > 1_000_000 {
                 // pay the paralellization cost for large files
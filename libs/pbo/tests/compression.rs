@@ -0,0 +1,73 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::{Cursor, Read};
+
+use hemtt_pbo::{Mime, ReadablePbo, WritablePbo};
+
+#[test]
+fn write_compressed() {
+    let script = "#include \"script_component.hpp\"\r\n".repeat(64);
+    let texture = vec![0u8; 2048];
+
+    let mut pbo = WritablePbo::new();
+    pbo.add_property("prefix", "z\\test\\addons\\main");
+    pbo.add_file_compressed("fnc_test.sqf", Cursor::new(script.as_bytes()))
+        .unwrap();
+    pbo.add_file("tiny.sqf", Cursor::new(b"x".as_slice()))
+        .unwrap();
+    pbo.add_file("texture_co.paa", Cursor::new(texture.as_slice()))
+        .unwrap();
+    assert!(pbo.is_compressed("fnc_test.sqf"));
+    assert!(!pbo.is_compressed("texture_co.paa"));
+
+    let mut output = Cursor::new(Vec::new());
+    let checksum = pbo.write(&mut output, true).unwrap();
+    output.set_position(0);
+
+    let mut pbo = ReadablePbo::from(output).unwrap();
+    assert_eq!(pbo.checksum(), &checksum);
+    assert_eq!(pbo.gen_checksum().unwrap(), checksum);
+
+    let header = pbo.header("fnc_test.sqf").unwrap().clone();
+    assert_eq!(header.mime(), &Mime::Cprs);
+    assert_eq!(header.original() as usize, script.len());
+    assert!(header.size() < header.original());
+
+    let mut stored = Vec::new();
+    pbo.file_raw("fnc_test.sqf")
+        .unwrap()
+        .unwrap()
+        .read_to_end(&mut stored)
+        .unwrap();
+    assert_eq!(stored.len(), header.size() as usize);
+    let mut buffer = vec![0; header.original() as usize];
+    let data = hemtt_lzo::decompress_to_slice(&stored, &mut buffer).unwrap();
+    assert_eq!(data, script.as_bytes());
+
+    let mut data = String::new();
+    pbo.file("fnc_test.sqf")
+        .unwrap()
+        .unwrap()
+        .read_to_string(&mut data)
+        .unwrap();
+    assert_eq!(data, script);
+
+    let header = pbo.header("texture_co.paa").unwrap();
+    assert_eq!(header.mime(), &Mime::Blank);
+    assert_eq!(header.size() as usize, texture.len());
+}
+
+#[test]
+fn write_compressed_not_smaller() {
+    let mut pbo = WritablePbo::new();
+    pbo.add_file_compressed("tiny.sqf", Cursor::new(b"x".as_slice()))
+        .unwrap();
+    let mut output = Cursor::new(Vec::new());
+    pbo.write(&mut output, true).unwrap();
+    output.set_position(0);
+
+    let pbo = ReadablePbo::from(output).unwrap();
+    let header = pbo.header("tiny.sqf").unwrap();
+    assert_eq!(header.mime(), &Mime::Blank);
+    assert_eq!(header.size(), 1);
+}
@@ -0,0 +1,44 @@
+#![allow(clippy::unwrap_used)]
+
+use std::{fs::File, io::Read};
+
+use hemtt_pbo::ReadablePbo;
+
+#[test]
+fn random_access() {
+    let mut pbo = ReadablePbo::from(File::open("tests/ace_weather.pbo_cba6f72c").unwrap()).unwrap();
+    // SAFETY: the fixture is not modified by any test
+    let mapped =
+        unsafe { ReadablePbo::mmap(&File::open("tests/ace_weather.pbo_cba6f72c").unwrap()) }
+            .unwrap();
+    assert_eq!(pbo.files().len(), mapped.files().len());
+
+    // read in reverse to make sure nothing depends on the order of access
+    for header in pbo.files().iter().rev() {
+        let mut data = Vec::new();
+        pbo.file(header.filename())
+            .unwrap()
+            .unwrap()
+            .read_to_end(&mut data)
+            .unwrap();
+        assert_eq!(data.len(), header.size() as usize);
+        assert_eq!(
+            mapped
+                .file_slice(header.filename())
+                .unwrap()
+                .unwrap()
+                .as_ref(),
+            data.as_slice()
+        );
+        assert_eq!(
+            mapped.file_raw_slice(&header.filename().to_uppercase()),
+            Some(data.as_slice())
+        );
+        assert_eq!(
+            pbo.file_offset(header.filename()).unwrap(),
+            mapped.file_offset(header.filename()).unwrap()
+        );
+    }
+    assert!(mapped.file_slice("not_real").unwrap().is_none());
+    assert!(mapped.file_raw_slice("not_real").is_none());
+}
@@ -0,0 +1,89 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+
+use hemtt_pbo::{Error, Mime, ReadablePbo, StreamingPbo, WritablePbo};
+
+fn files() -> Vec<(&'static str, Vec<u8>)> {
+    vec![
+        (
+            "fnc_test.sqf",
+            "#include \"script_component.hpp\"\r\n"
+                .repeat(64)
+                .into_bytes(),
+        ),
+        ("data\\texture_co.paa", vec![7u8; 4096]),
+        ("config.cpp", b"class CfgPatches {};".to_vec()),
+    ]
+}
+
+#[test]
+fn streaming_matches_writable() {
+    let mut writable = WritablePbo::new();
+    let mut streaming = StreamingPbo::new();
+    writable.add_property("prefix", "z\\test\\addons\\main");
+    streaming.add_property("prefix", "z\\test\\addons\\main");
+    for (name, data) in files() {
+        if name == "fnc_test.sqf" {
+            writable
+                .add_file_compressed(name, Cursor::new(data.clone()))
+                .unwrap();
+            streaming
+                .add_file_compressed(name, data.clone(), data.len() as u64)
+                .unwrap();
+        } else {
+            writable.add_file(name, Cursor::new(data.clone())).unwrap();
+            streaming
+                .add_file(name, data.clone(), data.len() as u64)
+                .unwrap();
+        }
+    }
+
+    let mut expected = Vec::new();
+    let expected_checksum = writable.write(&mut expected, true).unwrap();
+    let mut output = Vec::new();
+    let checksum = streaming
+        .write(&mut output, true, |data: &Vec<u8>| {
+            Ok(Cursor::new(data.clone()))
+        })
+        .unwrap();
+    assert_eq!(checksum, expected_checksum);
+    assert_eq!(output, expected);
+
+    let mut pbo = ReadablePbo::from(Cursor::new(output)).unwrap();
+    assert_eq!(pbo.checksum(), &checksum);
+    assert_eq!(pbo.gen_checksum().unwrap(), checksum);
+    assert_eq!(pbo.header("fnc_test.sqf").unwrap().mime(), &Mime::Cprs);
+}
+
+#[test]
+fn streaming_size_mismatch() {
+    let mut pbo = StreamingPbo::new();
+    pbo.add_file("grew.txt", b"longer than expected".to_vec(), 4)
+        .unwrap();
+    let result = pbo.write(&mut Vec::new(), true, |data: &Vec<u8>| {
+        Ok(Cursor::new(data.clone()))
+    });
+    assert!(matches!(
+        result,
+        Err(Error::StreamSizeMismatch {
+            expected: 4,
+            actual: 5,
+            ..
+        })
+    ));
+
+    let mut pbo = StreamingPbo::new();
+    pbo.add_file("shrank.txt", b"short".to_vec(), 100).unwrap();
+    let result = pbo.write(&mut Vec::new(), true, |data: &Vec<u8>| {
+        Ok(Cursor::new(data.clone()))
+    });
+    assert!(matches!(
+        result,
+        Err(Error::StreamSizeMismatch {
+            expected: 100,
+            actual: 5,
+            ..
+        })
+    ));
+}
@@ -40,10 +40,10 @@ const BUILTIN_GEN: [&str; 6] = [
 ];
 
 /// Built-in macros that HEMTT supports, special cases
-const BUILTIN_SPECIAL: [&str; 1] = ["__EVAL"];
+const BUILTIN_SPECIAL: [&str; 2] = ["__EVAL", "__EXEC"];
 
 /// Built-in macros that HEMTT intentionally does not support
-const BUILTIN_PROTEST: [&str; 18] = [
+const BUILTIN_PROTEST: [&str; 17] = [
     "__DATE_ARR__",
     "__DATE_STR__",
     "__DATE_STR_ISO8601__",
@@ -61,7 +61,6 @@ const BUILTIN_PROTEST: [&str; 18] = [
     "__GAME_BUILD__",
     "__A3_DIAG__",
     "__A3_DEBUG__",
-    "__EXEC",
 ];
 
 impl Defines {
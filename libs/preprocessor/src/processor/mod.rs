@@ -8,9 +8,9 @@ use hemtt_workspace::{
 };
 use peekmore::{PeekMore, PeekMoreIterator};
 
+use crate::codes::pe18_eoi_ifstate::EoiIfState;
 use crate::codes::pe3_expected_ident::ExpectedIdent;
 use crate::codes::pw2_invalid_config_case::InvalidConfigCase;
-use crate::codes::{pe18_eoi_ifstate::EoiIfState, pe25_exec::ExecNotSupported};
 use crate::codes::{
     pe26_unsupported_builtin::BuiltInNotSupported, pe2_unexpected_eof::UnexpectedEOF,
 };
@@ -153,9 +153,6 @@ impl Processor {
         while let Some(token) = stream.peek() {
             match (token.symbol(), in_quotes) {
                 (Symbol::Word(w), false) => {
-                    if w == "__EXEC" {
-                        return Err(ExecNotSupported::code((**token).clone()));
-                    }
                     if Defines::is_unsupported_builtin(w) {
                         return Err(BuiltInNotSupported::code((**token).clone()));
                     }
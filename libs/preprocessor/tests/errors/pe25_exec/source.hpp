@@ -1,3 +0,0 @@
-class MyClass {
-    value = __EXEC(call something);
-};
@@ -21,6 +21,10 @@ pub enum Error {
     /// No authority was provided
     AuthorityMissing,
 
+    #[error("Invalid key: {0}")]
+    /// The parts of a key do not belong together
+    InvalidKey(String),
+
     #[error("Invalid file sorting")]
     /// The files in the PBO are not sorted
     InvalidFileSorting,
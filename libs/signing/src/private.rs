@@ -62,6 +62,12 @@ impl BIPrivateKey {
         })
     }
 
+    #[must_use]
+    /// Returns the authority of the key.
+    pub fn authority(&self) -> &str {
+        &self.authority
+    }
+
     #[must_use]
     /// Returns the public key for this private key.
     pub fn to_public_key(&self) -> BIPublicKey {
@@ -151,6 +157,39 @@ impl BIPrivateKey {
         })
     }
 
+    /// Check that the parts of the key belong together, and can sign and verify.
+    ///
+    /// # Errors
+    /// If the key is not valid.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.authority.is_empty() {
+            return Err(Error::AuthorityMissing);
+        }
+        if self.length as usize != self.n.bits() {
+            return Err(Error::InvalidKey(format!(
+                "length is {} bits, but the modulus is {} bits",
+                self.length,
+                self.n.bits()
+            )));
+        }
+        if &self.p * &self.q != self.n {
+            return Err(Error::InvalidKey(
+                "modulus is not the product of its primes".to_string(),
+            ));
+        }
+        let message = BigUint::from(0x4845_4d54_u32);
+        if message
+            .modpow(&self.d, &self.n)
+            .modpow(&self.exponent, &self.n)
+            != message
+        {
+            return Err(Error::InvalidKey(
+                "private exponent does not match the public exponent".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Sign a PBO.
     ///
     /// # Errors
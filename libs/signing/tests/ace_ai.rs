@@ -101,3 +101,23 @@ fn read_signature() {
         "b735a7d0b7736b5a2102\n  04e7ee5702ce4428701a\n  206b2f7ed55e12dbc3db\n  1350b2caf8e05a19a7ff\n  cb0118752412b398814a\n  65fbaffe115c87aafd84\n  848086bfae0ee2fea6e3\n  186ffbe5b100e9c26b31\n  5100a2b929c2873fb519\n  4cdb48884fef406e5005\n  1ba08a85b35d6dfc1d4a\n  5e3302ef7adaa765c5b1\n  2ef2e965e71cfaf1"
     );
 }
+
+#[test]
+fn validate() {
+    let file = PathBuf::from("tests/ace_ai_3.15.2.69");
+    let private =
+        BIPrivateKey::read(&mut File::open(file.join("test.biprivatekey")).unwrap()).unwrap();
+    private.validate().unwrap();
+
+    BIPrivateKey::generate(1024, "generated")
+        .unwrap()
+        .validate()
+        .unwrap();
+
+    // Corrupt the private exponent
+    let mut buffer = std::fs::read(file.join("test.biprivatekey")).unwrap();
+    let last = buffer.len() - 1;
+    buffer[last] ^= 0xFF;
+    let corrupt = BIPrivateKey::read(&mut buffer.as_slice()).unwrap();
+    assert!(corrupt.validate().is_err());
+}
@@ -104,6 +104,42 @@ impl Statements {
         }
         (command, version, span)
     }
+
+    #[must_use]
+    /// Gets the stringtable keys passed to `localize` and `isLocalized` in this code chunk.
+    ///
+    /// Keys built with `format`, such as `localize format ["STR_abe_main_%1", _name]`, are returned as the template.
+    pub fn localizations(&self) -> Vec<(Arc<str>, Range<usize>)> {
+        self.content
+            .iter()
+            .flat_map(Statement::walk_expressions)
+            .filter_map(|expression| match expression {
+                Expression::UnaryCommand(UnaryCommand::Named(command), argument, _)
+                    if command.eq_ignore_ascii_case("localize")
+                        || command.eq_ignore_ascii_case("islocalized") =>
+                {
+                    match &**argument {
+                        Expression::String(key, span, _) => Some((key.clone(), span.clone())),
+                        Expression::UnaryCommand(UnaryCommand::Named(command), arguments, _)
+                            if command.eq_ignore_ascii_case("format") =>
+                        {
+                            let Expression::Array(arguments, _) = &**arguments else {
+                                return None;
+                            };
+                            match arguments.first() {
+                                Some(Expression::String(template, span, _)) => {
+                                    Some((template.clone(), span.clone()))
+                                }
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
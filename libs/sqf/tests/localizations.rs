@@ -0,0 +1,54 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Write;
+
+use hemtt_preprocessor::Processor;
+use hemtt_sqf::parser::database::Database;
+
+#[test]
+fn localizations() {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .memory()
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let source = workspace.join("localizations.sqf").unwrap();
+    source
+        .create_file()
+        .unwrap()
+        .write_all(
+            br#"hint localize "STR_abe_main_car";
+if (isLocalized "STR_abe_main_old_car") then {
+    systemChat localize format ["STR_abe_main_%1", _name];
+};
+systemChat "STR_abe_main_not_a_reference";
+"#,
+        )
+        .unwrap();
+    let processed = Processor::run(&source).unwrap();
+    // The commands are added in case the wiki is not available
+    let mut database = Database::a3(false);
+    for command in [
+        "localize",
+        "isLocalized",
+        "format",
+        "hint",
+        "systemChat",
+        "if",
+    ] {
+        database.add_unary_command(command);
+    }
+    database.add_binary_command("then");
+    let parsed = hemtt_sqf::parser::run(&database, &processed).unwrap();
+    assert_eq!(
+        parsed
+            .localizations()
+            .iter()
+            .map(|(key, span)| (key.to_string(), span.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("STR_abe_main_car".to_string(), 14..32),
+            ("STR_abe_main_old_car".to_string(), 50..72),
+            ("STR_abe_main_%1".to_string(), 113..130),
+        ]
+    );
+}
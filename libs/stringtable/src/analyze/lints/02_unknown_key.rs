@@ -0,0 +1,161 @@
+use std::{collections::HashSet, ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Processed, Severity},
+    WorkspacePath,
+};
+
+use crate::analyze::{Localizations, SqfLintData};
+
+crate::analyze::lint!(LintL02UnknownKey);
+
+impl Lint<SqfLintData> for LintL02UnknownKey {
+    fn ident(&self) -> &str {
+        "unknown_key"
+    }
+
+    fn sort(&self) -> u32 {
+        20
+    }
+
+    fn description(&self) -> &str {
+        "Reports on stringtable keys that are referenced, but not defined in any stringtable"
+    }
+
+    fn documentation(&self) -> &str {
+        r#"### Example
+
+**Incorrect**
+```hpp
+class CfgVehicles {
+    class abe_car {
+        displayName = "$STR_abe_main_car";
+    };
+};
+```
+```sqf
+hint localize "STR_abe_main_car";
+```
+Where no stringtable in the project defines `STR_abe_main_car`.
+
+### Explanation
+
+Keys are referenced from configs with `$STR_` strings, and from SQF by `localize` and `isLocalized`, including through `LLSTRING`. A key that is not defined in any stringtable is displayed as the key itself in game.
+
+Only keys starting with the project prefix, such as `STR_abe_`, are checked, keys from other mods and the base game can not be known.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<SqfLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<SqfLintData> for Runner {
+    type Target = Localizations;
+    fn run(
+        &self,
+        project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        target: &Localizations,
+        _data: &SqfLintData,
+    ) -> Codes {
+        let prefix = project.map(|project| format!("str_{}_", project.prefix().to_lowercase()));
+        let keys = target
+            .keys()
+            .into_iter()
+            .map(|(id, _)| id.to_lowercase())
+            .collect::<HashSet<_>>();
+        let mut codes: Codes = Vec::new();
+        for (key, file, span) in target.references() {
+            // Keys built with `format` can not be checked
+            if key.contains('%') {
+                continue;
+            }
+            let lower = key.to_lowercase();
+            if prefix
+                .as_ref()
+                .is_some_and(|prefix| !lower.starts_with(prefix))
+            {
+                continue;
+            }
+            if !keys.contains(&lower) {
+                codes.push(Arc::new(CodeStringtableUnknownKey::new(
+                    key.clone(),
+                    file.clone(),
+                    span.clone(),
+                    config.severity(),
+                )));
+            }
+        }
+        codes
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeStringtableUnknownKey {
+    key: String,
+    file: WorkspacePath,
+    span: Range<usize>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeStringtableUnknownKey {
+    fn ident(&self) -> &'static str {
+        "L-L02"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/stringtables.html#unknown_key")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("Stringtable key `{}` is not defined", self.key)
+    }
+
+    fn label_message(&self) -> String {
+        "unknown key".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("Add the key to the addon's `stringtable.xml`".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeStringtableUnknownKey {
+    #[must_use]
+    pub fn new(key: String, file: WorkspacePath, span: Range<usize>, severity: Severity) -> Self {
+        Self {
+            key,
+            file,
+            span,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self).with_label(
+            Label::primary(self.file.clone(), self.span.clone()).with_message(self.label_message()),
+        ));
+        self
+    }
+}
@@ -0,0 +1,193 @@
+use std::{collections::HashSet, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Processed, Severity},
+    WorkspacePath,
+};
+
+use crate::analyze::{Localizations, SqfLintData};
+
+crate::analyze::lint!(LintL03UnusedKey);
+
+impl Lint<SqfLintData> for LintL03UnusedKey {
+    fn ident(&self) -> &str {
+        "unused_key"
+    }
+
+    fn sort(&self) -> u32 {
+        30
+    }
+
+    fn description(&self) -> &str {
+        "Reports on stringtable keys that are never referenced"
+    }
+
+    fn documentation(&self) -> &str {
+        r#"### Example
+
+**Incorrect**
+```xml
+<Key ID="STR_abe_main_old_car">
+    <English>Old Car</English>
+</Key>
+```
+Where no config or script in the project references `STR_abe_main_old_car`.
+
+### Explanation
+
+Keys are referenced from configs with `$STR_` strings, and from SQF by `localize` and `isLocalized`, including through `LLSTRING`. A key that is never referenced can usually be removed.
+
+Keys built with `format` are matched against the template, `localize format ["STR_abe_main_%1", _name]` references every key starting with `STR_abe_main_`. Keys that are built in any other way, such as stored in a variable before being passed to `localize`, count as unused. The lint can be disabled if the project relies on these.
+
+```toml
+[lints.stringtables]
+unused_key = false
+```
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::help()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<SqfLintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<SqfLintData> for Runner {
+    type Target = Localizations;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        target: &Localizations,
+        _data: &SqfLintData,
+    ) -> Codes {
+        let (templates, references): (Vec<String>, Vec<String>) = target
+            .references()
+            .iter()
+            .map(|(key, _, _)| key.to_lowercase())
+            .partition(|key| key.contains('%'));
+        let references = references.into_iter().collect::<HashSet<_>>();
+        let mut codes: Codes = Vec::new();
+        for (id, (_, path, existing)) in target.keys() {
+            let lower = id.to_lowercase();
+            if references.contains(&lower)
+                || templates
+                    .iter()
+                    .any(|template| matches_template(template, &lower))
+            {
+                continue;
+            }
+            let span = existing
+                .find(&format!("\"{id}\""))
+                .map(|start| start + 1..start + 1 + id.len());
+            codes.push(Arc::new(CodeStringtableUnusedKey::new(
+                id.to_string(),
+                path.clone(),
+                span,
+                config.severity(),
+            )));
+        }
+        codes
+    }
+}
+
+/// Check if a key could be built from a `format` template, where each `%1`, `%2`, ... matches any text
+fn matches_template(template: &str, key: &str) -> bool {
+    let mut parts = Vec::new();
+    let mut rest = template;
+    while let Some(index) = rest.find('%') {
+        parts.push(&rest[..index]);
+        rest = rest[index + 1..].trim_start_matches(|c: char| c.is_ascii_digit());
+    }
+    parts.push(rest);
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if !key.starts_with(first) || !key[first.len()..].ends_with(last) {
+        return false;
+    }
+    let mut remaining = &key[first.len()..key.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        let Some(index) = remaining.find(part) else {
+            return false;
+        };
+        remaining = &remaining[index + part.len()..];
+    }
+    true
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeStringtableUnusedKey {
+    key: String,
+    path: WorkspacePath,
+    span: Option<std::ops::Range<usize>>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeStringtableUnusedKey {
+    fn ident(&self) -> &'static str {
+        "L-L03"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/stringtables.html#unused_key")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("Stringtable key `{}` is never referenced", self.key)
+    }
+
+    fn label_message(&self) -> String {
+        "unused key".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        self.span
+            .is_none()
+            .then(|| format!("defined in `{}`", self.path))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeStringtableUnusedKey {
+    #[must_use]
+    pub fn new(
+        key: String,
+        path: WorkspacePath,
+        span: Option<std::ops::Range<usize>>,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            key,
+            path,
+            span,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        let mut diagnostic = Diagnostic::from_code(&self);
+        if let Some(span) = &self.span {
+            diagnostic = diagnostic.with_label(
+                Label::primary(self.path.clone(), span.clone()).with_message(self.label_message()),
+            );
+        }
+        self.diagnostic = Some(diagnostic);
+        self
+    }
+}
@@ -1,7 +1,11 @@
+use std::ops::Range;
+
 use hemtt_common::config::ProjectConfig;
-use hemtt_workspace::{lint::LintManager, lint_manager, reporting::Codes};
+use hemtt_workspace::{lint::LintManager, lint_manager, reporting::Codes, WorkspacePath};
 use lints::_01_sorted::StringtableData;
 
+use crate::Package;
+
 pub mod lints {
     automod::dir!(pub "src/analyze/lints");
 }
@@ -40,3 +44,72 @@ pub fn lint_all(addons: &Vec<StringtableData>, project: Option<&ProjectConfig>)
     }
     manager.run(&SqfLintData {}, project, None, addons)
 }
+
+/// The stringtables of the project, and the keys referenced by its configs and scripts
+pub struct Localizations {
+    stringtables: Vec<StringtableData>,
+    references: Vec<(String, WorkspacePath, Range<usize>)>,
+}
+
+impl Localizations {
+    #[must_use]
+    pub const fn new(
+        stringtables: Vec<StringtableData>,
+        references: Vec<(String, WorkspacePath, Range<usize>)>,
+    ) -> Self {
+        Self {
+            stringtables,
+            references,
+        }
+    }
+
+    #[must_use]
+    pub fn stringtables(&self) -> &[StringtableData] {
+        &self.stringtables
+    }
+
+    #[must_use]
+    /// The referenced keys, with the file and span they are referenced from
+    pub fn references(&self) -> &[(String, WorkspacePath, Range<usize>)] {
+        &self.references
+    }
+
+    #[must_use]
+    /// The ids of every key defined in the stringtables, with the stringtable they are defined in
+    pub fn keys(&self) -> Vec<(&str, &StringtableData)> {
+        fn package_keys<'a>(
+            package: &'a Package,
+            stringtable: &'a StringtableData,
+            keys: &mut Vec<(&'a str, &'a StringtableData)>,
+        ) {
+            for key in package.keys() {
+                keys.push((key.id(), stringtable));
+            }
+            for container in package.containers() {
+                package_keys(container, stringtable, keys);
+            }
+        }
+        let mut keys = Vec::new();
+        for stringtable in &self.stringtables {
+            for package in stringtable.0.packages() {
+                package_keys(package, stringtable, &mut keys);
+            }
+        }
+        keys
+    }
+}
+
+pub fn lint_localizations(localizations: &Localizations, project: Option<&ProjectConfig>) -> Codes {
+    let mut manager = LintManager::new(project.map_or_else(Default::default, |project| {
+        project.lints().stringtables().clone()
+    }));
+    if let Err(e) = manager.extend(
+        STRINGTABLE_LINTS
+            .iter()
+            .map(|l| (**l).clone())
+            .collect::<Vec<_>>(),
+    ) {
+        return e;
+    }
+    manager.run(&SqfLintData {}, project, None, localizations)
+}
@@ -0,0 +1,101 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::BufReader;
+
+use hemtt_common::config::{PDriveOption, ProjectConfig};
+use hemtt_stringtable::{
+    analyze::{lint_localizations, Localizations},
+    Project,
+};
+
+fn lint(references: &[&str], project: Option<&ProjectConfig>) -> Vec<String> {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .memory()
+        .finish(None, false, &PDriveOption::Disallow)
+        .unwrap();
+    let path = workspace.join("stringtable.xml").unwrap();
+    let existing = std::fs::read_to_string("tests/lints.xml").unwrap();
+    let stringtable = Project::from_reader(BufReader::new(existing.as_bytes())).unwrap();
+    let config = workspace.join("config.cpp").unwrap();
+    let localizations = Localizations::new(
+        vec![(stringtable, path, existing)],
+        references
+            .iter()
+            .map(|key| ((*key).to_string(), config.clone(), 0..key.len()))
+            .collect(),
+    );
+    lint_localizations(&localizations, project)
+        .iter()
+        .map(|c| format!("{} {}", c.ident(), c.message()))
+        .collect()
+}
+
+fn project() -> ProjectConfig {
+    ProjectConfig::from_file(std::path::Path::new("tests/project.toml")).unwrap()
+}
+
+#[test]
+fn clean() {
+    assert!(lint(
+        &[
+            "STR_abe_main_car",
+            "str_ABE_main_old_car",
+            "STR_abe_main_setting"
+        ],
+        Some(&project())
+    )
+    .is_empty());
+}
+
+#[test]
+fn unknown_key() {
+    assert_eq!(
+        lint(
+            &[
+                "STR_abe_main_car",
+                "STR_abe_main_old_car",
+                "STR_abe_main_setting",
+                "STR_abe_main_truck",
+                "STR_A3_OPTIONS_DEFAULT",
+            ],
+            Some(&project())
+        ),
+        vec!["L-L02 Stringtable key `STR_abe_main_truck` is not defined"]
+    );
+    assert_eq!(
+        lint(
+            &[
+                "STR_abe_main_car",
+                "STR_abe_main_old_car",
+                "STR_abe_main_setting",
+                "STR_A3_OPTIONS_DEFAULT",
+            ],
+            None
+        ),
+        vec!["L-L02 Stringtable key `STR_A3_OPTIONS_DEFAULT` is not defined"]
+    );
+}
+
+#[test]
+fn unused_key() {
+    assert_eq!(
+        lint(&["STR_abe_main_car"], Some(&project())),
+        vec![
+            "L-L03 Stringtable key `STR_abe_main_old_car` is never referenced",
+            "L-L03 Stringtable key `STR_abe_main_setting` is never referenced",
+        ]
+    );
+}
+
+#[test]
+fn format_templates() {
+    assert!(lint(
+        &["STR_abe_main_%1", "STR_abe_main_%1_%2_missing"],
+        Some(&project())
+    )
+    .is_empty());
+    assert_eq!(
+        lint(&["STR_abe_main_%1car"], Some(&project())),
+        vec!["L-L03 Stringtable key `STR_abe_main_setting` is never referenced"]
+    );
+}
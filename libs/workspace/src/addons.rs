@@ -235,10 +235,12 @@ impl Display for Location {
 }
 
 type RequiredVersion = (Version, WorkspacePath, Range<usize>);
+type Localization = (String, WorkspacePath, Range<usize>);
 
 #[derive(Debug, Clone, Default)]
 pub struct BuildData {
     required_version: Arc<RwLock<Option<RequiredVersion>>>,
+    localizations: Arc<RwLock<Vec<Localization>>>,
 }
 
 impl BuildData {
@@ -246,6 +248,7 @@ impl BuildData {
     pub fn new() -> Self {
         Self {
             required_version: Arc::new(RwLock::new(None)),
+            localizations: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -274,6 +277,29 @@ impl BuildData {
             .write()
             .expect("the required version lock is poisoned") = Some((version, file, line));
     }
+
+    #[must_use]
+    /// Fetches the stringtable keys referenced by the addon's configs and scripts
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned
+    pub fn localizations(&self) -> Vec<Localization> {
+        self.localizations
+            .read()
+            .expect("the localizations lock is poisoned")
+            .clone()
+    }
+
+    /// Adds stringtable keys referenced by the addon
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned
+    pub fn add_localizations(&self, localizations: impl IntoIterator<Item = Localization>) {
+        self.localizations
+            .write()
+            .expect("the localizations lock is poisoned")
+            .extend(localizations);
+    }
 }
 
 mod test_helper {
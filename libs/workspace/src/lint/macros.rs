@@ -1,10 +1,15 @@
+/// Declare the lints of a crate, the data passed to them is `analyze::SqfLintData`
+/// unless another type in the `analyze` module is given
 #[macro_export]
 macro_rules! lint_manager {
     ($ident:ident, $groups:expr) => {
+        $crate::lint_manager!($ident, $groups, SqfLintData);
+    };
+    ($ident:ident, $groups:expr, $data:ident) => {
         $crate::paste::paste! {
             #[linkme::distributed_slice]
             pub static [<$ident:upper _LINTS>]: [std::sync::LazyLock<
-                std::sync::Arc<Box<dyn hemtt_workspace::lint::Lint<super::analyze::SqfLintData>>>,
+                std::sync::Arc<Box<dyn hemtt_workspace::lint::Lint<super::analyze::$data>>>,
             >];
 
             #[allow(unused_macros)]
@@ -14,7 +19,7 @@ macro_rules! lint_manager {
                     pub struct $name;
                     #[linkme::distributed_slice(super::super::[<$ident:upper _LINTS>])]
                     static LINT_ADD: std::sync::LazyLock<
-                        std::sync::Arc<Box<dyn hemtt_workspace::lint::Lint<super::super::SqfLintData>>>,
+                        std::sync::Arc<Box<dyn hemtt_workspace::lint::Lint<super::super::$data>>>,
                     > = std::sync::LazyLock::new(|| std::sync::Arc::new(Box::new($name)));
                 };
             }
@@ -24,7 +29,7 @@ macro_rules! lint_manager {
             pub fn lint_check(
                 config: std::collections::HashMap<String, hemtt_common::config::LintConfigOverride>,
             ) -> $crate::reporting::Codes {
-                let mut manager: $crate::lint::LintManager<super::analyze::SqfLintData> =
+                let mut manager: $crate::lint::LintManager<super::analyze::$data> =
                     $crate::lint::LintManager::new(config);
                 if let Err(lint_errors) =
                     manager.extend([<$ident:upper _LINTS>].iter().map(|l| (**l).clone()).collect::<Vec<_>>())
@@ -32,8 +37,8 @@ macro_rules! lint_manager {
                     return lint_errors;
                 }
                 let groups: Vec<(
-                    $crate::lint::Lints<super::analyze::SqfLintData>,
-                    Box<dyn $crate::lint::AnyLintGroupRunner<super::analyze::SqfLintData>>,
+                    $crate::lint::Lints<super::analyze::$data>,
+                    Box<dyn $crate::lint::AnyLintGroupRunner<super::analyze::$data>>,
                 )> = $groups;
                 for group in groups {
                     if let Err(lint_errors) = manager.push_group(group.0, group.1) {